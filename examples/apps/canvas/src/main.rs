@@ -178,6 +178,7 @@ impl App {
                 ctx.draw(&Map {
                     color: Color::Green,
                     resolution: MapResolution::High,
+                    ..Default::default()
                 });
                 ctx.print(self.x, -self.y, "You are here".yellow());
             })
@@ -308,6 +308,7 @@ fn draw_second_tab(frame: &mut Frame, app: &mut App, area: Rect) {
             ctx.draw(&Map {
                 color: Color::White,
                 resolution: MapResolution::High,
+                ..Default::default()
             });
             ctx.layer();
             ctx.draw(&Rectangle {
@@ -325,13 +326,13 @@ fn draw_second_tab(frame: &mut Frame, app: &mut App, area: Rect) {
             });
             for (i, s1) in app.servers.iter().enumerate() {
                 for s2 in &app.servers[i + 1..] {
-                    ctx.draw(&canvas::Line {
-                        x1: s1.coords.1,
-                        y1: s1.coords.0,
-                        y2: s2.coords.0,
-                        x2: s2.coords.1,
-                        color: Color::Yellow,
-                    });
+                    ctx.draw(&canvas::Line::new(
+                        s1.coords.1,
+                        s1.coords.0,
+                        s2.coords.1,
+                        s2.coords.0,
+                        Color::Yellow,
+                    ));
                 }
             }
             for server in &app.servers {
@@ -108,6 +108,7 @@ fn render_map(selected_row: usize, area: Rect, buf: &mut Buffer) {
     let map = Map {
         resolution: MapResolution::High,
         color: theme.color,
+        ..Default::default()
     };
     Canvas::default()
         .background_color(theme.background_color)
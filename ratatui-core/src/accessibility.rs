@@ -0,0 +1,211 @@
+//! Semantic annotations of the rendered widgets for accessibility tools.
+//!
+//! Ratatui renders widgets into a grid of cells, which loses the structure of the interface (e.g.
+//! which cells form a list item, or which list item is selected). To make this structure available
+//! to assistive technologies such as screen readers, widgets can describe what they render by
+//! pushing [`Annotation`]s into the [`AccessibilityTree`] of the [`Buffer`] they render into.
+//!
+//! Collecting annotations is opt-in, as it has a cost on each render. It is enabled with
+//! [`Terminal::enable_accessibility_tree`] (or [`Buffer::enable_accessibility_tree`] when
+//! rendering into a buffer directly). The tree of the last frame is then available from the
+//! [`CompletedFrame`] returned by [`Terminal::draw`].
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use ratatui::accessibility::Role;
+//! use ratatui::backend::TestBackend;
+//! use ratatui::widgets::List;
+//! use ratatui::Terminal;
+//!
+//! let mut terminal = Terminal::new(TestBackend::new(10, 3))?;
+//! terminal.enable_accessibility_tree(true);
+//! let completed = terminal.draw(|frame| {
+//!     frame.render_widget(List::new(["Apple", "Pear"]), frame.area());
+//! })?;
+//! let tree = completed.accessibility_tree().unwrap();
+//! assert_eq!(tree.annotations()[1].role, Role::ListItem { selected: false });
+//! assert_eq!(tree.annotations()[1].text, "Apple");
+//! ```
+//!
+//! Widgets annotate themselves by checking whether the buffer collects annotations, which avoids
+//! building the text of the annotations when it does not:
+//!
+//! ```rust
+//! use ratatui_core::accessibility::{Annotation, Role};
+//! use ratatui_core::buffer::Buffer;
+//! use ratatui_core::layout::Rect;
+//! use ratatui_core::style::Style;
+//! use ratatui_core::widgets::Widget;
+//!
+//! struct Label(&'static str);
+//!
+//! impl Widget for Label {
+//!     fn render(self, area: Rect, buf: &mut Buffer) {
+//!         buf.set_string(area.x, area.y, self.0, Style::new());
+//!         if let Some(tree) = buf.accessibility_tree_mut() {
+//!             tree.push(Annotation::new(Role::Text, self.0, area));
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! [`Buffer`]: crate::buffer::Buffer
+//! [`Buffer::enable_accessibility_tree`]: crate::buffer::Buffer::enable_accessibility_tree
+//! [`CompletedFrame`]: crate::terminal::CompletedFrame
+//! [`Terminal::draw`]: crate::terminal::Terminal::draw
+//! [`Terminal::enable_accessibility_tree`]: crate::terminal::Terminal::enable_accessibility_tree
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::layout::Rect;
+
+/// The semantic role of an [`Annotation`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    /// Plain text, e.g. a label
+    Text,
+    /// A paragraph of text
+    Paragraph,
+    /// The title of a block or a section
+    Title,
+    /// A list of items, the items are annotated separately with [`Role::ListItem`]
+    List,
+    /// An item of a list
+    ListItem {
+        /// Whether the item is selected
+        selected: bool,
+    },
+}
+
+/// A semantic description of something rendered in a [`Buffer`]
+///
+/// [`Buffer`]: crate::buffer::Buffer
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Annotation {
+    /// The role of the annotated element
+    pub role: Role,
+    /// The text content of the annotated element, without any styling
+    pub text: String,
+    /// The area in which the element was rendered
+    pub area: Rect,
+}
+
+impl Annotation {
+    /// Creates a new `Annotation`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::accessibility::{Annotation, Role};
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let annotation = Annotation::new(Role::Title, "Settings", Rect::new(0, 0, 10, 1));
+    /// ```
+    pub fn new<T: Into<String>>(role: Role, text: T, area: Rect) -> Self {
+        Self {
+            role,
+            text: text.into(),
+            area,
+        }
+    }
+}
+
+/// The annotations collected while rendering a frame
+///
+/// The annotations are stored in the order in which the widgets were rendered. Container widgets
+/// push their own annotation before the annotations of their content (e.g. a [`Role::List`] is
+/// followed by its [`Role::ListItem`]s), so the nesting of the elements can be recovered from
+/// this order and the areas of the annotations.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct AccessibilityTree {
+    annotations: Vec<Annotation>,
+}
+
+impl AccessibilityTree {
+    /// Creates a new empty `AccessibilityTree`
+    pub const fn new() -> Self {
+        Self {
+            annotations: Vec::new(),
+        }
+    }
+
+    /// Adds an annotation to the tree
+    pub fn push(&mut self, annotation: Annotation) {
+        self.annotations.push(annotation);
+    }
+
+    /// Returns the annotations in the order they were added
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
+
+    /// Returns an iterator over the annotations with the given role
+    pub fn with_role(&self, role: Role) -> impl Iterator<Item = &Annotation> {
+        self.annotations
+            .iter()
+            .filter(move |annotation| annotation.role == role)
+    }
+
+    /// Returns the number of annotations
+    pub fn len(&self) -> usize {
+        self.annotations.len()
+    }
+
+    /// Returns `true` if the tree contains no annotations
+    pub fn is_empty(&self) -> bool {
+        self.annotations.is_empty()
+    }
+
+    /// Removes all the annotations
+    pub fn clear(&mut self) {
+        self.annotations.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut tree = AccessibilityTree::new();
+        assert!(tree.is_empty());
+        tree.push(Annotation::new(Role::Title, "Title", Rect::new(0, 0, 5, 1)));
+        tree.push(Annotation::new(Role::Text, "Text", Rect::new(0, 1, 5, 1)));
+        assert_eq!(tree.len(), 2);
+        assert_eq!(
+            tree.annotations(),
+            [
+                Annotation::new(Role::Title, "Title", Rect::new(0, 0, 5, 1)),
+                Annotation::new(Role::Text, "Text", Rect::new(0, 1, 5, 1)),
+            ]
+        );
+        tree.clear();
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn with_role() {
+        let mut tree = AccessibilityTree::new();
+        tree.push(Annotation::new(Role::List, "", Rect::new(0, 0, 5, 2)));
+        tree.push(Annotation::new(
+            Role::ListItem { selected: false },
+            "a",
+            Rect::new(0, 0, 5, 1),
+        ));
+        tree.push(Annotation::new(
+            Role::ListItem { selected: true },
+            "b",
+            Rect::new(0, 1, 5, 1),
+        ));
+        let selected = tree
+            .with_role(Role::ListItem { selected: true })
+            .map(|annotation| annotation.text.as_str());
+        assert!(selected.eq(["b"]));
+    }
+}
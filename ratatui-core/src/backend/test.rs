@@ -171,6 +171,7 @@ impl TestBackend {
                 ..Rect::ZERO
             },
             content: vec![],
+            accessibility_tree: None,
         };
         self.assert_scrollback(&expected);
     }
@@ -951,6 +952,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[0..10 * 5].to_vec(),
+                accessibility_tree: None,
             },
             Buffer::with_lines([
                 "         6",
@@ -966,6 +968,7 @@ mod tests {
             Buffer {
                 area: Rect::new(0, 0, 10, 5),
                 content: backend.scrollback.content[10 * 65530..10 * 65535].to_vec(),
+                accessibility_tree: None,
             },
             Buffer::with_lines([
                 "     65536",
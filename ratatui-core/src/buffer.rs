@@ -5,5 +5,5 @@ mod assert;
 mod buffer;
 mod cell;
 
-pub use buffer::Buffer;
+pub use buffer::{Buffer, MergeMode};
 pub use cell::Cell;
@@ -1,14 +1,19 @@
-use alloc::vec;
+use alloc::string::String;
 use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::Write;
+use core::hash::{Hash, Hasher};
 use core::ops::{Index, IndexMut};
 use core::{cmp, fmt};
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+use crate::accessibility::AccessibilityTree;
 use crate::buffer::Cell;
 use crate::layout::{Position, Rect};
-use crate::style::Style;
+use crate::style::{Color, Modifier, Style};
+use crate::symbols::line::{self, Connections};
 use crate::text::{Line, Span};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
@@ -61,7 +66,7 @@ use crate::text::{Line, Span};
 /// # Some(())
 /// # }
 /// ```
-#[derive(Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Buffer {
     /// The area represented by this buffer
@@ -69,6 +74,11 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// The accessibility annotations pushed by the widgets rendered into the buffer
+    ///
+    /// This is not part of the equality of buffers, as it doesn't affect what is displayed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) accessibility_tree: Option<AccessibilityTree>,
 }
 
 impl Buffer {
@@ -83,7 +93,11 @@ impl Buffer {
     pub fn filled(area: Rect, cell: Cell) -> Self {
         let size = area.area() as usize;
         let content = vec![cell; size];
-        Self { area, content }
+        Self {
+            area,
+            content,
+            accessibility_tree: None,
+        }
     }
 
     /// Returns a Buffer containing the given lines
@@ -110,6 +124,309 @@ impl Buffer {
         &self.content
     }
 
+    /// Returns an iterator over the rows of the buffer, from top to bottom
+    ///
+    /// Each row is a slice of [`Buffer::content`] that is as long as the width of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let buffer = Buffer::with_lines(["ab", "cd"]);
+    /// let rows: Vec<String> = buffer
+    ///     .rows()
+    ///     .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+    ///     .collect();
+    /// assert_eq!(rows, ["ab", "cd"]);
+    /// ```
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[Cell]> {
+        let width = self.area.width as usize;
+        (0..self.area.height as usize).map(move |y| &self.content[y * width..(y + 1) * width])
+    }
+
+    /// Returns the graphemes of the given row along with the x coordinate of the cell they start in
+    ///
+    /// The cells hidden by a multi-width grapheme are skipped, so each item is a whole grapheme
+    /// (including any combining marks). As the iterator is double-ended, it can be reversed to step
+    /// backward through the row by grapheme (e.g. to move a cursor to the left or delete the
+    /// previous grapheme).
+    ///
+    /// Returns an empty iterator if the row is outside of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let buffer = Buffer::with_lines(["aコb"]);
+    /// let graphemes: Vec<_> = buffer.content_graphemes(0).rev().collect();
+    /// assert_eq!(graphemes, [(3, "b"), (1, "コ"), (0, "a")]);
+    /// ```
+    pub fn content_graphemes(&self, row: u16) -> impl DoubleEndedIterator<Item = (u16, &str)> {
+        let mut graphemes = Vec::new();
+        if (self.area.top()..self.area.bottom()).contains(&row) {
+            let start = self.index_of(self.area.x, row);
+            let cells = &self.content[start..start + self.area.width as usize];
+            let mut to_skip = 0;
+            for (x, cell) in (self.area.x..).zip(cells) {
+                if to_skip > 0 {
+                    to_skip -= 1;
+                    continue;
+                }
+                let symbol = cell.symbol();
+                to_skip = symbol.width().saturating_sub(1);
+                graphemes.push((x, symbol));
+            }
+        }
+        graphemes.into_iter()
+    }
+
+    /// Returns the text displayed in the given area of the buffer, ignoring styles
+    ///
+    /// Each row of the area becomes a line of the returned string, with trailing spaces removed.
+    /// The lines are separated by `\n`, without a trailing newline. The cells hidden by a
+    /// multi-width grapheme are skipped, so each grapheme appears once.
+    ///
+    /// The area is clipped to the area of the buffer. An empty string is returned if they don't
+    /// overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let buffer = Buffer::with_lines(["Hello  ", "コンビ "]);
+    /// assert_eq!(buffer.content_string(buffer.area), "Hello\nコンビ");
+    /// assert_eq!(buffer.content_string(Rect::new(1, 0, 3, 1)), "ell");
+    /// ```
+    pub fn content_string(&self, area: Rect) -> String {
+        let area = self.area.intersection(area);
+        let mut string = String::new();
+        let mut line = String::new();
+        for (i, row) in area.rows().enumerate() {
+            if i > 0 {
+                string.push('\n');
+            }
+            line.clear();
+            for (_, symbol) in self
+                .content_graphemes(row.y)
+                .filter(|(x, _)| (area.left()..area.right()).contains(x))
+            {
+                line.push_str(symbol);
+            }
+            string.push_str(line.trim_end_matches(' '));
+        }
+        string
+    }
+
+    /// Returns the content of the buffer as a string with ANSI escape sequences for the styles
+    ///
+    /// This is useful to print the buffer outside of a terminal application, e.g. to pipe a
+    /// colored output into `less -R` or to write it to a file. The lines of the buffer are
+    /// separated by `\n`.
+    ///
+    /// Each change of style is written as a single SGR sequence which resets the previous style
+    /// (`\x1b[0;...m`), so consecutive cells with the same style don't repeat the sequence. Each
+    /// line which ends with a non default style is terminated by a reset (`\x1b[0m`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let buffer = Buffer::with_lines([Line::from(vec!["ab".red(), "c".into()])]);
+    /// assert_eq!(buffer.to_ansi_string(), "\x1b[0;31mab\x1b[0mc");
+    /// ```
+    pub fn to_ansi_string(&self) -> String {
+        let mut string = String::new();
+        if self.area.is_empty() {
+            return string;
+        }
+        for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
+            if y > 0 {
+                string.push('\n');
+            }
+            let mut style = Cell::EMPTY.style();
+            let mut skip: usize = 0;
+            for cell in line {
+                if skip == 0 {
+                    if cell.style() != style {
+                        style = cell.style();
+                        push_sgr(&mut string, cell);
+                    }
+                    string.push_str(cell.symbol());
+                }
+                skip = cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+            if style != Cell::EMPTY.style() {
+                string.push_str("\x1b[0m");
+            }
+        }
+        string
+    }
+
+    /// Returns the content of the buffer as HTML
+    ///
+    /// The content is written in a `<pre>` element, each run of cells with the same style being
+    /// wrapped in a `<span>` with an inline `style` attribute (e.g. `color:#cd0000`). Cells with
+    /// the default style are not wrapped. The colors are converted with [`Color::to_rgb`], and
+    /// only the bold, italic, underlined and crossed out modifiers are exported. The `&`, `<` and
+    /// `>` characters of the symbols are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let buffer = Buffer::with_lines([Line::from(vec!["ab".red(), "c".into()])]);
+    /// assert_eq!(
+    ///     buffer.to_html(),
+    ///     "<pre><span style=\"color:#cd0000\">ab</span>c</pre>"
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<pre>");
+        if self.area.is_empty() {
+            html.push_str("</pre>");
+            return html;
+        }
+        for (y, line) in self.content.chunks(self.area.width as usize).enumerate() {
+            if y > 0 {
+                html.push('\n');
+            }
+            let mut style = Cell::EMPTY.style();
+            let mut in_span = false;
+            let mut skip: usize = 0;
+            for cell in line {
+                if skip == 0 {
+                    if cell.style() != style {
+                        style = cell.style();
+                        if in_span {
+                            html.push_str("</span>");
+                        }
+                        let css = css_style(cell);
+                        in_span = !css.is_empty();
+                        if in_span {
+                            let _ = write!(html, "<span style=\"{css}\">");
+                        }
+                    }
+                    push_escaped_html(&mut html, cell.symbol());
+                }
+                skip = cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+            if in_span {
+                html.push_str("</span>");
+            }
+        }
+        html.push_str("</pre>");
+        html
+    }
+
+    /// Returns the content of the buffer as a self-contained SVG image
+    ///
+    /// Each cell is `cell_width` wide and `cell_height` high, except for the cells with a wide
+    /// symbol which span two cells. The background of each cell is drawn as a `<rect>` and its
+    /// symbol as a `<text>` element using a monospace font of `cell_height` size. The colors are
+    /// converted with [`Color::to_rgb`]. No `<rect>` is drawn for [`Color::Reset`] backgrounds and
+    /// the text is drawn in black for [`Color::Reset`] foregrounds. Blank symbols are not drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::style::Stylize;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let buffer = Buffer::with_lines([Line::from("ab".red().on_blue())]);
+    /// let svg = buffer.to_svg(8.0, 16.0);
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("<rect").count(), 2);
+    /// ```
+    pub fn to_svg(&self, cell_width: f32, cell_height: f32) -> String {
+        let width = f32::from(self.area.width) * cell_width;
+        let height = f32::from(self.area.height) * cell_height;
+        let mut svg = String::new();
+        let _ = write!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+            viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"{cell_height}\" \
+            xml:space=\"preserve\">"
+        );
+        for y in 0..self.area.height {
+            let mut skip: usize = 0;
+            for x in 0..self.area.width {
+                let cell = &self[(self.area.x + x, self.area.y + y)];
+                let symbol = cell.symbol();
+                if skip == 0 {
+                    let left = f32::from(x) * cell_width;
+                    let top = f32::from(y) * cell_height;
+                    let cells = if symbol.width() > 1 { 2.0 } else { 1.0 };
+                    push_svg_cell(&mut svg, cell, left, top, cells * cell_width, cell_height);
+                }
+                skip = cmp::max(skip, symbol.width()).saturating_sub(1);
+            }
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+
+    /// Enables or disables the collection of accessibility annotations
+    ///
+    /// When enabled, the widgets rendered into the buffer push [`Annotation`]s describing what
+    /// they render into the [`accessibility_tree`](Buffer::accessibility_tree). The annotations
+    /// are cleared by [`Buffer::reset`]. Disabling the collection drops the collected annotations.
+    ///
+    /// See the [`accessibility`](crate::accessibility) module for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+    /// assert!(buffer.accessibility_tree().is_none());
+    /// buffer.enable_accessibility_tree(true);
+    /// assert!(buffer
+    ///     .accessibility_tree()
+    ///     .is_some_and(|tree| tree.is_empty()));
+    /// ```
+    ///
+    /// [`Annotation`]: crate::accessibility::Annotation
+    pub fn enable_accessibility_tree(&mut self, enabled: bool) {
+        if enabled {
+            self.accessibility_tree
+                .get_or_insert_with(AccessibilityTree::new);
+        } else {
+            self.accessibility_tree = None;
+        }
+    }
+
+    /// Returns the accessibility annotations pushed by the widgets rendered into the buffer
+    ///
+    /// Returns `None` unless the collection of annotations is enabled, see
+    /// [`Buffer::enable_accessibility_tree`].
+    pub const fn accessibility_tree(&self) -> Option<&AccessibilityTree> {
+        self.accessibility_tree.as_ref()
+    }
+
+    /// Returns a mutable reference to the accessibility annotations of the buffer
+    ///
+    /// Widgets push the [`Annotation`]s describing what they render into the returned tree. This
+    /// returns `None` unless the collection of annotations is enabled, in which case widgets
+    /// should skip building their annotations.
+    ///
+    /// [`Annotation`]: crate::accessibility::Annotation
+    pub fn accessibility_tree_mut(&mut self) -> Option<&mut AccessibilityTree> {
+        self.accessibility_tree.as_mut()
+    }
+
     /// Returns the area covered by this buffer
     pub const fn area(&self) -> &Rect {
         &self.area
@@ -409,13 +726,38 @@ impl Buffer {
 
     /// Resize the buffer so that the mapped area matches the given area and that the buffer
     /// length is equal to area.width * area.height
+    ///
+    /// The content of the buffer is preserved, anchored to the top-left corner of the area: the
+    /// cell at a given offset from the top-left corner of the previous area is copied to the same
+    /// offset from the top-left corner of the new area. Cells that do not fit in the new area are
+    /// dropped and new cells are set to the default one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let mut buffer = Buffer::with_lines(["ab", "cd"]);
+    /// buffer.resize(Rect::new(0, 0, 3, 1));
+    /// assert_eq!(buffer, Buffer::with_lines(["ab "]));
+    /// ```
     pub fn resize(&mut self, area: Rect) {
-        let length = area.area() as usize;
-        if self.content.len() > length {
-            self.content.truncate(length);
-        } else {
-            self.content.resize(length, Cell::EMPTY);
+        if area.width == self.area.width && area.height == self.area.height {
+            self.area = area;
+            return;
         }
+        let mut content = vec![Cell::EMPTY; area.area() as usize];
+        if area.width > 0 && self.area.width > 0 {
+            let width = cmp::min(area.width, self.area.width) as usize;
+            let rows = content
+                .chunks_exact_mut(area.width as usize)
+                .zip(self.content.chunks_exact(self.area.width as usize));
+            for (row, previous_row) in rows {
+                row[..width].clone_from_slice(&previous_row[..width]);
+            }
+        }
+        self.content = content;
         self.area = area;
     }
 
@@ -424,10 +766,142 @@ impl Buffer {
         for cell in &mut self.content {
             cell.reset();
         }
+        if let Some(tree) = &mut self.accessibility_tree {
+            tree.clear();
+        }
     }
 
     /// Merge an other buffer into this one
     pub fn merge(&mut self, other: &Self) {
+        self.merge_cells(other, |cell, other| *cell = other.clone());
+    }
+
+    /// Merge an other buffer into this one, layering it on top according to the given mode
+    ///
+    /// Unlike [`Buffer::merge`], only the cells of `other` which are not empty (i.e. not equal to
+    /// [`Cell::EMPTY`]) are merged, so that the content of this buffer shows through the empty
+    /// cells of `other`. See [`MergeMode`] for how the cells are merged.
+    ///
+    /// The area of this buffer becomes the union of both areas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::{Buffer, MergeMode};
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::style::{Color, Style};
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+    /// buffer.set_style(buffer.area, Style::new().bg(Color::Rgb(0, 0, 0)));
+    ///
+    /// let mut overlay = Buffer::empty(Rect::new(2, 1, 6, 1));
+    /// overlay.set_string(2, 1, "dialog", Style::new().bg(Color::Rgb(255, 255, 255)));
+    ///
+    /// buffer.merge_with(&overlay, MergeMode::BlendBackground(0.5));
+    /// assert_eq!(buffer[(2, 1)].symbol(), "d");
+    /// assert_eq!(buffer[(2, 1)].bg, Color::Rgb(128, 128, 128));
+    /// ```
+    pub fn merge_with(&mut self, other: &Self, mode: MergeMode) {
+        self.merge_cells(other, |cell, other| {
+            if *other == Cell::EMPTY {
+                return;
+            }
+            let bg = match mode {
+                MergeMode::Overwrite => other.bg,
+                MergeMode::BlendBackground(alpha) => cell.bg.blend(other.bg, alpha),
+            };
+            *cell = other.clone();
+            cell.bg = bg;
+        });
+    }
+
+    /// Joins the box-drawing symbols of the given area with their neighbors
+    ///
+    /// Each line symbol (of the [normal], [rounded], [double] or [thick] line sets) is extended
+    /// towards the neighbors which connect to it, so that the lines drawn by different widgets meet
+    /// with the correct junction symbol. For example a vertical line `│` with a horizontal line `─`
+    /// on its right becomes `├`, and a corner `┐` with a vertical line `│` above it becomes `┤`.
+    ///
+    /// Only lines of the same weight are joined (e.g. a double line is not joined with a normal
+    /// line), and only the neighbors inside the area are taken into account. The symbols are only
+    /// ever extended, so applying this more than once has no further effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::buffer::Buffer;
+    ///
+    /// let mut buffer = Buffer::with_lines([" │ ", "─│─", " │ "]);
+    /// buffer.merge_box_borders(buffer.area);
+    /// assert_eq!(buffer, Buffer::with_lines([" │ ", "─┼─", " │ "]));
+    /// ```
+    ///
+    /// [normal]: crate::symbols::line::NORMAL
+    /// [rounded]: crate::symbols::line::ROUNDED
+    /// [double]: crate::symbols::line::DOUBLE
+    /// [thick]: crate::symbols::line::THICK
+    pub fn merge_box_borders(&mut self, area: Rect) {
+        let area = self.area.intersection(area);
+        // the symbols are computed from the original content, then replaced
+        let mut joined = Vec::new();
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let Some((set, connections)) = line_connections(self[(x, y)].symbol()) else {
+                    continue;
+                };
+                // each neighbor with the direction in which it must connect back to this cell
+                let neighbors = [
+                    (
+                        Connections::UP,
+                        Connections::DOWN,
+                        (y > area.top()).then(|| (x, y - 1)),
+                    ),
+                    (
+                        Connections::DOWN,
+                        Connections::UP,
+                        (y + 1 < area.bottom()).then_some((x, y + 1)),
+                    ),
+                    (
+                        Connections::LEFT,
+                        Connections::RIGHT,
+                        (x > area.left()).then(|| (x - 1, y)),
+                    ),
+                    (
+                        Connections::RIGHT,
+                        Connections::LEFT,
+                        (x + 1 < area.right()).then_some((x + 1, y)),
+                    ),
+                ];
+                let mut merged = connections;
+                for (direction, opposite, neighbor) in neighbors {
+                    let Some(neighbor) = neighbor else {
+                        continue;
+                    };
+                    if let Some((neighbor_set, neighbor_connections)) =
+                        line_connections(self[neighbor].symbol())
+                    {
+                        if neighbor_set.vertical == set.vertical
+                            && neighbor_connections.contains(opposite)
+                        {
+                            merged |= direction;
+                        }
+                    }
+                }
+                if merged != connections {
+                    if let Some(symbol) = set.connecting(merged) {
+                        joined.push(((x, y), symbol));
+                    }
+                }
+            }
+        }
+        for (position, symbol) in joined {
+            self[position].set_symbol(symbol);
+        }
+    }
+
+    /// Grows this buffer to the union of both areas and merges each cell of the other buffer into
+    /// the corresponding cell of this one with the given function
+    fn merge_cells<F: Fn(&mut Cell, &Cell)>(&mut self, other: &Self, merge: F) {
         let area = self.area.union(other.area);
         self.content.resize(area.area() as usize, Cell::EMPTY);
 
@@ -450,7 +924,7 @@ impl Buffer {
             let (x, y) = other.pos_of(i);
             // New index in content
             let k = ((y - area.y) * area.width + x - area.x) as usize;
-            self.content[k] = other.content[i].clone();
+            merge(&mut self.content[k], &other.content[i]);
         }
         self.area = area;
     }
@@ -508,6 +982,162 @@ impl Buffer {
     }
 }
 
+/// Describes how the cells of a buffer are merged into another one with [`Buffer::merge_with`]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum MergeMode {
+    /// The merged cells overwrite the cells of the buffer
+    #[default]
+    Overwrite,
+    /// The merged cells overwrite the cells of the buffer, except for the background color which
+    /// is blended over the background color of the buffer with the given opacity
+    ///
+    /// The opacity is a value between `0.0` (the background of the buffer is kept) and `1.0` (the
+    /// background of the merged cells is used). See [`Color::blend`] for more details.
+    ///
+    /// [`Color::blend`]: crate::style::Color::blend
+    BlendBackground(f32),
+}
+
+/// Writes the SGR sequence which sets the style of the cell, after resetting the previous style
+fn push_sgr(string: &mut String, cell: &Cell) {
+    string.push_str("\x1b[0");
+    for (modifier, code) in [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::SLOW_BLINK, 5),
+        (Modifier::RAPID_BLINK, 6),
+        (Modifier::REVERSED, 7),
+        (Modifier::HIDDEN, 8),
+        (Modifier::CROSSED_OUT, 9),
+    ] {
+        if cell.modifier.contains(modifier) {
+            let _ = write!(string, ";{code}");
+        }
+    }
+    push_sgr_color(string, cell.fg, 30);
+    push_sgr_color(string, cell.bg, 40);
+    #[cfg(feature = "underline-color")]
+    push_sgr_color(string, cell.underline_color, 50);
+    string.push('m');
+}
+
+/// Writes the SGR parameters of a color, `base` being the code of the black color (30 for the
+/// foreground, 40 for the background and 50 for the underline)
+///
+/// Nothing is written for [`Color::Reset`] as the sequence starts with a reset. There are no
+/// codes for the named underline colors, so they are written as indexed colors.
+fn push_sgr_color(string: &mut String, color: Color, base: u8) {
+    let index = match color {
+        Color::Reset => return,
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        Color::White => 15,
+        Color::Indexed(index) => index,
+        Color::Rgb(r, g, b) => {
+            let _ = write!(string, ";{};2;{r};{g};{b}", base + 8);
+            return;
+        }
+    };
+    let _ = match color {
+        Color::Indexed(_) => write!(string, ";{};5;{index}", base + 8),
+        _ if base == 50 => write!(string, ";58;5;{index}"),
+        _ if index < 8 => write!(string, ";{}", base + index),
+        _ => write!(string, ";{}", base + 60 + index - 8),
+    };
+}
+
+/// Returns the CSS declarations of the style of the cell, separated by `;`
+fn css_style(cell: &Cell) -> String {
+    let mut declarations = Vec::new();
+    if let Some((r, g, b)) = cell.fg.to_rgb() {
+        declarations.push(format!("color:#{r:02x}{g:02x}{b:02x}"));
+    }
+    if let Some((r, g, b)) = cell.bg.to_rgb() {
+        declarations.push(format!("background:#{r:02x}{g:02x}{b:02x}"));
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        declarations.push("font-weight:bold".into());
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        declarations.push("font-style:italic".into());
+    }
+    let decorations = [
+        (Modifier::UNDERLINED, "underline"),
+        (Modifier::CROSSED_OUT, "line-through"),
+    ]
+    .into_iter()
+    .filter(|(modifier, _)| cell.modifier.contains(*modifier))
+    .map(|(_, decoration)| decoration)
+    .collect::<Vec<_>>();
+    if !decorations.is_empty() {
+        declarations.push(format!("text-decoration:{}", decorations.join(" ")));
+    }
+    declarations.join(";")
+}
+
+/// Writes the symbol with the `&`, `<` and `>` characters escaped as HTML entities
+fn push_escaped_html(string: &mut String, symbol: &str) {
+    for c in symbol.chars() {
+        match c {
+            '&' => string.push_str("&amp;"),
+            '<' => string.push_str("&lt;"),
+            '>' => string.push_str("&gt;"),
+            c => string.push(c),
+        }
+    }
+}
+
+/// Writes the background `<rect>` and the `<text>` elements of a cell of an SVG image
+fn push_svg_cell(svg: &mut String, cell: &Cell, left: f32, top: f32, width: f32, height: f32) {
+    if let Some((r, g, b)) = cell.bg.to_rgb() {
+        let _ = write!(
+            svg,
+            "<rect x=\"{left}\" y=\"{top}\" width=\"{width}\" height=\"{height}\" \
+            fill=\"#{r:02x}{g:02x}{b:02x}\"/>"
+        );
+    }
+    if cell.symbol().trim().is_empty() {
+        return;
+    }
+    // the baseline of the text is placed at about 80% of the height of the cell
+    let baseline = top + height * 0.8;
+    let _ = write!(svg, "<text x=\"{left}\" y=\"{baseline}\"");
+    if let Some((r, g, b)) = cell.fg.to_rgb() {
+        let _ = write!(svg, " fill=\"#{r:02x}{g:02x}{b:02x}\"");
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        svg.push_str(" font-weight=\"bold\"");
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        svg.push_str(" font-style=\"italic\"");
+    }
+    svg.push('>');
+    push_escaped_html(svg, cell.symbol());
+    svg.push_str("</text>");
+}
+
+/// Returns the line set of a box-drawing symbol and the neighbors it connects to
+fn line_connections(symbol: &str) -> Option<(line::Set, Connections)> {
+    [line::NORMAL, line::ROUNDED, line::DOUBLE, line::THICK]
+        .into_iter()
+        .find_map(|set| Some((set, set.connections(symbol)?)))
+}
+
 impl<P: Into<Position>> Index<P> for Buffer {
     type Output = Cell;
 
@@ -531,6 +1161,7 @@ impl<P: Into<Position>> Index<P> for Buffer {
     /// let cell = &buf[(0, 0)];
     /// let cell = &buf[Position::new(0, 0)];
     /// ```
+    #[track_caller]
     fn index(&self, position: P) -> &Self::Output {
         let position = position.into();
         let index = self.index_of(position.x, position.y);
@@ -559,6 +1190,7 @@ impl<P: Into<Position>> IndexMut<P> for Buffer {
     /// buf[(0, 0)].set_symbol("A");
     /// buf[Position::new(0, 0)].set_symbol("B");
     /// ```
+    #[track_caller]
     fn index_mut(&mut self, position: P) -> &mut Self::Output {
         let position = position.into();
         let index = self.index_of(position.x, position.y);
@@ -566,6 +1198,21 @@ impl<P: Into<Position>> IndexMut<P> for Buffer {
     }
 }
 
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.content == other.content
+    }
+}
+
+impl Eq for Buffer {}
+
+impl Hash for Buffer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.area.hash(state);
+        self.content.hash(state);
+    }
+}
+
 impl fmt::Debug for Buffer {
     /// Writes a debug representation of the buffer to the given formatter.
     ///
@@ -649,6 +1296,7 @@ mod tests {
     use rstest::{fixture, rstest};
 
     use super::*;
+    use crate::accessibility::{Annotation, Role};
     use crate::style::{Color, Modifier, Stylize};
 
     #[test]
@@ -660,6 +1308,71 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn content_graphemes_skips_wide_character_continuation() {
+        let buffer = Buffer::with_lines(["aコe\u{301}b", "xyz"]);
+        assert_eq!(
+            buffer.content_graphemes(0).collect_vec(),
+            [(0, "a"), (1, "コ"), (3, "e\u{301}"), (4, "b")]
+        );
+        assert_eq!(
+            buffer.content_graphemes(0).rev().collect_vec(),
+            [(4, "b"), (3, "e\u{301}"), (1, "コ"), (0, "a")]
+        );
+    }
+
+    #[test]
+    fn content_graphemes_with_offset_area() {
+        let mut buffer = Buffer::empty(Rect::new(2, 3, 3, 2));
+        buffer.set_string(2, 4, "🦀", Style::new());
+        assert_eq!(
+            buffer.content_graphemes(4).collect_vec(),
+            [(2, "🦀"), (4, " ")]
+        );
+        assert_eq!(buffer.content_graphemes(2).next(), None);
+        assert_eq!(buffer.content_graphemes(5).next(), None);
+    }
+
+    #[rstest]
+    #[case::grow_width(Rect::new(0, 0, 4, 2), ["ab  ", "cd  "])]
+    #[case::shrink_width(Rect::new(0, 0, 1, 2), ["a", "c"])]
+    #[case::grow_height(Rect::new(0, 0, 2, 3), ["ab", "cd", "  "])]
+    #[case::shrink_height(Rect::new(0, 0, 2, 1), ["ab"])]
+    #[case::grow_both(Rect::new(0, 0, 3, 3), ["ab ", "cd ", "   "])]
+    #[case::shrink_both(Rect::new(0, 0, 1, 1), ["a"])]
+    fn resize_preserves_top_left_content<'line, Lines>(#[case] area: Rect, #[case] expected: Lines)
+    where
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let mut buffer = Buffer::with_lines(["ab", "cd"]);
+        buffer[(1, 1)].set_fg(Color::Red);
+        buffer.resize(area);
+        let mut expected = Buffer::with_lines(expected);
+        if area.contains(Position::new(1, 1)) {
+            expected[(1, 1)].set_fg(Color::Red);
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn resize_moves_area() {
+        let mut buffer = Buffer::with_lines(["ab", "cd"]);
+        buffer.resize(Rect::new(5, 5, 3, 1));
+        let mut expected = Buffer::with_lines(["ab "]);
+        expected.area = Rect::new(5, 5, 3, 1);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn resize_from_and_to_empty_area() {
+        let mut buffer = Buffer::with_lines(["ab", "cd"]);
+        buffer.resize(Rect::new(0, 0, 0, 2));
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 0, 2)));
+        buffer.resize(Rect::new(0, 0, 2, 1));
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 2, 1)));
+    }
+
     #[cfg(feature = "underline-color")]
     #[test]
     fn debug_grapheme_override() {
@@ -802,6 +1515,17 @@ mod tests {
         assert_eq!(buf[(0, 0)], expected);
     }
 
+    #[test]
+    fn index_with_tuple() {
+        let mut buf = Buffer::empty(Rect::new(2, 3, 2, 2));
+        buf.set_string(2, 3, "ab", Style::new());
+        buf.set_string(2, 4, "cd", Style::new());
+        for position in buf.area.positions() {
+            assert_eq!(buf[(position.x, position.y)], buf[position]);
+        }
+        assert_eq!(buf[(3, 4)].symbol(), "d");
+    }
+
     #[rstest]
     #[case::left(9, 10)]
     #[case::top(10, 9)]
@@ -1012,6 +1736,151 @@ mod tests {
         assert_eq!(buffer.area.height, 4);
     }
 
+    #[test]
+    fn content_string() {
+        #[rustfmt::skip]
+        let buffer = Buffer::with_lines([
+            "Hello   ",
+            "        ",
+            "  a b   ",
+            "コンビ  ",
+        ]);
+        assert_eq!(buffer.content_string(buffer.area), "Hello\n\n  a b\nコンビ");
+    }
+
+    #[rstest]
+    #[case::inner_area(Rect::new(1, 0, 4, 3), "ello\n\n a b")]
+    #[case::starts_in_wide_char(Rect::new(1, 3, 4, 1), "ンビ")]
+    #[case::ends_in_wide_char(Rect::new(0, 3, 3, 1), "コン")]
+    #[case::clipped(Rect::new(6, 2, 10, 10), "\n")]
+    #[case::outside(Rect::new(20, 20, 5, 5), "")]
+    #[case::empty(Rect::new(0, 0, 0, 0), "")]
+    fn content_string_area(#[case] area: Rect, #[case] expected: &str) {
+        #[rustfmt::skip]
+        let buffer = Buffer::with_lines([
+            "Hello   ",
+            "        ",
+            "  a b   ",
+            "コンビ  ",
+        ]);
+        assert_eq!(buffer.content_string(area), expected);
+    }
+
+    #[test]
+    fn rows() {
+        let mut buffer = Buffer::empty(Rect::new(2, 3, 3, 3));
+        buffer.set_string(2, 3, "abc", Style::new());
+        buffer.set_string(2, 4, "d", Style::new());
+        buffer.set_string(2, 5, "コ", Style::new());
+        let rows: Vec<&[Cell]> = buffer.rows().collect();
+        assert_eq!(rows.len(), 3);
+        assert!(rows.iter().all(|row| row.len() == 3));
+        // the cell hidden by the wide character is blank
+        let symbols = |row: &[Cell]| row.iter().map(Cell::symbol).collect::<String>();
+        assert_eq!(
+            rows.into_iter().map(symbols).collect::<Vec<_>>(),
+            ["abc", "d  ", "コ  "]
+        );
+        assert_eq!(buffer.rows().next_back(), Some(&buffer.content[6..]));
+    }
+
+    #[test]
+    fn rows_empty() {
+        let buffer = Buffer::empty(Rect::new(0, 0, 0, 2));
+        assert_eq!(buffer.rows().collect::<Vec<_>>(), [&[], &[]]);
+        let buffer = Buffer::empty(Rect::new(0, 0, 2, 0));
+        assert_eq!(buffer.rows().count(), 0);
+    }
+
+    #[test]
+    fn content_string_ignores_styles() {
+        let buffer = Buffer::with_lines([Line::from(vec!["ab".red(), " ".into(), "c".on_blue()])]);
+        assert_eq!(buffer.content_string(buffer.area), "ab c");
+    }
+
+    #[test]
+    fn to_ansi_string_two_colors() {
+        let buffer = Buffer::with_lines([Line::from(vec!["ab".red(), "cd".on_blue()])]);
+        assert_eq!(buffer.to_ansi_string(), "\x1b[0;31mab\x1b[0;44mcd\x1b[0m");
+    }
+
+    #[test]
+    fn to_ansi_string_default_style() {
+        let buffer = Buffer::with_lines(["ab", "cd"]);
+        assert_eq!(buffer.to_ansi_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn to_ansi_string_resets_at_the_end_of_each_line() {
+        let mut buffer = Buffer::with_lines(["ab", "cd"]);
+        buffer.set_style(Rect::new(1, 0, 1, 2), Style::new().green());
+        assert_eq!(
+            buffer.to_ansi_string(),
+            "a\x1b[0;32mb\x1b[0m\nc\x1b[0;32md\x1b[0m"
+        );
+    }
+
+    #[rstest]
+    #[case::modifiers(Style::new().bold().italic(), "\x1b[0;1;3m")]
+    #[case::light_color(Style::new().light_cyan().on_dark_gray(), "\x1b[0;96;100m")]
+    #[case::indexed(Style::new().fg(Color::Indexed(208)), "\x1b[0;38;5;208m")]
+    #[case::rgb(Style::new().bg(Color::Rgb(1, 2, 3)), "\x1b[0;48;2;1;2;3m")]
+    fn to_ansi_string_style(#[case] style: Style, #[case] sequence: &str) {
+        let buffer = Buffer::with_lines([Line::styled("a", style)]);
+        assert_eq!(buffer.to_ansi_string(), format!("{sequence}a\x1b[0m"));
+    }
+
+    #[test]
+    fn to_ansi_string_skips_cells_hidden_by_wide_symbols() {
+        let buffer = Buffer::with_lines(["コa"]);
+        assert_eq!(buffer.to_ansi_string(), "コa");
+    }
+
+    #[test]
+    fn to_html() {
+        let buffer = Buffer::with_lines([
+            Line::from(vec!["a<".red().on_blue(), "&b".into()]),
+            Line::from("c>".bold().italic().underlined()),
+        ]);
+        assert_eq!(
+            buffer.to_html(),
+            "<pre><span style=\"color:#cd0000;background:#0000ee\">a&lt;</span>&amp;b\n\
+            <span style=\"font-weight:bold;font-style:italic;text-decoration:underline\">c&gt;\
+            </span>  </pre>"
+        );
+    }
+
+    #[test]
+    fn to_html_default_style() {
+        let buffer = Buffer::with_lines(["ab", "cd"]);
+        assert_eq!(buffer.to_html(), "<pre>ab\ncd</pre>");
+        assert_eq!(Buffer::default().to_html(), "<pre></pre>");
+    }
+
+    #[test]
+    fn to_svg() {
+        let buffer = Buffer::with_lines([
+            Line::from(vec!["a".red().on_blue(), " ".on_green(), "b".into()]),
+            Line::from("コ<"),
+        ]);
+        let svg = buffer.to_svg(10.0, 20.0);
+        assert!(svg
+            .starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"40\" "));
+        assert!(svg.ends_with("</svg>"));
+        assert_eq!(svg.matches("<rect").count(), 2);
+        assert_eq!(svg.matches("<text").count(), 4);
+        assert!(svg.contains(
+            "<rect x=\"0\" y=\"0\" width=\"10\" height=\"20\" fill=\"#0000ee\"/>\
+            <text x=\"0\" y=\"16\" fill=\"#cd0000\">a</text>"
+        ));
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"20\" fill=\"#00cd00\"/>")
+        );
+        assert!(svg.contains("<text x=\"20\" y=\"16\">b</text>"));
+        // the wide symbol spans two cells, the next symbol is escaped
+        assert!(svg.contains("<text x=\"0\" y=\"36\">コ</text><text x=\"20\" y=\"36\">&lt;</text>"));
+    }
+
     #[test]
     fn diff_empty_empty() {
         let area = Rect::new(0, 0, 40, 40);
@@ -1039,6 +1908,37 @@ mod tests {
         assert_eq!(diff, []);
     }
 
+    #[test]
+    fn diff_modifier_only() {
+        let prev = Buffer::with_lines(["abc"]);
+        let mut next = prev.clone();
+        next[(1, 0)].modifier = Modifier::ITALIC;
+        let diff = prev.diff(&next);
+        let mut expected = Cell::new("b");
+        expected.modifier = Modifier::ITALIC;
+        assert_eq!(diff, [(1, 0, &expected)]);
+    }
+
+    #[test]
+    fn diff_fg_and_bg_only() {
+        let prev = Buffer::with_lines(["abc"]);
+        let mut next = prev.clone();
+        next[(0, 0)].fg = Color::Red;
+        next[(2, 0)].bg = Color::Blue;
+        let diff = prev.diff(&next);
+        assert_eq!(diff, [(0, 0, &next[(0, 0)]), (2, 0, &next[(2, 0)])]);
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn diff_underline_color_only() {
+        let prev = Buffer::with_lines(["abc"]);
+        let mut next = prev.clone();
+        next[(2, 0)].underline_color = Color::Green;
+        let diff = prev.diff(&next);
+        assert_eq!(diff, [(2, 0, &next[(2, 0)])]);
+    }
+
     #[test]
     fn diff_single_width() {
         let prev = Buffer::with_lines([
@@ -1196,6 +2096,46 @@ mod tests {
         assert_eq!(skipped, expected);
     }
 
+    #[test]
+    fn merge_with_overwrite_keeps_cells_under_empty_cells() {
+        let mut one = Buffer::with_lines(["1111".on_blue(), "1111".on_blue()]);
+        let mut two = Buffer::empty(Rect::new(1, 0, 3, 3));
+        two.set_string(1, 0, "2", Style::new().on_red());
+        two.set_string(3, 2, "2", Style::new());
+        one.merge_with(&two, MergeMode::Overwrite);
+
+        let mut expected = Buffer::with_lines(["1211", "1111", "   2"]);
+        expected.set_style(Rect::new(0, 0, 4, 2), Style::new().on_blue());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().on_red());
+        assert_eq!(one, expected);
+    }
+
+    #[test]
+    fn merge_with_blend_background() {
+        let mut one = Buffer::with_lines(["11".bg(Color::Rgb(0, 0, 0))]);
+        let mut two = Buffer::empty(Rect::new(0, 0, 2, 1));
+        two.set_string(0, 0, "2", Style::new().red().bg(Color::Rgb(200, 100, 0)));
+        one.merge_with(&two, MergeMode::BlendBackground(0.25));
+
+        let mut expected = Buffer::with_lines(["21".bg(Color::Rgb(0, 0, 0))]);
+        expected.set_style(
+            Rect::new(0, 0, 1, 1),
+            Style::new().red().bg(Color::Rgb(50, 25, 0)),
+        );
+        assert_eq!(one, expected);
+    }
+
+    #[rstest]
+    #[case::transparent(0.0, Color::Blue)]
+    #[case::opaque(1.0, Color::Red)]
+    fn merge_with_blend_background_bounds(#[case] alpha: f32, #[case] expected: Color) {
+        let mut one = Buffer::with_lines(["1".on_blue()]);
+        let two = Buffer::with_lines(["2".on_red()]);
+        one.merge_with(&two, MergeMode::BlendBackground(alpha));
+        assert_eq!(one[(0, 0)].symbol(), "2");
+        assert_eq!(one[(0, 0)].bg, expected);
+    }
+
     #[test]
     fn with_lines_accepts_into_lines() {
         use crate::style::Stylize;
@@ -1295,4 +2235,57 @@ mod tests {
         assert_eq!(buffer.index_of(255, 256), 65791);
         assert_eq!(buffer.pos_of(65791), (255, 256)); // previously (255, 0)
     }
+
+    #[test]
+    fn reset_clears_accessibility_tree() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        buffer.enable_accessibility_tree(true);
+        let area = buffer.area;
+        if let Some(tree) = buffer.accessibility_tree_mut() {
+            tree.push(Annotation::new(Role::Text, "Hello", area));
+        }
+        buffer.reset();
+        assert_eq!(buffer.accessibility_tree(), Some(&AccessibilityTree::new()));
+
+        buffer.enable_accessibility_tree(false);
+        assert_eq!(buffer.accessibility_tree(), None);
+    }
+
+    #[test]
+    fn accessibility_tree_is_ignored_by_eq() {
+        let buffer = Buffer::with_lines(["Hello"]);
+        let mut annotated = buffer.clone();
+        annotated.enable_accessibility_tree(true);
+        if let Some(tree) = annotated.accessibility_tree_mut() {
+            tree.push(Annotation::new(Role::Text, "Hello", buffer.area));
+        }
+        assert_eq!(annotated, buffer);
+    }
+
+    #[rstest]
+    #[case::cross(&[" │ ", "─│─", " │ "], &[" │ ", "─┼─", " │ "])]
+    #[case::tee_right(&["│ ", "│─", "│ "], &["│ ", "├─", "│ "])]
+    #[case::tee_down(&["───", " │ "], &["─┬─", " │ "])]
+    #[case::corner(&["┌─┐", "│ │", "┌─┘"], &["┌─┐", "│ │", "├─┘"])]
+    #[case::rounded_corner(&["─╮", " │"], &["─╮", " │"])]
+    #[case::rounded_tee(&["─╮─"], &["─┬─"])]
+    #[case::double(&["═║═"], &["═╬═"])]
+    #[case::different_weights(&["═│═"], &["═│═"])]
+    #[case::parallel_lines(&["││", "││"], &["││", "││"])]
+    #[case::not_connected(&["│ ─", "x│x"], &["│ ─", "x│x"])]
+    fn merge_box_borders(#[case] lines: &[&str], #[case] expected: &[&str]) {
+        let mut buffer = Buffer::with_lines(lines.iter().copied());
+        buffer.merge_box_borders(buffer.area);
+        assert_eq!(buffer, Buffer::with_lines(expected.iter().copied()));
+        // joining the symbols again has no effect
+        buffer.merge_box_borders(buffer.area);
+        assert_eq!(buffer, Buffer::with_lines(expected.iter().copied()));
+    }
+
+    #[test]
+    fn merge_box_borders_only_in_area() {
+        let mut buffer = Buffer::with_lines([" │ ", "─│─", " │ "]);
+        buffer.merge_box_borders(Rect::new(1, 0, 2, 3));
+        assert_eq!(buffer, Buffer::with_lines([" │ ", "─├─", " │ "]));
+    }
 }
@@ -55,6 +55,47 @@ impl Cell {
         }
     }
 
+    /// Sets the foreground color of the cell.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::buffer::{Buffer, Cell};
+    /// use ratatui_core::layout::Rect;
+    /// use ratatui_core::style::{Color, Modifier};
+    ///
+    /// let cell = Cell::new("x")
+    ///     .fg(Color::Red)
+    ///     .bg(Color::Blue)
+    ///     .modifier(Modifier::BOLD);
+    /// let buffer = Buffer::filled(Rect::new(0, 0, 10, 10), cell);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = color;
+        self
+    }
+
+    /// Sets the background color of the cell.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = color;
+        self
+    }
+
+    /// Sets the modifier of the cell, replacing the current one.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn modifier(mut self, modifier: Modifier) -> Self {
+        self.modifier = modifier;
+        self
+    }
+
     /// Gets the symbol of the cell.
     #[must_use]
     pub fn symbol(&self) -> &str {
@@ -137,6 +178,14 @@ impl Cell {
         self
     }
 
+    /// Returns whether the cell is skipped when copying (diffing) the buffer to the screen.
+    ///
+    /// See [`Cell::set_skip`] for more information.
+    #[must_use]
+    pub const fn skip(&self) -> bool {
+        self.skip
+    }
+
     /// Resets the cell to the empty state.
     pub fn reset(&mut self) {
         self.symbol = CompactString::const_new(" ");
@@ -186,6 +235,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fluent_setters() {
+        let cell = Cell::new("x")
+            .fg(Color::Red)
+            .bg(Color::Blue)
+            .modifier(Modifier::BOLD | Modifier::ITALIC);
+        assert_eq!(cell.symbol(), "x");
+        assert_eq!(cell.fg, Color::Red);
+        assert_eq!(cell.bg, Color::Blue);
+        assert_eq!(cell.modifier, Modifier::BOLD | Modifier::ITALIC);
+        assert!(!cell.skip);
+
+        let cell = cell.modifier(Modifier::DIM);
+        assert_eq!(cell.modifier, Modifier::DIM);
+    }
+
     #[test]
     fn empty() {
         let cell = Cell::EMPTY;
@@ -243,6 +308,9 @@ mod tests {
         let mut cell = Cell::EMPTY;
         cell.set_skip(true);
         assert!(cell.skip);
+        assert!(cell.skip());
+        cell.set_skip(false);
+        assert!(!cell.skip());
     }
 
     #[test]
@@ -12,10 +12,10 @@ mod rect;
 mod size;
 
 pub use alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
-pub use constraint::Constraint;
+pub use constraint::{Constraint, ParseConstraintError};
 pub use direction::Direction;
 pub use flex::Flex;
-pub use layout::{Layout, Spacing};
+pub use layout::{Layout, LayoutError, Rounding, Spacing};
 pub use margin::Margin;
 pub use position::Position;
 pub use rect::{Columns, Offset, Positions, Rect, Rows};
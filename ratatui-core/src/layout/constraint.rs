@@ -1,5 +1,6 @@
 use alloc::vec::Vec;
 use core::fmt;
+use core::str::FromStr;
 
 use strum::EnumIs;
 
@@ -381,11 +382,99 @@ impl fmt::Display for Constraint {
     }
 }
 
+/// Error type indicating a failure to parse a constraint string.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParseConstraintError;
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse Constraint")
+    }
+}
+
+impl core::error::Error for ParseConstraintError {}
+
+/// Converts a string representation to a `Constraint` instance.
+///
+/// This is useful for layouts defined in configuration files. The following compact forms are
+/// supported:
+///
+/// - `"30%"`: [`Constraint::Percentage`]
+/// - `"1/3"`: [`Constraint::Ratio`]
+/// - `"10"`: [`Constraint::Length`]
+/// - `">=5"`: [`Constraint::Min`]
+/// - `"<=5"`: [`Constraint::Max`]
+/// - `"fill(2)"`: [`Constraint::Fill`]
+///
+/// The format used by the [`Display`](fmt::Display) implementation (e.g. `"Percentage(30)"` or
+/// `"Ratio(1, 3)"`) is also supported, with case-insensitive names. Whitespace around the values is
+/// ignored. If the string cannot be parsed, a [`ParseConstraintError`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use ratatui_core::layout::Constraint;
+///
+/// assert_eq!(Constraint::from_str("30%"), Ok(Constraint::Percentage(30)));
+/// assert_eq!(Constraint::from_str("10"), Ok(Constraint::Length(10)));
+/// assert_eq!(Constraint::from_str(">=5"), Ok(Constraint::Min(5)));
+/// assert_eq!(Constraint::from_str("fill(2)"), Ok(Constraint::Fill(2)));
+/// assert_eq!("Ratio(1, 3)".parse(), Ok(Constraint::Ratio(1, 3)));
+/// assert!(Constraint::from_str("10px").is_err());
+/// ```
+impl FromStr for Constraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(value) = s.strip_suffix('%') {
+            return parse_value(value).map(Self::Percentage);
+        }
+        if let Some(value) = s.strip_prefix(">=") {
+            return parse_value(value).map(Self::Min);
+        }
+        if let Some(value) = s.strip_prefix("<=") {
+            return parse_value(value).map(Self::Max);
+        }
+        if let Some((name, value)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+            return match name.trim().to_ascii_lowercase().as_str() {
+                "percentage" => parse_value(value).map(Self::Percentage),
+                "ratio" => parse_ratio(value, ','),
+                "length" => parse_value(value).map(Self::Length),
+                "min" => parse_value(value).map(Self::Min),
+                "max" => parse_value(value).map(Self::Max),
+                "fill" => parse_value(value).map(Self::Fill),
+                _ => Err(ParseConstraintError),
+            };
+        }
+        if s.contains('/') {
+            return parse_ratio(s, '/');
+        }
+        parse_value(s).map(Self::Length)
+    }
+}
+
+fn parse_value<T: FromStr>(value: &str) -> Result<T, ParseConstraintError> {
+    value.trim().parse().ok().ok_or(ParseConstraintError)
+}
+
+fn parse_ratio(value: &str, separator: char) -> Result<Constraint, ParseConstraintError> {
+    let (numerator, denominator) = value.split_once(separator).ok_or(ParseConstraintError)?;
+    Ok(Constraint::Ratio(
+        parse_value(numerator)?,
+        parse_value(denominator)?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
     use alloc::vec;
 
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -393,6 +482,56 @@ mod tests {
         assert_eq!(Constraint::default(), Constraint::Percentage(100));
     }
 
+    #[rstest]
+    #[case::percentage("30%", Constraint::Percentage(30))]
+    #[case::ratio("1/3", Constraint::Ratio(1, 3))]
+    #[case::length("10", Constraint::Length(10))]
+    #[case::min(">=5", Constraint::Min(5))]
+    #[case::max("<=5", Constraint::Max(5))]
+    #[case::fill("fill(2)", Constraint::Fill(2))]
+    #[case::whitespace(" >= 5 ", Constraint::Min(5))]
+    #[case::whitespace_ratio("1 / 3", Constraint::Ratio(1, 3))]
+    #[case::display_percentage("Percentage(30)", Constraint::Percentage(30))]
+    #[case::display_ratio("Ratio(1, 3)", Constraint::Ratio(1, 3))]
+    #[case::display_length("Length(10)", Constraint::Length(10))]
+    #[case::display_min("Min(5)", Constraint::Min(5))]
+    #[case::display_max("Max(5)", Constraint::Max(5))]
+    #[case::display_fill("Fill(2)", Constraint::Fill(2))]
+    #[case::uppercase("FILL(2)", Constraint::Fill(2))]
+    fn from_str(#[case] input: &str, #[case] expected: Constraint) {
+        assert_eq!(input.parse(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::unit("10px")]
+    #[case::negative("-1")]
+    #[case::overflow("65536")]
+    #[case::float("0.5%")]
+    #[case::missing_value(">=")]
+    #[case::unknown_function("grow(2)")]
+    #[case::unclosed_function("fill(2")]
+    #[case::missing_denominator("1/")]
+    #[case::missing_ratio_argument("Ratio(1)")]
+    fn from_str_error(#[case] input: &str) {
+        assert_eq!(input.parse::<Constraint>(), Err(ParseConstraintError));
+    }
+
+    #[test]
+    fn from_str_display_round_trip() {
+        let constraints = [
+            Constraint::Percentage(30),
+            Constraint::Ratio(1, 3),
+            Constraint::Length(10),
+            Constraint::Min(5),
+            Constraint::Max(5),
+            Constraint::Fill(2),
+        ];
+        for constraint in constraints {
+            assert_eq!(constraint.to_string().parse(), Ok(constraint));
+        }
+    }
+
     #[test]
     fn to_string() {
         assert_eq!(Constraint::Percentage(50).to_string(), "Percentage(50)");
@@ -1,13 +1,14 @@
 use alloc::rc::Rc;
 use alloc::vec::Vec;
-use core::iter;
 use core::num::NonZeroUsize;
+use core::{fmt, iter};
 
 use hashbrown::HashMap;
 use itertools::Itertools;
 use kasuari::WeightedRelation::{EQ, GE, LE};
 use kasuari::{AddConstraintError, Expression, Solver, Strength, Variable};
 use lru::LruCache;
+use strum::{Display, EnumString};
 
 use self::strengths::{
     ALL_SEGMENT_GROW, FILL_GROW, GROW, LENGTH_SIZE_EQ, MAX_SIZE_EQ, MAX_SIZE_LE, MIN_SIZE_EQ,
@@ -105,6 +106,83 @@ impl From<i16> for Spacing {
     }
 }
 
+/// Defines how the fractional sizes computed by a [`Layout`] are rounded to whole cells.
+///
+/// Constraints such as [`Constraint::Percentage`] or [`Constraint::Ratio`] often result in sizes
+/// that are not a whole number of cells (e.g. 33% of 10 cells is 3.3 cells). This controls how the
+/// leftover cells are allocated.
+///
+/// See the [`Layout::rounding`] method for details on how to use this enum.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Rounding {
+    /// Rounds the position of each edge to the nearest cell (default).
+    ///
+    /// The segments keep close to their exact positions, and the leftover cells end up in
+    /// whichever segments have an edge rounded up. For example, three `Percentage(33)` segments
+    /// in 10 cells have sizes 3, 4 and 3.
+    #[default]
+    Round,
+    /// Rounds the size of each segment down to a whole number of cells.
+    ///
+    /// The leftover cells are added to the space after the last segment. For example, three
+    /// `Percentage(33)` segments in 10 cells have sizes 3, 3 and 3.
+    Floor,
+    /// Rounds the size of each segment down, then spreads the leftover cells across the segments
+    /// from left to right (or top to bottom).
+    ///
+    /// Each segment with a fractional size receives at most one extra cell, so that the segments
+    /// together cover the same number of cells as with [`Rounding::Round`]. For example, three
+    /// `Percentage(33)` segments in 10 cells have sizes 4, 3 and 3.
+    DistributeRemainder,
+}
+
+/// An error describing why the constraints of a [`Layout`] can't be satisfied in an area.
+///
+/// This is returned by [`Layout::try_split`]. The sizes are measured along the direction of the
+/// layout, in cells.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LayoutError {
+    /// The constraints (and the spacing between them) require more space than is available.
+    ///
+    /// [`Layout::split`] shrinks some of the segments below their constraints to fit the area.
+    OverConstrained {
+        /// The minimum space required by the constraints
+        required: u32,
+        /// The space available in the area, without the margins
+        available: u16,
+    },
+    /// The constraints can't fill the available space, and the layout uses [`Flex::Legacy`].
+    ///
+    /// [`Layout::split`] stretches the last segment beyond its constraint to fill the area. The
+    /// other [`Flex`] modes distribute the excess space between the segments instead.
+    UnderConstrained {
+        /// The maximum space the constraints can fill
+        maximum: u32,
+        /// The space available in the area, without the margins
+        available: u16,
+    },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OverConstrained {
+                required,
+                available,
+            } => write!(
+                f,
+                "the constraints require at least {required} cells but only {available} are available"
+            ),
+            Self::UnderConstrained { maximum, available } => write!(
+                f,
+                "the constraints fill at most {maximum} of the {available} available cells"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for LayoutError {}
+
 /// A layout is a set of constraints that can be applied to a given area to split it into smaller
 /// ones.
 ///
@@ -115,6 +193,7 @@ impl From<i16> for Spacing {
 ///   areas
 /// - a flex option
 /// - a spacing option
+/// - a rounding option
 ///
 /// The algorithm used to compute the layout is based on the [`kasuari`] solver. It is a simple
 /// linear solver that can be used to solve linear equations and inequalities. In our case, we
@@ -146,6 +225,7 @@ impl From<i16> for Spacing {
 /// - [`Layout::vertical_margin`]: set the vertical margin of the layout
 /// - [`Layout::flex`]: set the way the space is distributed when the constraints are satisfied
 /// - [`Layout::spacing`]: sets the gap between the constraints of the layout
+/// - [`Layout::rounding`]: sets how fractional sizes are rounded to whole cells
 ///
 /// # Example
 ///
@@ -178,6 +258,7 @@ pub struct Layout {
     margin: Margin,
     flex: Flex,
     spacing: Spacing,
+    rounding: Rounding,
 }
 
 impl Layout {
@@ -202,6 +283,7 @@ impl Layout {
     /// - `margin`: 0, 0
     /// - `flex`: [`Flex::Start`]
     /// - `spacing`: 0
+    /// - `rounding`: [`Rounding::Round`]
     ///
     /// # Examples
     ///
@@ -275,6 +357,56 @@ impl Layout {
         )
     }
 
+    /// Splits the area into a grid of cells with the given row and column constraints.
+    ///
+    /// This is equivalent to splitting the area with a vertical layout of the `rows` and with a
+    /// horizontal layout of the `columns`, and intersecting each row with each column. The result
+    /// is indexed by row then column, i.e. `grid[row][column]`.
+    ///
+    /// The `rows` and `columns` parameters accept any type that implements `IntoIterator<Item =
+    /// Into<Constraint>>`, like [`Layout::vertical`] and [`Layout::horizontal`]. The layouts use
+    /// the default values for the other options. To configure them (e.g. the spacing between the
+    /// cells), split the area with the two layouts and combine the results instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 4);
+    /// let grid = Layout::grid(area, [Constraint::Length(1); 2], [Constraint::Fill(1); 2]);
+    /// assert_eq!(
+    ///     grid,
+    ///     [
+    ///         [Rect::new(0, 0, 5, 1), Rect::new(5, 0, 5, 1)],
+    ///         [Rect::new(0, 1, 5, 1), Rect::new(5, 1, 5, 1)],
+    ///     ]
+    /// );
+    /// ```
+    pub fn grid<R, C>(area: Rect, rows: R, columns: C) -> Vec<Vec<Rect>>
+    where
+        R: IntoIterator,
+        R::Item: Into<Constraint>,
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+    {
+        let rows = Self::vertical(rows).split(area);
+        let columns = Self::horizontal(columns).split(area);
+        rows.iter()
+            .map(|row| {
+                columns
+                    .iter()
+                    .map(|column| Rect {
+                        x: column.x,
+                        y: row.y,
+                        width: column.width,
+                        height: row.height,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Initialize an empty cache with a custom size. The cache is keyed on the layout and area, so
     /// that subsequent calls with the same parameters are faster. The cache is a `LruCache`, and
     /// grows until `cache_size` is reached.
@@ -505,6 +637,40 @@ impl Layout {
         self
     }
 
+    /// Sets how the fractional sizes of the segments are rounded to whole cells.
+    ///
+    /// Percentages, ratios and the distribution of the excess space often result in segments with
+    /// a fractional size. By default, the edges of the segments are rounded to the nearest cell
+    /// ([`Rounding::Round`]). See [`Rounding`] for the other modes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Constraint::*;
+    /// use ratatui_core::layout::{Layout, Rect, Rounding};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let layout = Layout::horizontal([Percentage(33); 3]);
+    /// let widths = |layout: Layout| {
+    ///     layout
+    ///         .split(area)
+    ///         .iter()
+    ///         .map(|r| r.width)
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(widths(layout.clone()), [3, 4, 3]);
+    /// assert_eq!(widths(layout.clone().rounding(Rounding::Floor)), [3, 3, 3]);
+    /// assert_eq!(
+    ///     widths(layout.rounding(Rounding::DistributeRemainder)),
+    ///     [4, 3, 3]
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
     /// Split the rect into a number of sub-rects according to the given [`Layout`].
     ///
     /// An ergonomic wrapper around [`Layout::split`] that returns an array of `Rect`s instead of
@@ -520,7 +686,7 @@ impl Layout {
     /// # Examples
     ///
     /// ```rust
-    /// use ratatui_core::layout::{Layout, Constraint, Rect};
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
     ///
     /// let area = Rect::new(0, 0, 10, 10);
     /// let layout = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
@@ -528,9 +694,15 @@ impl Layout {
     ///
     /// // or explicitly specify the number of constraints:
     /// let areas = layout.areas::<2>(area);
+    /// ```
     pub fn areas<const N: usize>(&self, area: Rect) -> [Rect; N] {
         let (areas, _) = self.split_with_spacers(area);
-        areas.as_ref().try_into().expect("invalid number of rects")
+        areas.as_ref().try_into().unwrap_or_else(|_| {
+            panic!(
+                "invalid number of rects: expected {N}, but the layout has {} constraints",
+                areas.len()
+            )
+        })
     }
 
     /// Split the rect into a number of sub-rects according to the given [`Layout`] and return just
@@ -563,10 +735,12 @@ impl Layout {
     /// ```
     pub fn spacers<const N: usize>(&self, area: Rect) -> [Rect; N] {
         let (_, spacers) = self.split_with_spacers(area);
-        spacers
-            .as_ref()
-            .try_into()
-            .expect("invalid number of rects")
+        spacers.as_ref().try_into().unwrap_or_else(|_| {
+            panic!(
+                "invalid number of rects: expected {N}, but the layout has {} spacers",
+                spacers.len()
+            )
+        })
     }
 
     /// Wrapper function around the cassowary solver to be able to split a given area into
@@ -607,6 +781,44 @@ impl Layout {
         self.split_with_spacers(area).0
     }
 
+    /// Split the rect into a number of sub-rects according to the given [`Layout`], writing them
+    /// into the given slice.
+    ///
+    /// This is equivalent to [`Layout::split`], but writes the areas in a slice provided by the
+    /// caller instead of returning a `Rc<[Rect]>`. When the layout is cached, this does not
+    /// allocate, which is useful when splitting areas in a hot loop. The areas are written to the
+    /// start of the slice, and the number of areas written (i.e. the number of constraints) is
+    /// returned. The rest of the slice is left untouched.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is shorter than the number of constraints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::{Constraint, Layout, Rect};
+    ///
+    /// let layout = Layout::vertical([Constraint::Length(5), Constraint::Min(0)]);
+    /// let mut areas = [Rect::ZERO; 4];
+    /// let count = layout.split_into(Rect::new(0, 0, 10, 10), &mut areas);
+    /// assert_eq!(
+    ///     areas[..count],
+    ///     [Rect::new(0, 0, 10, 5), Rect::new(0, 5, 10, 5)]
+    /// );
+    /// ```
+    pub fn split_into(&self, area: Rect, out: &mut [Rect]) -> usize {
+        let (areas, _) = self.split_with_spacers(area);
+        assert!(
+            out.len() >= areas.len(),
+            "invalid number of rects: expected at least {}, but the slice has room for {}",
+            areas.len(),
+            out.len()
+        );
+        out[..areas.len()].copy_from_slice(&areas);
+        areas.len()
+    }
+
     /// Wrapper function around the cassowary solver that splits the given area into smaller ones
     /// based on the preferred widths or heights and the direction, with the ability to include
     /// spacers between the areas.
@@ -654,7 +866,7 @@ impl Layout {
     /// );
     /// ```
     pub fn split_with_spacers(&self, area: Rect) -> (Segments, Spacers) {
-        let split = || self.try_split(area).expect("failed to split");
+        let split = || self.solve(area).expect("failed to split");
 
         #[cfg(feature = "layout-cache")]
         {
@@ -668,7 +880,90 @@ impl Layout {
         split()
     }
 
-    fn try_split(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
+    /// Split the rect into a number of sub-rects according to the given [`Layout`], or return an
+    /// error if the constraints can't be satisfied in the area.
+    ///
+    /// This is equivalent to [`Layout::split`], which instead resolves the conflicts by clamping
+    /// the segments, but first checks that:
+    ///
+    /// - the constraints and the spacing fit in the area, i.e. the sum of the lengths, minimums,
+    ///   percentages and ratios does not exceed its size ([`LayoutError::OverConstrained`]);
+    /// - with [`Flex::Legacy`], the constraints can fill the area, i.e. it is not larger than the
+    ///   sum of their maximum sizes ([`LayoutError::UnderConstrained`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Constraint, Layout, LayoutError, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// let layout = Layout::horizontal([Constraint::Length(100)]);
+    /// assert_eq!(
+    ///     layout.try_split(area),
+    ///     Err(LayoutError::OverConstrained {
+    ///         required: 100,
+    ///         available: 10
+    ///     })
+    /// );
+    ///
+    /// let layout = Layout::horizontal([Constraint::Length(4), Constraint::Min(0)]);
+    /// assert_eq!(
+    ///     layout.try_split(area).unwrap()[..],
+    ///     [Rect::new(0, 0, 4, 1), Rect::new(4, 0, 6, 1)]
+    /// );
+    /// ```
+    pub fn try_split(&self, area: Rect) -> Result<Rects, LayoutError> {
+        let inner_area = area.inner(self.margin);
+        let available = match self.direction {
+            Direction::Horizontal => inner_area.width,
+            Direction::Vertical => inner_area.height,
+        };
+        let size = u64::from(available);
+        let gaps = self.constraints.len().saturating_sub(1) as i64;
+        let spacing = match self.spacing {
+            Spacing::Space(space) => i64::from(space) * gaps,
+            Spacing::Overlap(overlap) => -i64::from(overlap) * gaps,
+        };
+        let (mut required, mut maximum) = (spacing, Some(spacing));
+        for constraint in &self.constraints {
+            let (min, max) = match *constraint {
+                Constraint::Min(min) => (u64::from(min), None),
+                Constraint::Max(max) => (0, Some(u64::from(max))),
+                Constraint::Length(length) => (u64::from(length), Some(u64::from(length))),
+                Constraint::Percentage(percentage) => {
+                    let length = size * u64::from(percentage) / 100;
+                    (length, Some(length))
+                }
+                Constraint::Ratio(numerator, denominator) => {
+                    let length = size * u64::from(numerator) / u64::from(denominator).max(1);
+                    (length, Some(length))
+                }
+                Constraint::Fill(_) => (0, None),
+            };
+            required = required.saturating_add_unsigned(min);
+            maximum = maximum
+                .zip(max)
+                .map(|(sum, max)| sum.saturating_add_unsigned(max));
+        }
+        let to_cells = |cells: i64| u32::try_from(cells.max(0)).unwrap_or(u32::MAX);
+        if required > i64::from(available) {
+            return Err(LayoutError::OverConstrained {
+                required: to_cells(required),
+                available,
+            });
+        }
+        if let Some(maximum) = maximum.filter(|_| self.flex == Flex::Legacy) {
+            if !self.constraints.is_empty() && maximum < i64::from(available) {
+                return Err(LayoutError::UnderConstrained {
+                    maximum: to_cells(maximum),
+                    available,
+                });
+            }
+        }
+        Ok(self.split(area))
+    }
+
+    fn solve(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
         // To take advantage of all of cassowary features, we would want to store the `Solver` in
         // one of the fields of the Layout struct. And we would want to set it up such that we could
         // add or remove constraints as and when needed.
@@ -685,8 +980,8 @@ impl Layout {
         // To minimize the time it takes to solve the same problem over and over again, we
         // cache the `Layout` struct along with the results.
         //
-        // `try_split` is the inner method in `split` that is called only when the LRU cache doesn't
-        // match the key. So inside `try_split`, we create a new instance of the solver.
+        // `solve` is the inner method in `split` that is called only when the LRU cache doesn't
+        // match the key. So inside `solve`, we create a new instance of the solver.
         //
         // This is equivalent to storing the solver in `Layout` and calling `solver.reset()` here.
         let mut solver = Solver::new();
@@ -766,8 +1061,13 @@ impl Layout {
         // debug_elements(&segments, &changes);
         // debug_elements(&spacers, &changes);
 
-        let segment_rects = changes_to_rects(&changes, &segments, inner_area, self.direction);
-        let spacer_rects = changes_to_rects(&changes, &spacers, inner_area, self.direction);
+        let edges = match self.rounding {
+            Rounding::Round => round_edges(&changes, &variables),
+            Rounding::Floor => floor_edges(&changes, &variables, false),
+            Rounding::DistributeRemainder => floor_edges(&changes, &variables, true),
+        };
+        let segment_rects = edges_to_rects(edges.iter().skip(1), inner_area, self.direction);
+        let spacer_rects = edges_to_rects(edges.iter(), inner_area, self.direction);
 
         Ok((segment_rects, spacer_rects))
     }
@@ -982,20 +1282,85 @@ fn configure_fill_constraints(
     Ok(())
 }
 
-fn changes_to_rects(
+/// Returns the exact position of each variable, in cells
+fn variable_positions<'a>(
+    changes: &'a HashMap<Variable, f64>,
+    variables: &'a [Variable],
+) -> impl Iterator<Item = f64> + 'a {
+    variables
+        .iter()
+        .map(|variable| changes.get(variable).unwrap_or(&0.0).round() / FLOAT_PRECISION_MULTIPLIER)
+}
+
+/// Rounds the position of each edge to the nearest cell
+fn round_edges(changes: &HashMap<Variable, f64>, variables: &[Variable]) -> Vec<u16> {
+    variable_positions(changes, variables)
+        .map(|position| position.round() as u16)
+        .collect()
+}
+
+/// Rounds the size of each segment down, optionally distributing the leftover cells to the
+/// segments with a fractional size
+///
+/// The variables delimit the spacers and the segments in turn. The spacers are rounded to the
+/// nearest cell, and the last spacer takes the space left at the end of the area.
+fn floor_edges(
     changes: &HashMap<Variable, f64>,
-    elements: &[Element],
+    variables: &[Variable],
+    distribute_remainder: bool,
+) -> Vec<u16> {
+    let positions = variable_positions(changes, variables).collect_vec();
+    let (Some(&area_start), Some(&area_end)) = (positions.first(), positions.last()) else {
+        return Vec::new();
+    };
+    let exact_sizes = positions.windows(2).map(|edges| edges[1] - edges[0]);
+    let mut sizes = exact_sizes
+        .clone()
+        .enumerate()
+        .map(|(i, size)| {
+            if i % 2 == 0 {
+                size.round()
+            } else {
+                size.floor()
+            }
+        })
+        .collect_vec();
+    if distribute_remainder {
+        let segments = || exact_sizes.clone().skip(1).step_by(2);
+        let total: f64 = segments().sum();
+        let floored: f64 = sizes.iter().skip(1).step_by(2).sum();
+        let mut remainder = total.round() - floored;
+        for (size, exact) in sizes.iter_mut().skip(1).step_by(2).zip(segments()) {
+            if remainder < 1.0 {
+                break;
+            }
+            if exact.fract() > 0.0 {
+                *size += 1.0;
+                remainder -= 1.0;
+            }
+        }
+    }
+    let mut edges = Vec::with_capacity(positions.len());
+    let mut position = area_start.round();
+    edges.push(position as u16);
+    for size in &sizes[..sizes.len() - 1] {
+        position = (position + size).clamp(area_start, area_end);
+        edges.push(position as u16);
+    }
+    // the last spacer takes the rest of the area
+    edges.push(area_end.round() as u16);
+    edges
+}
+
+/// Converts pairs of consecutive edges into `Rect`s
+fn edges_to_rects<'a>(
+    edges: impl Iterator<Item = &'a u16>,
     area: Rect,
     direction: Direction,
 ) -> Rects {
-    // convert to Rects
-    elements
-        .iter()
-        .map(|element| {
-            let start = changes.get(&element.start).unwrap_or(&0.0);
-            let end = changes.get(&element.end).unwrap_or(&0.0);
-            let start = (start.round() / FLOAT_PRECISION_MULTIPLIER).round() as u16;
-            let end = (end.round() / FLOAT_PRECISION_MULTIPLIER).round() as u16;
+    edges
+        .tuples()
+        .map(|(&start, &end)| {
             let size = end.saturating_sub(start);
             match direction {
                 Direction::Horizontal => Rect {
@@ -1188,6 +1553,8 @@ mod tests {
     use alloc::vec;
     use alloc::vec::Vec;
 
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -1231,6 +1598,7 @@ mod tests {
                 constraints: vec![],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                rounding: Rounding::default(),
             }
         );
     }
@@ -1276,6 +1644,7 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                rounding: Rounding::default(),
             }
         );
     }
@@ -1290,6 +1659,7 @@ mod tests {
                 constraints: vec![Constraint::Min(0)],
                 flex: Flex::default(),
                 spacing: Spacing::default(),
+                rounding: Rounding::default(),
             }
         );
     }
@@ -1389,6 +1759,183 @@ mod tests {
         assert_eq!(Layout::default().flex(Flex::Center).flex, Flex::Center);
     }
 
+    #[test]
+    fn areas() {
+        let layout = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ]);
+        let [header, body, footer] = layout.areas(Rect::new(0, 0, 10, 10));
+        assert_eq!(header, Rect::new(0, 0, 10, 1));
+        assert_eq!(body, Rect::new(0, 1, 10, 7));
+        assert_eq!(footer, Rect::new(0, 8, 10, 2));
+    }
+
+    #[test]
+    #[should_panic = "invalid number of rects: expected 2, but the layout has 3 constraints"]
+    fn areas_invalid_number_of_rects() {
+        let layout = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ]);
+        let [_header, _body] = layout.areas(Rect::new(0, 0, 10, 10));
+    }
+
+    #[test]
+    #[should_panic = "invalid number of rects: expected 3, but the layout has 4 spacers"]
+    fn spacers_invalid_number_of_rects() {
+        let layout = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ]);
+        let [_before, _between, _after] = layout.spacers(Rect::new(0, 0, 10, 10));
+    }
+
+    #[test]
+    fn grid() {
+        let grid = Layout::grid(
+            Rect::new(1, 2, 20, 10),
+            [Constraint::Length(3), Constraint::Fill(1)],
+            [
+                Constraint::Length(4),
+                Constraint::Percentage(50),
+                Constraint::Min(0),
+            ],
+        );
+        assert_eq!(
+            grid,
+            [
+                [
+                    Rect::new(1, 2, 4, 3),
+                    Rect::new(5, 2, 10, 3),
+                    Rect::new(15, 2, 6, 3),
+                ],
+                [
+                    Rect::new(1, 5, 4, 7),
+                    Rect::new(5, 5, 10, 7),
+                    Rect::new(15, 5, 6, 7),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn grid_without_constraints() {
+        let area = Rect::new(0, 0, 10, 10);
+        let no_constraints: [Constraint; 0] = [];
+        assert_eq!(
+            Layout::grid(area, [Constraint::Min(0)], no_constraints),
+            [vec![]]
+        );
+        assert!(Layout::grid(area, no_constraints, [Constraint::Min(0)]).is_empty());
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(Layout::default().rounding, Rounding::Round);
+        assert_eq!(
+            Layout::default().rounding(Rounding::Floor).rounding,
+            Rounding::Floor
+        );
+    }
+
+    #[rstest]
+    #[case::round_thirds(Rounding::Round, &[Constraint::Percentage(33); 3], 10, &[3, 4, 3])]
+    #[case::floor_thirds(Rounding::Floor, &[Constraint::Percentage(33); 3], 10, &[3, 3, 3])]
+    #[case::distribute_thirds(Rounding::DistributeRemainder, &[Constraint::Percentage(33); 3], 10, &[4, 3, 3])]
+    #[case::round_sevenths(Rounding::Round, &[Constraint::Percentage(14); 7], 10, &[1, 2, 1, 2, 1, 1, 2])]
+    #[case::floor_sevenths(Rounding::Floor, &[Constraint::Percentage(14); 7], 10, &[1, 1, 1, 1, 1, 1, 1])]
+    #[case::distribute_sevenths(
+        Rounding::DistributeRemainder,
+        &[Constraint::Percentage(14); 7],
+        10,
+        &[2, 2, 2, 1, 1, 1, 1]
+    )]
+    #[case::distribute_skips_whole_sizes(
+        Rounding::DistributeRemainder,
+        &[Constraint::Length(2), Constraint::Percentage(25), Constraint::Percentage(25)],
+        10,
+        &[2, 3, 2]
+    )]
+    #[case::floor_even(Rounding::Floor, &[Constraint::Percentage(50); 2], 10, &[5, 5])]
+    fn split_with_rounding(
+        #[case] rounding: Rounding,
+        #[case] constraints: &[Constraint],
+        #[case] width: u16,
+        #[case] expected: &[u16],
+    ) {
+        let area = Rect::new(2, 1, width, 1);
+        let rects = Layout::horizontal(constraints.iter().copied())
+            .rounding(rounding)
+            .split(area);
+        let widths = rects.iter().map(|rect| rect.width).collect_vec();
+        assert_eq!(widths, expected);
+        // the segments are contiguous and start at the start of the area
+        let mut x = area.x;
+        for rect in rects.iter() {
+            assert_eq!(rect.x, x);
+            x += rect.width;
+        }
+    }
+
+    #[rstest]
+    #[case::floor(Rounding::Floor, [(0, 3), (3, 3), (6, 3)])]
+    #[case::distribute_remainder(Rounding::DistributeRemainder, [(0, 4), (4, 3), (7, 3)])]
+    fn split_vertical_with_rounding(#[case] rounding: Rounding, #[case] expected: [(u16, u16); 3]) {
+        let rects = Layout::vertical([Constraint::Percentage(33); 3])
+            .rounding(rounding)
+            .split(Rect::new(0, 0, 1, 10));
+        let rows = rects.iter().map(|rect| (rect.y, rect.height)).collect_vec();
+        assert_eq!(rows, expected);
+    }
+
+    #[test]
+    fn split_with_rounding_and_spacing() {
+        let (areas, spacers) = Layout::horizontal([Constraint::Percentage(33); 3])
+            .spacing(1)
+            .rounding(Rounding::Floor)
+            .split_with_spacers(Rect::new(0, 0, 12, 1));
+        let areas = areas.iter().map(|rect| (rect.x, rect.width)).collect_vec();
+        let spacers = spacers
+            .iter()
+            .map(|rect| (rect.x, rect.width))
+            .collect_vec();
+        assert_eq!(areas, [(0, 3), (4, 3), (8, 3)]);
+        assert_eq!(spacers, [(0, 0), (3, 1), (7, 1), (11, 1)]);
+    }
+
+    #[rstest]
+    #[case::exact_length(3)]
+    #[case::longer_slice(5)]
+    fn split_into(#[case] length: usize) {
+        let layout = Layout::horizontal([
+            Constraint::Length(2),
+            Constraint::Fill(1),
+            Constraint::Percentage(30),
+        ])
+        .spacing(1);
+        let area = Rect::new(1, 1, 20, 3);
+        let mut areas = vec![Rect::ZERO; length];
+        let count = layout.split_into(area, &mut areas);
+        assert_eq!(count, 3);
+        assert_eq!(areas[..count], layout.split(area)[..]);
+        assert!(areas[count..].iter().all(|&area| area == Rect::ZERO));
+    }
+
+    #[test]
+    #[should_panic = "invalid number of rects: expected at least 3, but the slice has room for 2"]
+    fn split_into_slice_too_short() {
+        let layout = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ]);
+        layout.split_into(Rect::new(0, 0, 10, 10), &mut [Rect::ZERO; 2]);
+    }
+
     #[test]
     fn spacing() {
         assert_eq!(Layout::default().spacing(10).spacing, Spacing::Space(10));
@@ -2588,6 +3135,31 @@ mod tests {
             assert_eq!(result, expected);
         }
 
+        #[rstest]
+        #[case::two_segments(vec![Length(10), Length(10)], 100)]
+        #[case::three_segments(vec![Length(10), Length(20), Length(5)], 100)]
+        #[case::uneven_gaps(vec![Length(1), Length(1), Length(1)], 10)]
+        #[case::mixed(vec![Length(10), Percentage(20), Min(5)], 77)]
+        #[case::no_room(vec![Length(60), Length(60)], 100)]
+        fn split_with_spacers_tile_space_between(
+            #[case] constraints: Vec<Constraint>,
+            #[case] width: u16,
+        ) {
+            let rect = Rect::new(3, 0, width, 1);
+            let (segments, spacers) = Layout::horizontal(&constraints)
+                .flex(Flex::SpaceBetween)
+                .split_with_spacers(rect);
+            assert_eq!(spacers.len(), segments.len() + 1);
+            assert_eq!(spacers[0].x, rect.left());
+            for (index, segment) in segments.iter().enumerate() {
+                assert_eq!(spacers[index].right(), segment.left());
+                assert_eq!(segment.right(), spacers[index + 1].left());
+            }
+            assert_eq!(spacers[spacers.len() - 1].right(), rect.right());
+            let total_width: u16 = segments.iter().chain(spacers.iter()).map(|r| r.width).sum();
+            assert_eq!(total_width, width);
+        }
+
         #[rstest]
         #[case::spacers(vec![(0, 0), (10, 5), (100, 0)], vec![Length(10), Length(10)], Flex::Legacy, 5)]
         #[case::spacers(vec![(0, 0), (10, 80), (100, 0)], vec![Length(10), Length(10)], Flex::SpaceBetween, 5)]
@@ -2686,5 +3258,80 @@ mod tests {
                 .collect::<Vec<(u16, u16)>>();
             assert_eq!(result, expected);
         }
+
+        #[rstest]
+        #[case::length(vec![Length(100)], 0, 100)]
+        #[case::lengths(vec![Length(6), Length(6)], 0, 12)]
+        #[case::min(vec![Min(8), Length(3)], 0, 11)]
+        #[case::percentages(vec![Percentage(60), Percentage(50)], 0, 11)]
+        #[case::spacing(vec![Length(4), Length(4)], 3, 11)]
+        fn try_split_over_constrained(
+            #[case] constraints: Vec<Constraint>,
+            #[case] spacing: u16,
+            #[case] required: u32,
+        ) {
+            let layout = Layout::horizontal(constraints).spacing(spacing);
+            assert_eq!(
+                layout.try_split(Rect::new(0, 0, 10, 1)),
+                Err(LayoutError::OverConstrained {
+                    required,
+                    available: 10
+                })
+            );
+        }
+
+        #[rstest]
+        #[case::lengths(vec![Length(5), Length(5)], 0, Flex::Legacy)]
+        #[case::fill(vec![Length(5), Fill(1)], 0, Flex::Legacy)]
+        #[case::min(vec![Length(2), Min(2)], 0, Flex::Legacy)]
+        #[case::percentages(vec![Percentage(50), Percentage(50)], 0, Flex::Legacy)]
+        #[case::overlap(vec![Length(6), Length(6)], -2, Flex::Legacy)]
+        #[case::start(vec![Length(2), Length(2)], 0, Flex::Start)]
+        #[case::empty(vec![], 0, Flex::Legacy)]
+        fn try_split_satisfiable(
+            #[case] constraints: Vec<Constraint>,
+            #[case] spacing: i16,
+            #[case] flex: Flex,
+        ) {
+            let area = Rect::new(0, 0, 10, 1);
+            let layout = Layout::horizontal(constraints).flex(flex).spacing(spacing);
+            assert_eq!(layout.try_split(area), Ok(layout.split(area)));
+        }
+
+        #[test]
+        fn try_split_under_constrained() {
+            let layout = Layout::vertical([Length(2), Max(3)]).flex(Flex::Legacy);
+            let area = Rect::new(0, 0, 10, 10);
+            assert_eq!(
+                layout.try_split(area),
+                Err(LayoutError::UnderConstrained {
+                    maximum: 5,
+                    available: 10
+                })
+            );
+            // the margins are not available to the constraints
+            let layout = layout.margin(3);
+            assert_eq!(layout.try_split(area), Ok(layout.split(area)));
+        }
+
+        #[test]
+        fn layout_error_display() {
+            let error = LayoutError::OverConstrained {
+                required: 12,
+                available: 10,
+            };
+            assert_eq!(
+                error.to_string(),
+                "the constraints require at least 12 cells but only 10 are available"
+            );
+            let error = LayoutError::UnderConstrained {
+                maximum: 5,
+                available: 10,
+            };
+            assert_eq!(
+                error.to_string(),
+                "the constraints fill at most 5 of the 10 available cells"
+            );
+        }
     }
 }
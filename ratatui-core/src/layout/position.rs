@@ -47,6 +47,141 @@ impl Position {
     pub const fn new(x: u16, y: u16) -> Self {
         Self { x, y }
     }
+
+    /// Returns the Manhattan distance to the other position
+    ///
+    /// This is the number of cells to move through to reach the other position when only
+    /// horizontal and vertical moves are allowed (i.e. the sum of the horizontal and vertical
+    /// distances). The distance is returned as a `u32` so that it can't overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Position;
+    ///
+    /// let distance = Position::new(1, 2).manhattan_distance(Position::new(4, 0));
+    /// assert_eq!(distance, 5);
+    /// ```
+    pub const fn manhattan_distance(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x) as u32 + self.y.abs_diff(other.y) as u32
+    }
+
+    /// Returns the Chebyshev distance to the other position
+    ///
+    /// This is the number of cells to move through to reach the other position when diagonal
+    /// moves are allowed too (i.e. the largest of the horizontal and vertical distances). The
+    /// distance is returned as a `u32` for consistency with [`Position::manhattan_distance`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Position;
+    ///
+    /// let distance = Position::new(1, 2).chebyshev_distance(Position::new(4, 0));
+    /// assert_eq!(distance, 3);
+    /// ```
+    pub const fn chebyshev_distance(self, other: Self) -> u32 {
+        let x = self.x.abs_diff(other.x);
+        let y = self.y.abs_diff(other.y);
+        if x > y {
+            x as u32
+        } else {
+            y as u32
+        }
+    }
+
+    /// Returns an iterator over the 4 orthogonally adjacent positions
+    ///
+    /// The positions are yielded clockwise, starting from the one above. Neighbors which would be
+    /// outside of the range of `u16` (e.g. on the left of `x = 0`) are skipped, so positions on
+    /// the edges have fewer neighbors. See [`Position::neighbors4_in`] to only yield the neighbors
+    /// within an area (e.g. for a flood fill).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Position;
+    ///
+    /// let neighbors: Vec<_> = Position::new(0, 5).neighbors4().collect();
+    /// assert_eq!(
+    ///     neighbors,
+    ///     [
+    ///         Position::new(0, 4),
+    ///         Position::new(1, 5),
+    ///         Position::new(0, 6)
+    ///     ]
+    /// );
+    /// ```
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        const OFFSETS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+        OFFSETS
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset_by(dx, dy))
+    }
+
+    /// Returns an iterator over the 8 adjacent positions, including the diagonal ones
+    ///
+    /// The positions are yielded clockwise, starting from the one above on the left. Neighbors
+    /// which would be outside of the range of `u16` are skipped. See [`Position::neighbors8_in`]
+    /// to only yield the neighbors within an area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Position;
+    ///
+    /// assert_eq!(Position::new(3, 3).neighbors8().count(), 8);
+    /// assert_eq!(Position::ORIGIN.neighbors8().count(), 3);
+    /// ```
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        const OFFSETS: [(i32, i32); 8] = [
+            (-1, -1),
+            (0, -1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+        ];
+        OFFSETS
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset_by(dx, dy))
+    }
+
+    /// Returns an iterator over the orthogonally adjacent positions which are within the area
+    ///
+    /// This is [`Position::neighbors4`] restricted to the positions contained in `area`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Position, Rect};
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(Position::new(9, 9).neighbors4_in(area).count(), 2);
+    /// ```
+    pub fn neighbors4_in(self, area: Rect) -> impl Iterator<Item = Self> {
+        self.neighbors4()
+            .filter(move |position| area.contains(*position))
+    }
+
+    /// Returns an iterator over the adjacent positions, including the diagonal ones, which are
+    /// within the area
+    ///
+    /// This is [`Position::neighbors8`] restricted to the positions contained in `area`.
+    pub fn neighbors8_in(self, area: Rect) -> impl Iterator<Item = Self> {
+        self.neighbors8()
+            .filter(move |position| area.contains(*position))
+    }
+
+    /// Returns the position moved by the given offsets, or `None` if it is outside of the range
+    /// of `u16`
+    fn offset_by(self, dx: i32, dy: i32) -> Option<Self> {
+        let x = u16::try_from(i32::from(self.x) + dx).ok()?;
+        let y = u16::try_from(i32::from(self.y) + dy).ok()?;
+        Some(Self { x, y })
+    }
 }
 
 impl From<(u16, u16)> for Position {
@@ -76,6 +211,9 @@ impl fmt::Display for Position {
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use rstest::rstest;
 
     use super::*;
 
@@ -101,6 +239,14 @@ mod tests {
         assert_eq!(y, 2);
     }
 
+    #[test]
+    fn tuple_round_trip() {
+        let position = Position::new(1, 2);
+        assert_eq!(Position::from(<(u16, u16)>::from(position)), position);
+        let tuple = (3, 4);
+        assert_eq!(<(u16, u16)>::from(Position::from(tuple)), tuple);
+    }
+
     #[test]
     fn from_rect() {
         let rect = Rect::new(1, 2, 3, 4);
@@ -109,9 +255,105 @@ mod tests {
         assert_eq!(position.y, 2);
     }
 
+    #[test]
+    fn manhattan_distance() {
+        let position = Position::new(3, 7);
+        assert_eq!(position.manhattan_distance(position), 0);
+        assert_eq!(position.manhattan_distance(Position::new(5, 2)), 7);
+        assert_eq!(Position::new(5, 2).manhattan_distance(position), 7);
+        assert_eq!(
+            Position::ORIGIN.manhattan_distance(Position::new(u16::MAX, u16::MAX)),
+            2 * u32::from(u16::MAX)
+        );
+    }
+
+    #[test]
+    fn chebyshev_distance() {
+        let position = Position::new(3, 7);
+        assert_eq!(position.chebyshev_distance(position), 0);
+        assert_eq!(position.chebyshev_distance(Position::new(5, 2)), 5);
+        assert_eq!(Position::new(5, 2).chebyshev_distance(position), 5);
+        assert_eq!(position.chebyshev_distance(Position::new(10, 7)), 7);
+        assert_eq!(
+            Position::ORIGIN.chebyshev_distance(Position::new(u16::MAX, u16::MAX)),
+            u32::from(u16::MAX)
+        );
+    }
+
     #[test]
     fn to_string() {
         let position = Position::new(1, 2);
         assert_eq!(position.to_string(), "(1, 2)");
     }
+
+    #[test]
+    fn neighbors4() {
+        let neighbors: Vec<_> = Position::new(3, 7).neighbors4().collect();
+        assert_eq!(
+            neighbors,
+            [
+                Position::new(3, 6),
+                Position::new(4, 7),
+                Position::new(3, 8),
+                Position::new(2, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors8() {
+        let neighbors: Vec<_> = Position::new(3, 7).neighbors8().collect();
+        assert_eq!(
+            neighbors,
+            [
+                Position::new(2, 6),
+                Position::new(3, 6),
+                Position::new(4, 6),
+                Position::new(4, 7),
+                Position::new(4, 8),
+                Position::new(3, 8),
+                Position::new(2, 8),
+                Position::new(2, 7),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case::origin(Position::ORIGIN, 2, 3)]
+    #[case::top_edge(Position::new(5, 0), 3, 5)]
+    #[case::left_edge(Position::new(0, 5), 3, 5)]
+    #[case::max(Position::new(u16::MAX, u16::MAX), 2, 3)]
+    #[case::inside(Position::new(5, 5), 4, 8)]
+    fn neighbors_at_the_edges(
+        #[case] position: Position,
+        #[case] expected4: usize,
+        #[case] expected8: usize,
+    ) {
+        assert_eq!(position.neighbors4().count(), expected4);
+        assert_eq!(position.neighbors8().count(), expected8);
+    }
+
+    #[rstest]
+    #[case::top_left_corner(Position::new(2, 3), [Position::new(3, 3), Position::new(2, 4)])]
+    #[case::bottom_right_corner(Position::new(5, 6), [Position::new(5, 5), Position::new(4, 6)])]
+    fn neighbors4_in_corner(#[case] position: Position, #[case] expected: [Position; 2]) {
+        let area = Rect::new(2, 3, 4, 4);
+        let neighbors: Vec<_> = position.neighbors4_in(area).collect();
+        assert_eq!(neighbors, expected);
+    }
+
+    #[test]
+    fn neighbors8_in_corner() {
+        let area = Rect::new(2, 3, 4, 4);
+        let neighbors: Vec<_> = Position::new(2, 3).neighbors8_in(area).collect();
+        assert_eq!(
+            neighbors,
+            [
+                Position::new(3, 3),
+                Position::new(3, 4),
+                Position::new(2, 4)
+            ]
+        );
+        assert_eq!(Position::new(10, 10).neighbors8_in(area).count(), 0);
+    }
 }
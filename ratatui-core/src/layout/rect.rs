@@ -1,6 +1,7 @@
 #![warn(missing_docs)]
 use core::cmp::{max, min};
 use core::fmt;
+use core::ops::Range;
 
 use crate::layout::{Margin, Position, Size};
 
@@ -155,6 +156,25 @@ impl Rect {
         }
     }
 
+    /// Returns a new `Rect` inside the current one, inset by `x` on the left and right and by `y`
+    /// on the top and bottom.
+    ///
+    /// This is equivalent to [`Rect::inner`] with a [`Margin`] of `x` and `y`. If the insets are
+    /// larger than the `Rect`, the returned `Rect` will have no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// assert_eq!(area.inner_xy(2, 1), Rect::new(2, 1, 6, 3));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub const fn inner_xy(self, x: u16, y: u16) -> Self {
+        self.inner(Margin::new(x, y))
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -235,6 +255,47 @@ impl Rect {
             && position.y < self.bottom()
     }
 
+    /// Returns true if the given `Rect` is entirely inside this `Rect`.
+    ///
+    /// A `Rect` with no area is considered inside if its position is inside this `Rect` (see
+    /// [`Rect::contains`]). Use [`Rect::covers`] to only compare the edges of the `Rect`s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(1, 2, 3, 4);
+    /// assert!(rect.contains_rect(Rect::new(2, 3, 2, 3)));
+    /// assert!(!rect.contains_rect(Rect::new(2, 3, 3, 3)));
+    /// assert!(!rect.contains_rect(Rect::new(4, 2, 0, 0)));
+    /// ```
+    pub const fn contains_rect(self, other: Self) -> bool {
+        self.covers(other) && (!other.is_empty() || self.contains(Position::new(other.x, other.y)))
+    }
+
+    /// Returns true if the edges of the given `Rect` are within the edges of this `Rect`.
+    ///
+    /// Unlike [`Rect::contains_rect`], a `Rect` with no area is covered when it lies on the right
+    /// or bottom edge of this `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let rect = Rect::new(1, 2, 3, 4);
+    /// assert!(rect.covers(Rect::new(1, 2, 3, 4)));
+    /// assert!(rect.covers(Rect::new(4, 2, 0, 0)));
+    /// assert!(!rect.covers(Rect::new(0, 2, 2, 2)));
+    /// ```
+    pub const fn covers(self, other: Self) -> bool {
+        other.x >= self.x
+            && other.right() <= self.right()
+            && other.y >= self.y
+            && other.bottom() <= self.bottom()
+    }
+
     /// Clamp this `Rect` to fit inside the other `Rect`.
     ///
     /// If the width or height of this `Rect` is larger than the other `Rect`, it will be clamped to
@@ -308,6 +369,54 @@ impl Rect {
         Columns::new(self)
     }
 
+    /// An iterator over the rows within the `Rect` whose y coordinates are in the given range.
+    ///
+    /// The range is clamped to the bounds of the `Rect`, so rows outside of it are never returned.
+    /// An empty range, or a range that does not overlap the `Rect`, returns no rows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(0, 2, 10, 5);
+    /// let rows: Vec<Rect> = area.rows_in_range(0..4).collect();
+    /// assert_eq!(rows, [Rect::new(0, 2, 10, 1), Rect::new(0, 3, 10, 1)]);
+    /// ```
+    pub fn rows_in_range(self, range: Range<u16>) -> Rows {
+        let start = range.start.clamp(self.top(), self.bottom());
+        let end = range.end.clamp(start, self.bottom());
+        Rows::new(Self {
+            y: start,
+            height: end - start,
+            ..self
+        })
+    }
+
+    /// An iterator over the columns within the `Rect` whose x coordinates are in the given range.
+    ///
+    /// The range is clamped to the bounds of the `Rect`, so columns outside of it are never
+    /// returned. An empty range, or a range that does not overlap the `Rect`, returns no columns.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let area = Rect::new(2, 0, 5, 10);
+    /// let columns: Vec<Rect> = area.columns_in_range(5..9).collect();
+    /// assert_eq!(columns, [Rect::new(5, 0, 1, 10), Rect::new(6, 0, 1, 10)]);
+    /// ```
+    pub fn columns_in_range(self, range: Range<u16>) -> Columns {
+        let start = range.start.clamp(self.left(), self.right());
+        let end = range.end.clamp(start, self.right());
+        Columns::new(Self {
+            x: start,
+            width: end - start,
+            ..self
+        })
+    }
+
     /// An iterator over the positions within the `Rect`.
     ///
     /// The positions are returned in a row-major order (left-to-right, top-to-bottom).
@@ -328,6 +437,85 @@ impl Rect {
         Positions::new(self)
     }
 
+    /// An iterator over the positions on the border of the `Rect`.
+    ///
+    /// The positions are returned clockwise, starting from the top-left corner, and each position
+    /// is returned once (e.g. the corners are not duplicated). A `Rect` that is one cell high or
+    /// wide returns the positions of its single row or column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui_core::layout::{Position, Rect};
+    ///
+    /// let positions: Vec<Position> = Rect::new(0, 0, 2, 2).border_positions().collect();
+    /// assert_eq!(
+    ///     positions,
+    ///     [
+    ///         Position::new(0, 0),
+    ///         Position::new(1, 0),
+    ///         Position::new(1, 1),
+    ///         Position::new(0, 1),
+    ///     ]
+    /// );
+    /// ```
+    pub fn border_positions(self) -> impl Iterator<Item = Position> {
+        let (left, top) = (self.left(), self.top());
+        let (right, bottom) = (
+            self.right().saturating_sub(1),
+            self.bottom().saturating_sub(1),
+        );
+        let (columns, rows) = if self.is_empty() {
+            (0..0, 0..0)
+        } else {
+            (left..self.right(), top..self.bottom())
+        };
+        let bottom_columns = if self.height > 1 { left..right } else { 0..0 };
+        let left_rows = if self.width > 1 {
+            top + 1..bottom
+        } else {
+            0..0
+        };
+
+        let top_edge = columns.map(move |x| Position::new(x, top));
+        let right_edge = rows.skip(1).map(move |y| Position::new(right, y));
+        let bottom_edge = bottom_columns.rev().map(move |x| Position::new(x, bottom));
+        let left_edge = left_rows.rev().map(move |y| Position::new(left, y));
+        top_edge
+            .chain(right_edge)
+            .chain(bottom_edge)
+            .chain(left_edge)
+    }
+
+    /// Splits the `Rect` into four quadrants.
+    ///
+    /// The quadrants are returned in the order top-left, top-right, bottom-left and bottom-right.
+    /// When the width (resp. height) is odd, the right (resp. bottom) quadrants are one cell wider
+    /// (resp. taller) than the left (resp. top) ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::layout::Rect;
+    ///
+    /// let [top_left, top_right, bottom_left, bottom_right] = Rect::new(0, 0, 5, 4).quadrants();
+    /// assert_eq!(top_left, Rect::new(0, 0, 2, 2));
+    /// assert_eq!(top_right, Rect::new(2, 0, 3, 2));
+    /// assert_eq!(bottom_left, Rect::new(0, 2, 2, 2));
+    /// assert_eq!(bottom_right, Rect::new(2, 2, 3, 2));
+    /// ```
+    pub const fn quadrants(self) -> [Self; 4] {
+        let (left, top) = (self.width / 2, self.height / 2);
+        let (right, bottom) = (self.width - left, self.height - top);
+        let (x, y) = (self.x + left, self.y + top);
+        [
+            Self::new(self.x, self.y, left, top),
+            Self::new(x, self.y, right, top),
+            Self::new(self.x, y, left, bottom),
+            Self::new(x, y, right, bottom),
+        ]
+    }
+
     /// Returns a [`Position`] with the same coordinates as this `Rect`.
     ///
     /// # Examples
@@ -441,6 +629,23 @@ impl From<(Position, Size)> for Rect {
     }
 }
 
+/// Creates a `Rect` from a `(x, y, width, height)` tuple
+///
+/// As with [`Rect::new`], the width and height are clamped to keep the right and bottom edges
+/// within `u16`.
+impl From<(u16, u16, u16, u16)> for Rect {
+    fn from((x, y, width, height): (u16, u16, u16, u16)) -> Self {
+        Self::new(x, y, width, height)
+    }
+}
+
+/// Converts a `Rect` into a `(x, y, width, height)` tuple
+impl From<Rect> for (u16, u16, u16, u16) {
+    fn from(rect: Rect) -> Self {
+        (rect.x, rect.y, rect.width, rect.height)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
@@ -511,6 +716,21 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::zero(0, 0, Rect::new(1, 2, 6, 4))]
+    #[case::horizontal(1, 0, Rect::new(2, 2, 4, 4))]
+    #[case::vertical(0, 1, Rect::new(1, 3, 6, 2))]
+    #[case::both(2, 1, Rect::new(3, 3, 2, 2))]
+    #[case::exact_fit(3, 2, Rect::new(4, 4, 0, 0))]
+    #[case::over_inset_x(4, 0, Rect::ZERO)]
+    #[case::over_inset_y(0, 3, Rect::ZERO)]
+    #[case::max(u16::MAX, u16::MAX, Rect::ZERO)]
+    fn inner_xy(#[case] x: u16, #[case] y: u16, #[case] expected: Rect) {
+        let rect = Rect::new(1, 2, 6, 4);
+        assert_eq!(rect.inner_xy(x, y), expected);
+        assert_eq!(rect.inner_xy(x, y), rect.inner(Margin::new(x, y)));
+    }
+
     #[test]
     fn offset() {
         assert_eq!(
@@ -594,6 +814,36 @@ mod tests {
         );
     }
 
+    // the bounds of this rect are x: [1..=3], y: [2..=5]
+    #[rstest]
+    #[case::equal(Rect::new(1, 2, 3, 4), true, true)]
+    #[case::fully_inside(Rect::new(2, 3, 1, 2), true, true)]
+    #[case::touching_top_left(Rect::new(1, 2, 2, 2), true, true)]
+    #[case::touching_bottom_right(Rect::new(2, 4, 2, 2), true, true)]
+    #[case::partially_outside_left(Rect::new(0, 3, 2, 2), false, false)]
+    #[case::partially_outside_right(Rect::new(3, 3, 2, 2), false, false)]
+    #[case::partially_outside_top(Rect::new(2, 1, 2, 2), false, false)]
+    #[case::partially_outside_bottom(Rect::new(2, 5, 2, 2), false, false)]
+    #[case::surrounding(Rect::new(0, 1, 5, 6), false, false)]
+    #[case::outside(Rect::new(5, 7, 2, 2), false, false)]
+    #[case::empty_inside(Rect::new(2, 3, 0, 0), true, true)]
+    #[case::empty_on_right_edge(Rect::new(4, 3, 0, 2), false, true)]
+    #[case::empty_on_bottom_edge(Rect::new(2, 6, 2, 0), false, true)]
+    #[case::empty_outside(Rect::new(5, 3, 0, 0), false, false)]
+    fn contains_rect(
+        #[case] other: Rect,
+        #[case] expected_contains: bool,
+        #[case] expected_covers: bool,
+    ) {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(
+            rect.contains_rect(other),
+            expected_contains,
+            "other: {other:?}"
+        );
+        assert_eq!(rect.covers(other), expected_covers, "other: {other:?}");
+    }
+
     #[test]
     fn size_truncation() {
         assert_eq!(
@@ -679,6 +929,34 @@ mod tests {
         assert_eq!(rows, expected_rows);
     }
 
+    #[rstest]
+    #[case::inside(2..4, vec![Rect::new(1, 2, 3, 1), Rect::new(1, 3, 3, 1)])]
+    #[case::whole(1..5, vec![Rect::new(1, 1, 3, 1), Rect::new(1, 2, 3, 1), Rect::new(1, 3, 3, 1), Rect::new(1, 4, 3, 1)])]
+    #[case::partially_above(0..2, vec![Rect::new(1, 1, 3, 1)])]
+    #[case::partially_below(4..10, vec![Rect::new(1, 4, 3, 1)])]
+    #[case::outside(6..8, vec![])]
+    #[case::empty(3..3, vec![])]
+    #[allow(clippy::reversed_empty_ranges)]
+    #[case::reversed(4..2, vec![])]
+    fn rows_in_range(#[case] range: Range<u16>, #[case] expected: Vec<Rect>) {
+        let area = Rect::new(1, 1, 3, 4);
+        assert_eq!(area.rows_in_range(range).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case::inside(2..4, vec![Rect::new(2, 1, 1, 3), Rect::new(3, 1, 1, 3)])]
+    #[case::whole(1..5, vec![Rect::new(1, 1, 1, 3), Rect::new(2, 1, 1, 3), Rect::new(3, 1, 1, 3), Rect::new(4, 1, 1, 3)])]
+    #[case::partially_left(0..2, vec![Rect::new(1, 1, 1, 3)])]
+    #[case::partially_right(4..10, vec![Rect::new(4, 1, 1, 3)])]
+    #[case::outside(6..8, vec![])]
+    #[case::empty(3..3, vec![])]
+    #[allow(clippy::reversed_empty_ranges)]
+    #[case::reversed(4..2, vec![])]
+    fn columns_in_range(#[case] range: Range<u16>, #[case] expected: Vec<Rect>) {
+        let area = Rect::new(1, 1, 4, 3);
+        assert_eq!(area.columns_in_range(range).collect::<Vec<_>>(), expected);
+    }
+
     #[test]
     fn columns() {
         let area = Rect::new(0, 0, 3, 2);
@@ -730,6 +1008,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_tuple() {
+        assert_eq!(Rect::from((1, 2, 3, 4)), Rect::new(1, 2, 3, 4));
+        // the size is clamped like in Rect::new
+        assert_eq!(
+            Rect::from((u16::MAX - 1, 0, 10, 1)),
+            Rect::new(u16::MAX - 1, 0, 1, 1)
+        );
+    }
+
+    #[test]
+    fn into_tuple() {
+        let (x, y, width, height) = Rect::new(1, 2, 3, 4).into();
+        assert_eq!((x, y, width, height), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn tuple_round_trip() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(Rect::from(<(u16, u16, u16, u16)>::from(rect)), rect);
+        let tuple = (5, 6, 7, 8);
+        assert_eq!(<(u16, u16, u16, u16)>::from(Rect::from(tuple)), tuple);
+    }
+
     #[test]
     fn centered_horizontally() {
         let rect = Rect::new(0, 0, 5, 5);
@@ -756,4 +1058,66 @@ mod tests {
             Rect::new(1, 2, 3, 1)
         );
     }
+
+    #[rstest]
+    #[case::even(
+        Rect::new(0, 0, 4, 2),
+        [
+            Rect::new(0, 0, 2, 1),
+            Rect::new(2, 0, 2, 1),
+            Rect::new(0, 1, 2, 1),
+            Rect::new(2, 1, 2, 1),
+        ]
+    )]
+    #[case::odd(
+        Rect::new(1, 2, 5, 3),
+        [
+            Rect::new(1, 2, 2, 1),
+            Rect::new(3, 2, 3, 1),
+            Rect::new(1, 3, 2, 2),
+            Rect::new(3, 3, 3, 2),
+        ]
+    )]
+    #[case::single_cell(
+        Rect::new(1, 1, 1, 1),
+        [
+            Rect::new(1, 1, 0, 0),
+            Rect::new(1, 1, 1, 0),
+            Rect::new(1, 1, 0, 1),
+            Rect::new(1, 1, 1, 1),
+        ]
+    )]
+    fn quadrants(#[case] rect: Rect, #[case] expected: [Rect; 4]) {
+        assert_eq!(rect.quadrants(), expected);
+    }
+
+    #[rstest]
+    #[case::empty(Rect::new(1, 1, 0, 3), &[])]
+    #[case::single_cell(Rect::new(1, 1, 1, 1), &[(1, 1)])]
+    #[case::row(Rect::new(1, 1, 3, 1), &[(1, 1), (2, 1), (3, 1)])]
+    #[case::column(Rect::new(1, 1, 1, 3), &[(1, 1), (1, 2), (1, 3)])]
+    #[case::square(Rect::new(0, 0, 2, 2), &[(0, 0), (1, 0), (1, 1), (0, 1)])]
+    #[case::rect(
+        Rect::new(1, 1, 3, 3),
+        &[(1, 1), (2, 1), (3, 1), (3, 2), (3, 3), (2, 3), (1, 3), (1, 2)]
+    )]
+    fn border_positions(#[case] rect: Rect, #[case] expected: &[(u16, u16)]) {
+        let positions = rect.border_positions().collect::<Vec<_>>();
+        let expected = expected
+            .iter()
+            .copied()
+            .map(Position::from)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, expected);
+    }
+
+    #[test]
+    fn border_positions_are_unique() {
+        let rect = Rect::new(2, 3, 7, 5);
+        let positions = rect.border_positions().collect::<Vec<_>>();
+        assert_eq!(positions.len(), 2 * (7 + 5) - 4);
+        for (i, position) in positions.iter().enumerate() {
+            assert!(!positions[..i].contains(position));
+        }
+    }
 }
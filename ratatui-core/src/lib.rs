@@ -47,6 +47,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod accessibility;
 pub mod backend;
 pub mod buffer;
 pub mod layout;
@@ -74,6 +74,7 @@ use bitflags::bitflags;
 pub use color::{Color, ParseColorError};
 use stylize::ColorDebugKind;
 pub use stylize::{Styled, Stylize};
+pub use theme::{Theme, ThemeRole};
 
 #[cfg(feature = "anstyle")]
 mod anstyle;
@@ -82,6 +83,7 @@ pub mod palette;
 #[cfg(feature = "palette")]
 mod palette_conversion;
 mod stylize;
+mod theme;
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
@@ -903,4 +905,72 @@ mod tests {
                 .remove_modifier(Modifier::DIM)
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::reset(Color::Reset)]
+    #[case::black(Color::Black)]
+    #[case::red(Color::Red)]
+    #[case::green(Color::Green)]
+    #[case::yellow(Color::Yellow)]
+    #[case::blue(Color::Blue)]
+    #[case::magenta(Color::Magenta)]
+    #[case::cyan(Color::Cyan)]
+    #[case::gray(Color::Gray)]
+    #[case::dark_gray(Color::DarkGray)]
+    #[case::light_red(Color::LightRed)]
+    #[case::light_green(Color::LightGreen)]
+    #[case::light_yellow(Color::LightYellow)]
+    #[case::light_blue(Color::LightBlue)]
+    #[case::light_magenta(Color::LightMagenta)]
+    #[case::light_cyan(Color::LightCyan)]
+    #[case::white(Color::White)]
+    #[case::rgb(Color::Rgb(1, 128, 255))]
+    #[case::indexed(Color::Indexed(42))]
+    fn serde_style_color_round_trip(#[case] color: Color) -> Result<(), serde_json::Error> {
+        let style = Style::new().fg(color).bg(color);
+        #[cfg(feature = "underline-color")]
+        let style = style.underline_color(color);
+        let json = serde_json::to_string(&style)?;
+        assert_eq!(serde_json::from_str::<Style>(&json)?, style);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[rstest]
+    #[case::empty(Modifier::empty(), r#""""#)]
+    #[case::single(Modifier::BOLD, r#""BOLD""#)]
+    #[case::multiple(Modifier::BOLD | Modifier::ITALIC | Modifier::CROSSED_OUT, r#""BOLD | ITALIC | CROSSED_OUT""#)]
+    #[case::all(Modifier::all(), r#""BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT""#)]
+    fn serde_modifier_round_trip(
+        #[case] modifier: Modifier,
+        #[case] expected: &str,
+    ) -> Result<(), serde_json::Error> {
+        let json = serde_json::to_string(&modifier)?;
+        assert_eq!(json, expected);
+        assert_eq!(serde_json::from_str::<Modifier>(&json)?, modifier);
+
+        let style = Style::new()
+            .add_modifier(modifier)
+            .remove_modifier(Modifier::all() - modifier);
+        let json = serde_json::to_string(&style)?;
+        assert_eq!(serde_json::from_str::<Style>(&json)?, style);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_style_format() -> Result<(), serde_json::Error> {
+        let style = Style::new()
+            .fg(Color::Rgb(255, 0, 255))
+            .bg(Color::Indexed(10))
+            .add_modifier(Modifier::BOLD | Modifier::ITALIC)
+            .remove_modifier(Modifier::DIM);
+        let json = serde_json::to_value(style)?;
+        assert_eq!(json["fg"], "#FF00FF");
+        assert_eq!(json["bg"], "10");
+        assert_eq!(json["add_modifier"], "BOLD | ITALIC");
+        assert_eq!(json["sub_modifier"], "DIM");
+        Ok(())
+    }
 }
@@ -136,6 +136,125 @@ impl Color {
         let b = u as u8;
         Self::Rgb(r, g, b)
     }
+
+    /// Returns the RGB components of the color
+    ///
+    /// The named and indexed colors are converted using the default xterm palette. As terminals
+    /// are free to use their own palette, the result is only an approximation of the displayed
+    /// color for these colors.
+    ///
+    /// Returns `None` for [`Color::Reset`] as its actual color is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some((1, 2, 3)));
+    /// assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+    /// assert_eq!(Color::Indexed(196).to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub const fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        const ANSI: [(u8, u8, u8); 16] = [
+            (0, 0, 0),
+            (205, 0, 0),
+            (0, 205, 0),
+            (205, 205, 0),
+            (0, 0, 238),
+            (205, 0, 205),
+            (0, 205, 205),
+            (229, 229, 229),
+            (127, 127, 127),
+            (255, 0, 0),
+            (0, 255, 0),
+            (255, 255, 0),
+            (92, 92, 255),
+            (255, 0, 255),
+            (0, 255, 255),
+            (255, 255, 255),
+        ];
+        let rgb = match self {
+            Self::Reset => return None,
+            Self::Black => ANSI[0],
+            Self::Red => ANSI[1],
+            Self::Green => ANSI[2],
+            Self::Yellow => ANSI[3],
+            Self::Blue => ANSI[4],
+            Self::Magenta => ANSI[5],
+            Self::Cyan => ANSI[6],
+            Self::Gray => ANSI[7],
+            Self::DarkGray => ANSI[8],
+            Self::LightRed => ANSI[9],
+            Self::LightGreen => ANSI[10],
+            Self::LightYellow => ANSI[11],
+            Self::LightBlue => ANSI[12],
+            Self::LightMagenta => ANSI[13],
+            Self::LightCyan => ANSI[14],
+            Self::White => ANSI[15],
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(i @ 0..=15) => ANSI[i as usize],
+            Self::Indexed(i @ 16..=231) => {
+                const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+                let i = i - 16;
+                (
+                    LEVELS[(i / 36) as usize],
+                    LEVELS[(i / 6 % 6) as usize],
+                    LEVELS[(i % 6) as usize],
+                )
+            }
+            Self::Indexed(i) => {
+                let level = 8 + (i - 232) * 10;
+                (level, level, level)
+            }
+        };
+        Some(rgb)
+    }
+
+    /// Blends the given color over this color with the given opacity
+    ///
+    /// `alpha` is the opacity of `other`, from `0.0` (this color is returned) to `1.0` (`other` is
+    /// returned). It is clamped to this range, and `NaN` is treated as `0.0`. The colors are
+    /// blended in the RGB space (see [`Color::to_rgb`]) and the result is always a
+    /// [`Color::Rgb`], unless `alpha` is `0.0` or `1.0`.
+    ///
+    /// [`Color::Reset`] can't be blended as its actual color is unknown. If either color is
+    /// [`Color::Reset`], the most opaque of the two colors is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui_core::style::Color;
+    ///
+    /// let black = Color::Rgb(0, 0, 0);
+    /// let white = Color::Rgb(255, 255, 255);
+    /// assert_eq!(black.blend(white, 0.5), Color::Rgb(128, 128, 128));
+    /// assert_eq!(black.blend(white, 0.0), black);
+    /// assert_eq!(Color::Reset.blend(white, 0.25), Color::Reset);
+    /// ```
+    #[must_use]
+    pub fn blend(self, other: Self, alpha: f32) -> Self {
+        if alpha.is_nan() {
+            return self;
+        }
+        let alpha = alpha.clamp(0.0, 1.0);
+        if alpha <= 0.0 {
+            return self;
+        }
+        if alpha >= 1.0 {
+            return other;
+        }
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_rgb(), other.to_rgb()) else {
+            return if alpha < 0.5 { self } else { other };
+        };
+        let mix = |from: u8, to: u8| {
+            let from = f32::from(from);
+            let to = f32::from(to);
+            // the result is in the 0..=255 range, so adding 0.5 before truncating rounds it
+            (from + (to - from) * alpha + 0.5) as u8
+        };
+        Self::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -516,7 +635,6 @@ mod tests {
 
     #[cfg(feature = "palette")]
     use palette::{Hsl, Hsluv};
-    #[cfg(feature = "palette")]
     use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
@@ -572,6 +690,40 @@ mod tests {
         assert_eq!(Color::from_hsluv(hsluv), expected);
     }
 
+    #[rstest]
+    #[case::reset(Color::Reset, None)]
+    #[case::named(Color::Blue, Some((0, 0, 238)))]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some((1, 2, 3)))]
+    #[case::indexed_ansi(Color::Indexed(9), Some((255, 0, 0)))]
+    #[case::indexed_cube(Color::Indexed(67), Some((95, 135, 175)))]
+    #[case::indexed_grayscale(Color::Indexed(244), Some((128, 128, 128)))]
+    fn to_rgb(#[case] color: Color, #[case] expected: Option<(u8, u8, u8)>) {
+        assert_eq!(color.to_rgb(), expected);
+    }
+
+    #[rstest]
+    #[case::transparent(0.0, Color::Black)]
+    #[case::quarter(0.25, Color::Rgb(64, 64, 64))]
+    #[case::half(0.5, Color::Rgb(128, 128, 128))]
+    #[case::opaque(1.0, Color::White)]
+    #[case::clamped_below(-1.0, Color::Black)]
+    #[case::clamped_above(2.0, Color::White)]
+    fn blend(#[case] alpha: f32, #[case] expected: Color) {
+        assert_eq!(Color::Black.blend(Color::White, alpha), expected);
+    }
+
+    #[rstest]
+    #[case::mostly_self(0.25, Color::Reset)]
+    #[case::mostly_other(0.75, Color::Red)]
+    fn blend_reset(#[case] alpha: f32, #[case] expected: Color) {
+        assert_eq!(Color::Reset.blend(Color::Red, alpha), expected);
+    }
+
+    #[test]
+    fn blend_nan() {
+        assert_eq!(Color::Black.blend(Color::White, f32::NAN), Color::Black);
+    }
+
     #[test]
     fn from_u32() {
         assert_eq!(Color::from_u32(0x000000), Color::Rgb(0, 0, 0));
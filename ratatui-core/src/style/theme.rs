@@ -0,0 +1,117 @@
+use strum::EnumCount;
+
+use crate::style::Style;
+
+/// A semantic role of an element of the interface, which is styled by a [`Theme`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumCount)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThemeRole {
+    /// The main elements of the interface
+    Primary,
+    /// Elements of less importance than the primary ones
+    Secondary,
+    /// Elements which should stand out, e.g. the focused element
+    Accent,
+    /// Elements indicating that something went well
+    Success,
+    /// Elements indicating that something might go wrong
+    Warning,
+    /// Elements indicating that something went wrong
+    Error,
+}
+
+/// A set of styles mapped to semantic [`ThemeRole`]s
+///
+/// A theme avoids passing the styles of an application through all the code which builds its
+/// widgets: the widgets are given the role of the element they display, which is resolved to a
+/// [`Style`] by the theme. Roles which are not configured resolve to [`Style::new()`], which
+/// leaves the style of the widget unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui_core::style::{Style, Stylize, Theme, ThemeRole};
+///
+/// let theme = Theme::new()
+///     .with_style(ThemeRole::Primary, Style::new().white().on_black())
+///     .with_style(ThemeRole::Error, Style::new().red().bold());
+///
+/// assert_eq!(theme.style(ThemeRole::Error), Style::new().red().bold());
+/// assert_eq!(theme.style(ThemeRole::Accent), Style::new());
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    styles: [Style; ThemeRole::COUNT],
+}
+
+impl Theme {
+    /// Creates a new `Theme` where no role is styled
+    pub const fn new() -> Self {
+        Self {
+            styles: [Style::new(); ThemeRole::COUNT],
+        }
+    }
+
+    /// Sets the style of the given role
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: crate::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_style<S: Into<Style>>(mut self, role: ThemeRole, style: S) -> Self {
+        self.set_style(role, style);
+        self
+    }
+
+    /// Sets the style of the given role
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn set_style<S: Into<Style>>(&mut self, role: ThemeRole, style: S) {
+        self.styles[role as usize] = style.into();
+    }
+
+    /// Returns the style of the given role
+    pub const fn style(&self, role: ThemeRole) -> Style {
+        self.styles[role as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::Stylize;
+
+    #[rstest]
+    #[case::primary(ThemeRole::Primary)]
+    #[case::secondary(ThemeRole::Secondary)]
+    #[case::accent(ThemeRole::Accent)]
+    #[case::success(ThemeRole::Success)]
+    #[case::warning(ThemeRole::Warning)]
+    #[case::error(ThemeRole::Error)]
+    fn style(#[case] role: ThemeRole) {
+        assert_eq!(Theme::new().style(role), Style::new());
+
+        let theme = Theme::new()
+            .with_style(ThemeRole::Primary, Style::new().white())
+            .with_style(role, Style::new().red().bold());
+        assert_eq!(theme.style(role), Style::new().red().bold());
+    }
+
+    #[test]
+    fn set_style_keeps_other_roles() {
+        let mut theme = Theme::new().with_style(ThemeRole::Accent, Style::new().yellow());
+        theme.set_style(ThemeRole::Error, Style::new().red());
+        assert_eq!(theme.style(ThemeRole::Accent), Style::new().yellow());
+        assert_eq!(theme.style(ThemeRole::Error), Style::new().red());
+        assert_eq!(theme.style(ThemeRole::Primary), Style::new());
+    }
+}
@@ -1,3 +1,5 @@
+use bitflags::bitflags;
+
 pub const VERTICAL: &str = "│";
 pub const DOUBLE_VERTICAL: &str = "║";
 pub const THICK_VERTICAL: &str = "┃";
@@ -79,6 +81,100 @@ impl Default for Set {
     }
 }
 
+bitflags! {
+    /// The neighbors of a cell which a line symbol connects to
+    ///
+    /// See [`Set::connecting`] to get the symbol connecting to a set of neighbors.
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct Connections: u8 {
+        /// Connects to the cell above
+        const UP = 0b0001;
+        /// Connects to the cell below
+        const DOWN = 0b0010;
+        /// Connects to the cell on the left
+        const LEFT = 0b0100;
+        /// Connects to the cell on the right
+        const RIGHT = 0b1000;
+    }
+}
+
+impl Set {
+    /// Returns the symbol of this set which connects to the given neighbors
+    ///
+    /// A single connection is drawn as a straight line (e.g. [`Connections::UP`] is drawn as the
+    /// vertical line). Returns `None` when there is no connection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::symbols::line::{Connections, NORMAL};
+    ///
+    /// assert_eq!(NORMAL.connecting(Connections::all()), Some("┼"));
+    /// assert_eq!(
+    ///     NORMAL.connecting(Connections::UP | Connections::DOWN | Connections::RIGHT),
+    ///     Some("├")
+    /// );
+    /// ```
+    pub const fn connecting(&self, connections: Connections) -> Option<&'static str> {
+        let symbol = match (
+            connections.contains(Connections::UP),
+            connections.contains(Connections::DOWN),
+            connections.contains(Connections::LEFT),
+            connections.contains(Connections::RIGHT),
+        ) {
+            (false, false, false, false) => return None,
+            (_, _, false, false) => self.vertical,
+            (false, false, _, _) => self.horizontal,
+            (false, true, false, true) => self.top_left,
+            (false, true, true, false) => self.top_right,
+            (true, false, false, true) => self.bottom_left,
+            (true, false, true, false) => self.bottom_right,
+            (true, true, false, true) => self.vertical_right,
+            (true, true, true, false) => self.vertical_left,
+            (false, true, true, true) => self.horizontal_down,
+            (true, false, true, true) => self.horizontal_up,
+            (true, true, true, true) => self.cross,
+        };
+        Some(symbol)
+    }
+
+    /// Returns the neighbors which the given symbol of this set connects to
+    ///
+    /// This is the inverse of [`Set::connecting`], straight lines connect to both of their ends.
+    /// Returns `None` when the symbol is not part of this set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::symbols::line::{Connections, NORMAL};
+    ///
+    /// assert_eq!(
+    ///     NORMAL.connections("┐"),
+    ///     Some(Connections::DOWN | Connections::LEFT)
+    /// );
+    /// assert_eq!(NORMAL.connections("x"), None);
+    /// ```
+    pub fn connections(&self, symbol: &str) -> Option<Connections> {
+        let vertical = Connections::UP.union(Connections::DOWN);
+        let horizontal = Connections::LEFT.union(Connections::RIGHT);
+        [
+            (self.vertical, vertical),
+            (self.horizontal, horizontal),
+            (self.top_left, Connections::DOWN | Connections::RIGHT),
+            (self.top_right, Connections::DOWN | Connections::LEFT),
+            (self.bottom_left, Connections::UP | Connections::RIGHT),
+            (self.bottom_right, Connections::UP | Connections::LEFT),
+            (self.vertical_right, vertical | Connections::RIGHT),
+            (self.vertical_left, vertical | Connections::LEFT),
+            (self.horizontal_down, horizontal | Connections::DOWN),
+            (self.horizontal_up, horizontal | Connections::UP),
+            (self.cross, Connections::all()),
+        ]
+        .into_iter()
+        .find_map(|(candidate, connections)| (candidate == symbol).then_some(connections))
+    }
+}
+
 pub const NORMAL: Set = Set {
     vertical: VERTICAL,
     horizontal: HORIZONTAL,
@@ -171,6 +267,7 @@ mod tests {
     use alloc::string::String;
 
     use indoc::{formatdoc, indoc};
+    use rstest::rstest;
 
     use super::*;
 
@@ -179,6 +276,35 @@ mod tests {
         assert_eq!(Set::default(), NORMAL);
     }
 
+    #[rstest]
+    #[case::none(Connections::empty(), None)]
+    #[case::up(Connections::UP, Some("│"))]
+    #[case::left_right(Connections::LEFT | Connections::RIGHT, Some("─"))]
+    #[case::top_left(Connections::DOWN | Connections::RIGHT, Some("┌"))]
+    #[case::bottom_right(Connections::UP | Connections::LEFT, Some("┘"))]
+    #[case::vertical_left(Connections::UP | Connections::DOWN | Connections::LEFT, Some("┤"))]
+    #[case::horizontal_down(Connections::LEFT | Connections::RIGHT | Connections::DOWN, Some("┬"))]
+    #[case::cross(Connections::all(), Some("┼"))]
+    fn connecting(#[case] connections: Connections, #[case] expected: Option<&str>) {
+        assert_eq!(NORMAL.connecting(connections), expected);
+    }
+
+    #[test]
+    fn connections_is_inverse_of_connecting() {
+        for set in [NORMAL, ROUNDED, DOUBLE, THICK] {
+            for bits in 1..=Connections::all().bits() {
+                let connections = Connections::from_bits_truncate(bits);
+                // single connections are drawn as straight lines
+                if connections.bits().is_power_of_two() {
+                    continue;
+                }
+                let symbol = set.connecting(connections).unwrap();
+                assert_eq!(set.connections(symbol), Some(connections), "{symbol}");
+            }
+        }
+        assert_eq!(NORMAL.connections("╔"), None);
+    }
+
     /// A helper function to render a set of symbols.
     fn render(set: Set) -> String {
         formatdoc!(
@@ -1,3 +1,4 @@
+use crate::accessibility::AccessibilityTree;
 use crate::buffer::Buffer;
 use crate::layout::{Position, Rect};
 use crate::widgets::{StatefulWidget, Widget};
@@ -46,6 +47,18 @@ pub struct CompletedFrame<'a> {
     pub count: usize,
 }
 
+impl CompletedFrame<'_> {
+    /// Returns the accessibility annotations collected while drawing the frame
+    ///
+    /// Returns `None` if the collection is not enabled, see
+    /// [`Terminal::enable_accessibility_tree`].
+    ///
+    /// [`Terminal::enable_accessibility_tree`]: crate::terminal::Terminal::enable_accessibility_tree
+    pub const fn accessibility_tree(&self) -> Option<&AccessibilityTree> {
+        self.buffer.accessibility_tree()
+    }
+}
+
 impl Frame<'_> {
     /// The area of the current frame
     ///
@@ -73,6 +73,10 @@ where
     last_known_cursor_pos: Position,
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// Number of cells updated by the last flush.
+    last_frame_damage: usize,
+    /// Maximum height up to which the inline viewport grows to fit the rendered content.
+    inline_max_height: Option<u16>,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -162,6 +166,8 @@ where
             last_known_area: area,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            last_frame_damage: 0,
+            inline_max_height: None,
         })
     }
 
@@ -181,6 +187,57 @@ where
         &mut self.buffers[self.current]
     }
 
+    /// Enables or disables the collection of accessibility annotations while drawing
+    ///
+    /// When enabled, the annotations pushed by the widgets rendered in each frame are available
+    /// from [`CompletedFrame::accessibility_tree`] after [`Terminal::draw`]. This is disabled by
+    /// default.
+    ///
+    /// See the [`accessibility`](crate::accessibility) module for more details.
+    pub fn enable_accessibility_tree(&mut self, enabled: bool) {
+        for buffer in &mut self.buffers {
+            buffer.enable_accessibility_tree(enabled);
+        }
+    }
+
+    /// Allows the inline viewport to grow up to `max_height` rows to fit the rendered content
+    ///
+    /// By default, the content rendered outside of the height of an [inline](Viewport::Inline)
+    /// viewport is clipped. When a maximum height is set, [`Terminal::draw`] renders the frame in
+    /// an area of `max_height` rows (limited to the height of the terminal), and then grows the
+    /// viewport to fit the rows which contain non-empty cells. If the grown viewport does not fit
+    /// below its current position, the content above the viewport is scrolled up into the history
+    /// of the terminal. The viewport never shrinks. Set to `None` to disable the growth.
+    ///
+    /// As the frame area is taller than the viewport, widgets which fill their whole area (e.g. a
+    /// [`Block`] with borders) make the viewport grow to the maximum height. Such widgets should be
+    /// rendered in an area sized to their content instead.
+    ///
+    /// This has no effect on the other viewports, nor on [`Terminal::flush_frame`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::terminal::{Terminal, TerminalOptions, Viewport};
+    /// use ratatui_core::text::Text;
+    ///
+    /// let backend = TestBackend::new(10, 10);
+    /// let options = TerminalOptions {
+    ///     viewport: Viewport::Inline(1),
+    /// };
+    /// let mut terminal = Terminal::with_options(backend, options)?;
+    /// terminal.set_inline_max_height(Some(5));
+    /// terminal.draw(|frame| frame.render_widget(Text::raw("one\ntwo"), frame.area()))?;
+    /// assert_eq!(terminal.get_frame().area().height, 2);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    ///
+    /// [`Block`]: https://docs.rs/ratatui/latest/ratatui/widgets/block/struct.Block.html
+    pub const fn set_inline_max_height(&mut self, max_height: Option<u16>) {
+        self.inline_max_height = max_height;
+    }
+
     /// Gets the backend
     pub const fn backend(&self) -> &B {
         &self.backend
@@ -200,9 +257,32 @@ where
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = Position { x: *col, y: *row };
         }
+        self.last_frame_damage = updates.len();
         self.backend.draw(updates.into_iter())
     }
 
+    /// Returns the number of cells updated on the backend by the last [`Terminal::flush`]
+    ///
+    /// This is the number of cells which differed between the last two frames, and therefore the
+    /// number of cells [`Terminal::draw`] actually sent to the backend. This is useful to detect
+    /// accidental full redraws when profiling an application.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::backend::TestBackend;
+    /// use ratatui_core::terminal::Terminal;
+    /// use ratatui_core::text::Line;
+    ///
+    /// let mut terminal = Terminal::new(TestBackend::new(10, 1))?;
+    /// terminal.draw(|frame| frame.render_widget(Line::raw("Hello"), frame.area()))?;
+    /// assert_eq!(terminal.last_frame_damage(), 5);
+    /// # Ok::<(), core::convert::Infallible>(())
+    /// ```
+    pub const fn last_frame_damage(&self) -> usize {
+        self.last_frame_damage
+    }
+
     /// Updates the Terminal so that internal buffers match the requested area.
     ///
     /// Requested area will be saved to remain consistent when rendering. This leads to a full clear
@@ -383,7 +463,16 @@ where
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        // Render the frame in the area up to which the inline viewport can grow
+        let growth_area = self.inline_growth_area();
+        if let Some(area) = growth_area {
+            self.buffers[self.current].resize(area);
+        }
+
         let mut frame = self.get_frame();
+        if let Some(area) = growth_area {
+            frame.viewport_area = area;
+        }
 
         render_callback(&mut frame).map_err(Into::into)?;
 
@@ -392,6 +481,10 @@ where
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
         let cursor_position = frame.cursor_position;
 
+        if growth_area.is_some() {
+            self.fit_inline_viewport()?;
+        }
+
         // Draw to stdout
         self.flush()?;
 
@@ -408,6 +501,83 @@ where
         // Flush
         self.backend.flush()?;
 
+        Ok(self.complete_frame())
+    }
+
+    /// Returns the area in which the frame is rendered when the inline viewport can grow, or
+    /// `None` if the viewport can't grow.
+    fn inline_growth_area(&self) -> Option<Rect> {
+        let max_height = self.inline_max_height?;
+        if !matches!(self.viewport, Viewport::Inline(_)) || self.viewport_area.is_empty() {
+            return None;
+        }
+        let height = max_height.min(self.last_known_area.height);
+        (height > self.viewport_area.height).then_some(Rect {
+            height,
+            ..self.viewport_area
+        })
+    }
+
+    /// Grows the inline viewport to fit the rows of the current buffer which contain non-empty
+    /// cells, scrolling the content above the viewport up if the viewport doesn't fit on the
+    /// screen
+    fn fit_inline_viewport(&mut self) -> Result<(), B::Error> {
+        let buffer = &self.buffers[self.current];
+        let used_height = buffer
+            .content
+            .chunks(buffer.area.width as usize)
+            .rposition(|row| row.iter().any(|cell| *cell != Cell::EMPTY))
+            .map_or(0, |row| row as u16 + 1);
+        let height = used_height.max(self.viewport_area.height);
+        if height == self.viewport_area.height {
+            self.buffers[self.current].resize(self.viewport_area);
+            return Ok(());
+        }
+        let overflow =
+            (self.viewport_area.top() + height).saturating_sub(self.last_known_area.bottom());
+        self.scroll_up(overflow)?;
+        self.set_viewport_area(Rect {
+            y: self.viewport_area.y - overflow,
+            height,
+            ..self.viewport_area
+        });
+        self.viewport = Viewport::Inline(height);
+        self.clear()
+    }
+
+    /// Draws the current buffer to the terminal and completes the frame.
+    ///
+    /// This is the imperative counterpart of [`Terminal::draw`], for applications which do not fit
+    /// the closure pattern: render the widgets to the frame returned by [`Terminal::get_frame`],
+    /// then call this method to commit the frame to the terminal. The buffers are swapped and the
+    /// frame count is incremented as with [`Terminal::draw`].
+    ///
+    /// Unlike [`Terminal::draw`], this method does not resize the terminal nor handle the cursor.
+    /// Call [`Terminal::autoresize`] before getting the frame, and [`Terminal::hide_cursor`] or
+    /// [`Terminal::set_cursor_position`] as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// # let backend = ratatui::backend::TestBackend::new(10, 10);
+    /// # let mut terminal = ratatui::Terminal::new(backend)?;
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// terminal.autoresize()?;
+    /// let mut frame = terminal.get_frame();
+    /// frame.render_widget(Paragraph::new("Hello World!"), frame.area());
+    /// terminal.flush_frame()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn flush_frame(&mut self) -> Result<CompletedFrame<'_>, B::Error> {
+        self.flush()?;
+        self.swap_buffers();
+        self.backend.flush()?;
+        Ok(self.complete_frame())
+    }
+
+    /// Returns the frame that was just drawn and increments the frame count.
+    fn complete_frame(&mut self) -> CompletedFrame<'_> {
         let completed_frame = CompletedFrame {
             buffer: &self.buffers[1 - self.current],
             area: self.last_known_area,
@@ -417,7 +587,7 @@ where
         // increment frame count before returning from draw
         self.frame_count = self.frame_count.wrapping_add(1);
 
-        Ok(completed_frame)
+        completed_frame
     }
 
     /// Hides the cursor.
@@ -487,6 +657,23 @@ where
         Ok(())
     }
 
+    /// Forces a redraw of the given area on the next draw call.
+    ///
+    /// Unlike [`Terminal::clear`], the screen is not cleared. The cells of the area are instead
+    /// marked as changed, so that the next [`Terminal::draw`] sends them to the backend even if
+    /// they are unchanged since the last frame. This is useful to repair an area of the screen
+    /// that was overwritten outside of the terminal (e.g. by a popup of another program).
+    ///
+    /// The area is clipped to the viewport.
+    pub fn clear_region(&mut self, area: Rect) {
+        let previous_buffer = &mut self.buffers[1 - self.current];
+        for position in area.intersection(previous_buffer.area).positions() {
+            // a skipped cell is never equal to a drawn cell, so the diff includes the cell
+            previous_buffer[position].reset();
+            previous_buffer[position].set_skip(true);
+        }
+    }
+
     /// Clears the inactive buffer and swaps it with the current buffer
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
@@ -798,6 +985,7 @@ where
             let new = Buffer {
                 area,
                 content: to_draw.to_vec(),
+                accessibility_tree: None,
             };
             self.backend.draw(old.diff(&new).into_iter())?;
             self.backend.flush()?;
@@ -806,7 +994,6 @@ where
     }
 
     /// Scroll the whole screen up by the given number of lines.
-    #[cfg(not(feature = "scrolling-regions"))]
     fn scroll_up(&mut self, lines_to_scroll: u16) -> Result<(), B::Error> {
         if lines_to_scroll > 0 {
             self.set_cursor_position(Position::new(
@@ -853,3 +1040,198 @@ fn compute_inline_size<B: Backend>(
         pos,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::text::{Line, Text};
+
+    #[test]
+    fn draw_wraps_frame_count() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        terminal.frame_count = usize::MAX;
+
+        let frame = terminal
+            .draw(|frame| assert_eq!(frame.count(), usize::MAX))
+            .unwrap();
+        assert_eq!(frame.count, usize::MAX);
+        let frame = terminal.draw(|frame| assert_eq!(frame.count(), 0)).unwrap();
+        assert_eq!(frame.count, 0);
+    }
+
+    #[test]
+    fn flush_frame_matches_draw() {
+        let mut drawn = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        let mut flushed = Terminal::new(TestBackend::new(10, 2)).unwrap();
+
+        for text in ["Hello", "Hi"] {
+            drawn
+                .draw(|frame| frame.render_widget(Line::raw(text), frame.area()))
+                .unwrap();
+
+            flushed.autoresize().unwrap();
+            let mut frame = flushed.get_frame();
+            let area = frame.area();
+            frame.render_widget(Line::raw(text), area);
+            let completed = flushed.flush_frame().unwrap();
+            assert_eq!(
+                completed.buffer,
+                &Buffer::with_lines([format!("{text:10}"), " ".repeat(10)])
+            );
+
+            assert_eq!(flushed.backend().buffer(), drawn.backend().buffer());
+        }
+        assert_eq!(flushed.frame_count, 2);
+    }
+
+    #[test]
+    fn last_frame_damage() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        assert_eq!(terminal.last_frame_damage(), 0);
+        terminal
+            .draw(|frame| frame.render_widget(Line::raw("Hello"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.last_frame_damage(), 5);
+        terminal
+            .draw(|frame| frame.render_widget(Line::raw("Hello"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.last_frame_damage(), 0);
+        terminal
+            .draw(|frame| frame.render_widget(Line::raw("Help"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.last_frame_damage(), 2);
+    }
+
+    #[test]
+    fn clear_region_redraws_unchanged_cells() {
+        let mut terminal = Terminal::new(TestBackend::new(10, 2)).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(Line::raw("Hello"), frame.area()))
+            .unwrap();
+        terminal.clear_region(Rect::new(1, 0, 2, 2));
+        terminal
+            .draw(|frame| frame.render_widget(Line::raw("Hello"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.last_frame_damage(), 4);
+        terminal
+            .backend()
+            .assert_buffer_lines(["Hello     ", "          "]);
+
+        terminal.clear_region(Rect::new(8, 1, 10, 10));
+        terminal
+            .draw(|frame| frame.render_widget(Line::raw("Hello"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.last_frame_damage(), 2);
+    }
+
+    #[test]
+    fn draw_grows_inline_viewport() {
+        let mut backend = TestBackend::with_lines(
+            ["history 1", "history 2", "", "", ""].map(|line| format!("{line:9}")),
+        );
+        backend.set_cursor_position((0, 2)).unwrap();
+        let options = TerminalOptions {
+            viewport: Viewport::Inline(1),
+        };
+        let mut terminal = Terminal::with_options(backend, options).unwrap();
+        terminal.set_inline_max_height(Some(4));
+
+        terminal
+            .draw(|frame| {
+                assert_eq!(frame.area(), Rect::new(0, 2, 9, 4));
+                frame.render_widget(Text::raw("line 1\nline 2"), frame.area());
+            })
+            .unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 2, 9, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["history 1", "history 2", "line 1", "line 2", ""]);
+        terminal.backend().assert_scrollback_empty();
+
+        // the viewport does not fit anymore, so the history is scrolled up
+        terminal
+            .draw(|frame| frame.render_widget(Text::raw("a\nb\nc\nd\ne"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 1, 9, 4));
+        terminal.backend().assert_buffer_lines([
+            "history 2",
+            "a        ",
+            "b        ",
+            "c        ",
+            "d        ",
+        ]);
+        terminal.backend().assert_scrollback_lines(["history 1"]);
+    }
+
+    #[test]
+    fn draw_does_not_shrink_inline_viewport() {
+        let options = TerminalOptions {
+            viewport: Viewport::Inline(1),
+        };
+        let mut terminal = Terminal::with_options(TestBackend::new(3, 5), options).unwrap();
+        terminal.set_inline_max_height(Some(3));
+        terminal
+            .draw(|frame| frame.render_widget(Text::raw("a\nb"), frame.area()))
+            .unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(Text::raw("c"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 0, 3, 2));
+        terminal
+            .backend()
+            .assert_buffer_lines(["c  ", "   ", "   ", "   ", "   "]);
+    }
+
+    #[test]
+    fn draw_clips_inline_viewport_without_max_height() {
+        let options = TerminalOptions {
+            viewport: Viewport::Inline(1),
+        };
+        let mut terminal = Terminal::with_options(TestBackend::new(3, 3), options).unwrap();
+        terminal
+            .draw(|frame| frame.render_widget(Text::raw("a\nb"), frame.area()))
+            .unwrap();
+        assert_eq!(terminal.viewport_area, Rect::new(0, 0, 3, 1));
+        terminal
+            .backend()
+            .assert_buffer_lines(["a  ", "   ", "   "]);
+    }
+
+    #[test]
+    fn draw_collects_accessibility_tree() {
+        use crate::accessibility::{Annotation, Role};
+        use crate::widgets::Widget;
+
+        struct Label(&'static str);
+
+        impl Widget for Label {
+            fn render(self, area: Rect, buf: &mut Buffer) {
+                Line::raw(self.0).render(area, buf);
+                if let Some(tree) = buf.accessibility_tree_mut() {
+                    tree.push(Annotation::new(Role::Text, self.0, area));
+                }
+            }
+        }
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 1)).unwrap();
+        let completed = terminal
+            .draw(|frame| frame.render_widget(Label("Hello"), frame.area()))
+            .unwrap();
+        assert_eq!(completed.accessibility_tree(), None);
+
+        terminal.enable_accessibility_tree(true);
+        for text in ["Hello", "Hi"] {
+            let completed = terminal
+                .draw(|frame| frame.render_widget(Label(text), frame.area()))
+                .unwrap();
+            let tree = completed.accessibility_tree().unwrap();
+            assert_eq!(
+                tree.annotations(),
+                [Annotation::new(Role::Text, text, Rect::new(0, 0, 10, 1))]
+            );
+        }
+    }
+}
@@ -665,9 +665,13 @@ impl<'a> core::ops::AddAssign<Span<'a>> for Line<'a> {
     }
 }
 
-impl<'a> Extend<Span<'a>> for Line<'a> {
-    fn extend<T: IntoIterator<Item = Span<'a>>>(&mut self, iter: T) {
-        self.spans.extend(iter);
+impl<'a, T> Extend<T> for Line<'a>
+where
+    T: Into<Span<'a>>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let spans = iter.into_iter().map(Into::into);
+        self.spans.extend(spans);
     }
 }
 
@@ -1094,6 +1098,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn extend_from_iter() {
+        let mut line = Line::from("Hello, ").bold().centered();
+        line.extend(["world", "!"]);
+        line.extend(vec![String::from(" How are you?")]);
+        assert_eq!(
+            line,
+            Line::from_iter(["Hello, ", "world", "!", " How are you?"])
+                .bold()
+                .centered()
+        );
+    }
+
     #[test]
     fn into_string() {
         let line = Line::from(vec![
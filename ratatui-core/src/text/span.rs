@@ -169,6 +169,33 @@ impl<'a> Span<'a> {
         }
     }
 
+    /// Create a span with the specified style containing `symbol` repeated `count` times.
+    ///
+    /// This is useful for drawing horizontal rules or filler bars. The width of the span is the
+    /// width of `symbol` multiplied by `count`, so symbols which take up more than one column (e.g.
+    /// `"＝"`) or are made of several characters (e.g. `"-="`) are repeated as a whole.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::Span;
+    ///
+    /// let rule = Span::repeat("─", 10, Style::new().dark_gray());
+    /// assert_eq!(rule.content, "──────────");
+    ///
+    /// let wide = Span::repeat("＝", 3, Style::new());
+    /// assert_eq!(wide.width(), 6);
+    /// ```
+    ///
+    /// [`Color`]: crate::style::Color
+    pub fn repeat<S: Into<Style>>(symbol: &str, count: usize, style: S) -> Span<'static> {
+        Span::styled(symbol.repeat(count), style)
+    }
+
     /// Sets the content of the span.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -272,6 +299,29 @@ impl<'a> Span<'a> {
         self.content.width()
     }
 
+    /// Returns the width and the number of graphemes of the content held by this span.
+    ///
+    /// Both are computed in a single pass over the graphemes of the content (e.g. for cursor
+    /// positioning). The width is the sum of the widths of the graphemes, which is how the span is
+    /// laid out when rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Span;
+    ///
+    /// assert_eq!(Span::raw("abc").measure(), (3, 3));
+    /// // wide characters take 2 columns
+    /// assert_eq!(Span::raw("日本").measure(), (4, 2));
+    /// ```
+    pub fn measure(&self) -> (usize, usize) {
+        self.content
+            .graphemes(true)
+            .fold((0, 0), |(width, count), grapheme| {
+                (width + grapheme.width(), count + 1)
+            })
+    }
+
     /// Returns an iterator over the graphemes held by this span.
     ///
     /// `base_style` is the [`Style`] that will be patched with the `Span`'s `style` to get the
@@ -549,6 +599,32 @@ mod tests {
         assert_eq!(span.style, style);
     }
 
+    #[rstest]
+    #[case::single_column("─", 4, "────", 4)]
+    #[case::wide("＝", 3, "＝＝＝", 6)]
+    #[case::multiple_chars("-=", 3, "-=-=-=", 6)]
+    #[case::zero_count("─", 0, "", 0)]
+    #[case::empty_symbol("", 5, "", 0)]
+    fn repeat(
+        #[case] symbol: &str,
+        #[case] count: usize,
+        #[case] expected: &str,
+        #[case] expected_width: usize,
+    ) {
+        let span = Span::repeat(symbol, count, Style::new().red());
+        assert_eq!(span.content, expected);
+        assert_eq!(span.width(), expected_width);
+        assert_eq!(span.style, Style::new().red());
+    }
+
+    #[test]
+    fn render_repeat_wide() {
+        let span = Span::repeat("＝", 3, Style::new());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        span.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["＝＝＝ "]));
+    }
+
     #[test]
     fn set_content() {
         let span = Span::default().content("test content");
@@ -621,6 +697,16 @@ mod tests {
         assert_eq!(Span::raw("test\ncontent").width(), 12);
     }
 
+    #[rstest]
+    #[case::empty("", (0, 0))]
+    #[case::ascii("test", (4, 4))]
+    #[case::wide_characters("日本語", (6, 3))]
+    #[case::combining_characters("e\u{301}a\u{308}", (2, 2))]
+    #[case::zero_width_joiner("👨\u{200d}👩", (2, 1))]
+    fn measure(#[case] content: &str, #[case] expected: (usize, usize)) {
+        assert_eq!(Span::raw(content).measure(), expected);
+    }
+
     #[test]
     fn stylize() {
         let span = Span::raw("test content").green();
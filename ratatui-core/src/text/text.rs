@@ -4,6 +4,7 @@ use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::fmt;
+use core::ops::Range;
 
 use crate::buffer::Buffer;
 use crate::layout::{Alignment, Rect};
@@ -301,6 +302,41 @@ impl<'a> Text<'a> {
         self.lines.len()
     }
 
+    /// Returns the number of logical lines.
+    ///
+    /// This is the same as [`Text::height`], and doesn't take wrapping into account.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Text;
+    ///
+    /// let text = Text::from("The first line\nThe second line");
+    /// assert_eq!(2, text.lines_count());
+    /// ```
+    pub fn lines_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Returns the number of graphemes in all the lines.
+    ///
+    /// Graphemes are counted regardless of their width, and control characters are ignored as
+    /// they are not rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::text::Text;
+    ///
+    /// let text = Text::from("The first line\nThe second line");
+    /// assert_eq!(29, text.graphemes_count());
+    /// ```
+    pub fn graphemes_count(&self) -> usize {
+        self.iter()
+            .map(|line| line.styled_graphemes(Style::default()).count())
+            .sum()
+    }
+
     /// Sets the style of this text.
     ///
     /// Defaults to [`Style::default()`].
@@ -557,6 +593,91 @@ impl<'a> Text<'a> {
             self.lines.push(Line::from(span));
         }
     }
+
+    /// Styles the given byte ranges of the text, e.g. to apply the output of a syntax highlighter.
+    ///
+    /// The ranges are byte offsets in the whole text, with the lines separated by a single `\n`
+    /// (as in the [`Display`](fmt::Display) implementation of the text). The spans are split at the
+    /// boundaries of the ranges, and each part of a span covered by a range is patched with the
+    /// style of the range. If several ranges overlap, the last one wins. Offsets which are not on a
+    /// character boundary are moved back to the start of the character.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui_core::style::{Style, Stylize};
+    /// use ratatui_core::text::{Line, Span, Text};
+    ///
+    /// let text = Text::from("let a = 1;\nlet b = 2;")
+    ///     .apply_highlights(&[(0..3, Style::new().blue()), (11..14, Style::new().blue())]);
+    /// assert_eq!(
+    ///     text.lines[1],
+    ///     Line::from_iter([Span::from("let").blue(), Span::from(" b = 2;")]),
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn apply_highlights(mut self, ranges: &[(Range<usize>, Style)]) -> Self {
+        let mut offset = 0;
+        for line in &mut self.lines {
+            let spans = core::mem::take(&mut line.spans);
+            for span in spans {
+                let start = offset;
+                offset += span.content.len();
+                highlight_span(&span, start, ranges, &mut line.spans);
+            }
+            // the line separator
+            offset += 1;
+        }
+        self
+    }
+}
+
+/// Pushes the parts of the span starting at the byte offset `start` of the text, split at the
+/// boundaries of the highlighted ranges and styled by the last range covering each part
+#[expect(clippy::string_slice)] // Is safe as the offsets are moved to char boundaries
+fn highlight_span<'a>(
+    span: &Span<'a>,
+    start: usize,
+    ranges: &[(Range<usize>, Style)],
+    spans: &mut Vec<Span<'a>>,
+) {
+    let content = span.content.as_ref();
+    // the offset in the span of the character containing the given offset of the text
+    let offset_in_span = |offset: usize| {
+        let mut offset = offset.clamp(start, start + content.len()) - start;
+        while !content.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    };
+    let mut cuts: Vec<usize> = ranges
+        .iter()
+        .flat_map(|(range, _)| [range.start, range.end])
+        .map(offset_in_span)
+        .chain([0, content.len()])
+        .collect();
+    cuts.sort_unstable();
+    cuts.dedup();
+    for part in cuts.windows(2) {
+        let (from, to) = (part[0], part[1]);
+        if from == to {
+            continue;
+        }
+        let style = ranges
+            .iter()
+            .rev()
+            .find(|(range, _)| {
+                (offset_in_span(range.start)..offset_in_span(range.end)).contains(&from)
+            })
+            .map_or(span.style, |(_, style)| span.style.patch(*style));
+        let content = match &span.content {
+            Cow::Borrowed(content) => Cow::Borrowed(&content[from..to]),
+            Cow::Owned(content) => Cow::Owned(content[from..to].to_owned()),
+        };
+        spans.push(Span { style, content });
+    }
 }
 
 impl<'a> IntoIterator for Text<'a> {
@@ -791,6 +912,23 @@ mod tests {
         assert_eq!(2, text.height());
     }
 
+    #[test]
+    fn lines_count() {
+        assert_eq!(Text::default().lines_count(), 0);
+        let text = Text::from("The first line\n\nコンピュータ");
+        assert_eq!(text.lines_count(), 3);
+    }
+
+    #[test]
+    fn graphemes_count() {
+        assert_eq!(Text::default().graphemes_count(), 0);
+        let mut text = Text::from("The first line\n\nコンピュータ");
+        text.push_span(Span::raw("👨‍👩‍👧").red());
+        // the wide characters and the emoji sequence count as one grapheme each
+        assert_eq!(text.width(), 14);
+        assert_eq!(text.graphemes_count(), 14 + 6 + 1);
+    }
+
     #[test]
     fn patch_style() {
         let style = Style::new().yellow().italic();
@@ -907,6 +1045,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn collect_lines_and_strings() {
+        let lines = [Line::from("first").bold(), Line::from("second").centered()];
+        let text: Text = lines.iter().cloned().collect();
+        assert_eq!(text.lines, lines);
+
+        let text: Text = (1..=2).map(|i| format!("line {i}")).collect();
+        assert_eq!(text, Text::from("line 1\nline 2"));
+    }
+
     #[test]
     fn into_iter() {
         let text = Text::from("The first line\nThe second line");
@@ -1387,4 +1535,69 @@ mod tests {
             ]).on_blue().italic().centered()"#}
         );
     }
+
+    #[test]
+    fn apply_highlights_adjacent_ranges() {
+        let text = Text::from("let a = 1;")
+            .apply_highlights(&[(0..3, Style::new().blue()), (3..6, Style::new().red())]);
+        assert_eq!(
+            text,
+            Text::from(Line::from_iter([
+                Span::from("let").blue(),
+                Span::from(" a ").red(),
+                Span::from("= 1;"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn apply_highlights_overlapping_ranges() {
+        let text = Text::from("abcdef").apply_highlights(&[
+            (0..4, Style::new().blue()),
+            (2..6, Style::new().red()),
+            (3..4, Style::new().bold()),
+        ]);
+        assert_eq!(
+            text,
+            Text::from(Line::from_iter([
+                Span::from("ab").blue(),
+                Span::from("c").red(),
+                Span::from("d").bold(),
+                Span::from("ef").red(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn apply_highlights_across_lines_and_spans() {
+        let text = Text::from_iter([
+            Line::from_iter([Span::from("ab").italic(), Span::from("cd")]).centered(),
+            Line::from("ef"),
+        ])
+        // the second range starts at the line separator
+        .apply_highlights(&[(1..3, Style::new().red()), (4..6, Style::new().blue())]);
+        assert_eq!(
+            text,
+            Text::from_iter([
+                Line::from_iter([
+                    Span::from("a").italic(),
+                    Span::from("b").italic().red(),
+                    Span::from("c").red(),
+                    Span::from("d"),
+                ])
+                .centered(),
+                Line::from_iter([Span::from("e").blue(), Span::from("f")]),
+            ])
+        );
+    }
+
+    #[test]
+    fn apply_highlights_multibyte_characters() {
+        // "é" is 2 bytes long, the range ends in its middle
+        let text = Text::from(String::from("aéb")).apply_highlights(&[(0..2, Style::new().red())]);
+        assert_eq!(
+            text,
+            Text::from(Line::from_iter([Span::from("a").red(), Span::from("éb")]))
+        );
+    }
 }
@@ -17,6 +17,9 @@ use std::io::{self, Write};
 
 pub use crossterm;
 use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{
+    DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange,
+};
 #[cfg(feature = "underline-color")]
 use crossterm::style::SetUnderlineColor;
 use crossterm::style::{
@@ -37,6 +40,12 @@ use ratatui_core::style::{Color, Modifier, Style};
 /// used to send commands to the terminal. It provides methods for drawing content, manipulating
 /// the cursor, and clearing the terminal screen.
 ///
+/// The commands are not written to the writer directly, but are buffered until the backend is
+/// flushed (which [`Terminal`] does once per frame). This ensures that a frame is sent to the
+/// terminal in a single write instead of a write per command. The buffer grows as needed, its
+/// initial capacity can be set with [`CrosstermBackend::with_buffer_capacity`]. The commands still
+/// in the buffer are written to the writer when the backend is dropped.
+///
 /// Most applications should not call the methods on `CrosstermBackend` directly, but will instead
 /// use the [`Terminal`] struct, which provides a more ergonomic interface.
 ///
@@ -90,6 +99,8 @@ use ratatui_core::style::{Color, Modifier, Style};
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
     writer: W,
+    /// The commands waiting to be written to the writer on the next flush.
+    buffer: Vec<u8>,
 }
 
 impl<W> CrosstermBackend<W>
@@ -113,7 +124,34 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Sets the initial capacity of the buffer in which the commands are stored until the next
+    /// flush.
+    ///
+    /// The buffer grows as needed, so this only avoids reallocations of the buffer for the first
+    /// frames. A good capacity is a few times the number of cells of the terminal.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::CrosstermBackend;
+    ///
+    /// let backend = CrosstermBackend::new(stdout()).with_buffer_capacity(64 * 1024);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.buffer
+            .reserve(capacity.saturating_sub(self.buffer.len()));
+        self
     }
 
     /// Gets the writer.
@@ -128,7 +166,8 @@ where
     /// Gets the writer as a mutable reference.
     ///
     /// Note: writing to the writer may cause incorrect output after the write. This is due to the
-    /// way that the Terminal implements diffing Buffers.
+    /// way that the Terminal implements diffing Buffers. The buffered commands are only written
+    /// to the writer when the backend is flushed.
     #[instability::unstable(
         feature = "backend-writer",
         issue = "https://github.com/ratatui/ratatui/pull/991"
@@ -136,23 +175,111 @@ where
     pub const fn writer_mut(&mut self) -> &mut W {
         &mut self.writer
     }
+
+    /// Enables the bracketed paste mode of the terminal (`\x1b[?2004h`).
+    ///
+    /// In this mode, the terminal wraps pasted text in escape sequences, which crossterm reports
+    /// as a single [`Event::Paste`] instead of a key event per character.
+    ///
+    /// The mode should be disabled with [`CrosstermBackend::disable_bracketed_paste`] before the
+    /// application exits.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::CrosstermBackend;
+    ///
+    /// let mut backend = CrosstermBackend::new(stdout());
+    /// backend.enable_bracketed_paste()?;
+    /// // -- snip --
+    /// backend.disable_bracketed_paste()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`Event::Paste`]: crossterm::event::Event::Paste
+    pub fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        execute!(self, EnableBracketedPaste)
+    }
+
+    /// Disables the bracketed paste mode of the terminal (`\x1b[?2004l`).
+    ///
+    /// See [`CrosstermBackend::enable_bracketed_paste`] for more details.
+    pub fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        execute!(self, DisableBracketedPaste)
+    }
+
+    /// Enables the reporting of focus changes by the terminal (`\x1b[?1004h`).
+    ///
+    /// When enabled, crossterm reports an [`Event::FocusGained`] or [`Event::FocusLost`] when the
+    /// terminal gains or loses the focus (e.g. to pause animations while the application is not
+    /// visible).
+    ///
+    /// The reporting should be disabled with [`CrosstermBackend::disable_focus_change`] before the
+    /// application exits.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::io::stdout;
+    ///
+    /// use ratatui::backend::CrosstermBackend;
+    ///
+    /// let mut backend = CrosstermBackend::new(stdout());
+    /// backend.enable_focus_change()?;
+    /// // -- snip --
+    /// backend.disable_focus_change()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`Event::FocusGained`]: crossterm::event::Event::FocusGained
+    /// [`Event::FocusLost`]: crossterm::event::Event::FocusLost
+    pub fn enable_focus_change(&mut self) -> io::Result<()> {
+        execute!(self, EnableFocusChange)
+    }
+
+    /// Disables the reporting of focus changes by the terminal (`\x1b[?1004l`).
+    ///
+    /// See [`CrosstermBackend::enable_focus_change`] for more details.
+    pub fn disable_focus_change(&mut self) -> io::Result<()> {
+        execute!(self, DisableFocusChange)
+    }
 }
 
 impl<W> Write for CrosstermBackend<W>
 where
     W: Write,
 {
-    /// Writes a buffer of bytes to the underlying buffer.
+    /// Writes a buffer of bytes to the buffer of the backend.
+    ///
+    /// The bytes are written to the underlying writer on the next flush.
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        self.writer.write(buf)
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
     }
 
-    /// Flushes the underlying buffer.
+    /// Writes the buffered bytes to the underlying writer in a single write, and flushes it.
     fn flush(&mut self) -> io::Result<()> {
+        if !self.buffer.is_empty() {
+            self.writer.write_all(&self.buffer)?;
+            self.buffer.clear();
+        }
         self.writer.flush()
     }
 }
 
+impl<W> Drop for CrosstermBackend<W>
+where
+    W: Write,
+{
+    /// Writes the commands still in the buffer to the writer.
+    fn drop(&mut self) {
+        // errors cannot be reported from a destructor
+        let _ = Write::flush(self);
+    }
+}
+
 impl<W> Backend for CrosstermBackend<W>
 where
     W: Write,
@@ -172,7 +299,7 @@ where
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
             if !matches!(last_pos, Some(p) if x == p.x + 1 && y == p.y) {
-                queue!(self.writer, MoveTo(x, y))?;
+                queue!(self, MoveTo(x, y))?;
             }
             last_pos = Some(Position { x, y });
             if cell.modifier != modifier {
@@ -180,12 +307,12 @@ where
                     from: modifier,
                     to: cell.modifier,
                 };
-                diff.queue(&mut self.writer)?;
+                diff.queue(&mut *self)?;
                 modifier = cell.modifier;
             }
             if cell.fg != fg || cell.bg != bg {
                 queue!(
-                    self.writer,
+                    self,
                     SetColors(CrosstermColors::new(
                         cell.fg.into_crossterm(),
                         cell.bg.into_crossterm(),
@@ -197,16 +324,16 @@ where
             #[cfg(feature = "underline-color")]
             if cell.underline_color != underline_color {
                 let color = cell.underline_color.into_crossterm();
-                queue!(self.writer, SetUnderlineColor(color))?;
+                queue!(self, SetUnderlineColor(color))?;
                 underline_color = cell.underline_color;
             }
 
-            queue!(self.writer, Print(cell.symbol()))?;
+            queue!(self, Print(cell.symbol()))?;
         }
 
         #[cfg(feature = "underline-color")]
         return queue!(
-            self.writer,
+            self,
             SetForegroundColor(CrosstermColor::Reset),
             SetBackgroundColor(CrosstermColor::Reset),
             SetUnderlineColor(CrosstermColor::Reset),
@@ -214,7 +341,7 @@ where
         );
         #[cfg(not(feature = "underline-color"))]
         return queue!(
-            self.writer,
+            self,
             SetForegroundColor(CrosstermColor::Reset),
             SetBackgroundColor(CrosstermColor::Reset),
             SetAttribute(CrosstermAttribute::Reset),
@@ -222,11 +349,11 @@ where
     }
 
     fn hide_cursor(&mut self) -> io::Result<()> {
-        execute!(self.writer, Hide)
+        execute!(self, Hide)
     }
 
     fn show_cursor(&mut self) -> io::Result<()> {
-        execute!(self.writer, Show)
+        execute!(self, Show)
     }
 
     fn get_cursor_position(&mut self) -> io::Result<Position> {
@@ -237,7 +364,7 @@ where
 
     fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
         let Position { x, y } = position.into();
-        execute!(self.writer, MoveTo(x, y))
+        execute!(self, MoveTo(x, y))
     }
 
     fn clear(&mut self) -> io::Result<()> {
@@ -246,7 +373,7 @@ where
 
     fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
         execute!(
-            self.writer,
+            self,
             Clear(match clear_type {
                 ClearType::All => crossterm::terminal::ClearType::All,
                 ClearType::AfterCursor => crossterm::terminal::ClearType::FromCursorDown,
@@ -259,9 +386,9 @@ where
 
     fn append_lines(&mut self, n: u16) -> io::Result<()> {
         for _ in 0..n {
-            queue!(self.writer, Print("\n"))?;
+            queue!(self, Print("\n"))?;
         }
-        self.writer.flush()
+        Write::flush(self)
     }
 
     fn size(&self) -> io::Result<Size> {
@@ -286,33 +413,33 @@ where
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        self.writer.flush()
+        Write::flush(self)
     }
 
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_up(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
-            self.writer,
+            self,
             ScrollUpInRegion {
                 first_row: region.start,
                 last_row: region.end.saturating_sub(1),
                 lines_to_scroll: amount,
             }
         )?;
-        self.writer.flush()
+        Write::flush(self)
     }
 
     #[cfg(feature = "scrolling-regions")]
     fn scroll_region_down(&mut self, region: std::ops::Range<u16>, amount: u16) -> io::Result<()> {
         queue!(
-            self.writer,
+            self,
             ScrollDownInRegion {
                 first_row: region.start,
                 last_row: region.end.saturating_sub(1),
                 lines_to_scroll: amount,
             }
         )?;
-        self.writer.flush()
+        Write::flush(self)
     }
 }
 
@@ -399,8 +526,9 @@ impl ModifierDiff {
     where
         W: io::Write,
     {
-        //use crossterm::Attribute;
         let removed = self.from - self.to;
+        // the modifiers which are unchanged, only used when they are reset with a removed one
+        let kept = self.from & self.to;
         if removed.contains(Modifier::REVERSED) {
             queue!(w, SetAttribute(CrosstermAttribute::NoReverse))?;
         }
@@ -408,13 +536,13 @@ impl ModifierDiff {
             // Bold and Dim are both reset by applying the Normal intensity
             queue!(w, SetAttribute(CrosstermAttribute::NormalIntensity))?;
 
-            // The remaining Bold and Dim attributes must be
-            // reapplied after the intensity reset above.
-            if self.to.contains(Modifier::DIM) {
+            // The kept Bold and Dim attributes must be reapplied after the intensity reset above.
+            // The added ones are applied below.
+            if kept.contains(Modifier::DIM) {
                 queue!(w, SetAttribute(CrosstermAttribute::Dim))?;
             }
 
-            if self.to.contains(Modifier::BOLD) {
+            if kept.contains(Modifier::BOLD) {
                 queue!(w, SetAttribute(CrosstermAttribute::Bold))?;
             }
         }
@@ -427,8 +555,18 @@ impl ModifierDiff {
         if removed.contains(Modifier::CROSSED_OUT) {
             queue!(w, SetAttribute(CrosstermAttribute::NotCrossedOut))?;
         }
+        if removed.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CrosstermAttribute::NoHidden))?;
+        }
         if removed.contains(Modifier::SLOW_BLINK) || removed.contains(Modifier::RAPID_BLINK) {
+            // Both blinks are reset at once, so the kept one must be reapplied
             queue!(w, SetAttribute(CrosstermAttribute::NoBlink))?;
+            if kept.contains(Modifier::SLOW_BLINK) {
+                queue!(w, SetAttribute(CrosstermAttribute::SlowBlink))?;
+            }
+            if kept.contains(Modifier::RAPID_BLINK) {
+                queue!(w, SetAttribute(CrosstermAttribute::RapidBlink))?;
+            }
         }
 
         let added = self.to - self.from;
@@ -456,6 +594,9 @@ impl ModifierDiff {
         if added.contains(Modifier::RAPID_BLINK) {
             queue!(w, SetAttribute(CrosstermAttribute::RapidBlink))?;
         }
+        if added.contains(Modifier::HIDDEN) {
+            queue!(w, SetAttribute(CrosstermAttribute::Hidden))?;
+        }
 
         Ok(())
     }
@@ -642,6 +783,8 @@ impl crate::crossterm::Command for ScrollDownInRegion {
 
 #[cfg(test)]
 mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
     use rstest::rstest;
 
     use super::*;
@@ -672,6 +815,93 @@ mod tests {
         assert_eq!(Color::from_crossterm(crossterm_color), color);
     }
 
+    #[test]
+    fn draw_does_not_emit_skipped_cells() {
+        let previous = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut next = Buffer::with_lines(["abc"]);
+        next[(1, 0)].set_skip(true);
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.draw(previous.diff(&next).into_iter()).unwrap();
+        Backend::flush(&mut backend).unwrap();
+
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert!(output.contains('a'));
+        assert!(!output.contains('b'));
+        assert!(output.contains('c'));
+    }
+
+    #[rstest]
+    #[case::add_bold(Modifier::empty(), Modifier::BOLD, "\x1b[1m")]
+    #[case::add_bold_to_italic(Modifier::ITALIC, Modifier::ITALIC | Modifier::BOLD, "\x1b[1m")]
+    #[case::remove_italic(Modifier::ITALIC | Modifier::BOLD, Modifier::BOLD, "\x1b[23m")]
+    #[case::remove_bold_keep_dim(Modifier::BOLD | Modifier::DIM, Modifier::DIM, "\x1b[22m\x1b[2m")]
+    #[case::replace_bold_with_dim(Modifier::BOLD, Modifier::DIM, "\x1b[22m\x1b[2m")]
+    #[case::remove_slow_blink_keep_rapid(
+        Modifier::SLOW_BLINK | Modifier::RAPID_BLINK,
+        Modifier::RAPID_BLINK,
+        "\x1b[25m\x1b[6m"
+    )]
+    #[case::add_hidden(Modifier::empty(), Modifier::HIDDEN, "\x1b[8m")]
+    #[case::remove_hidden(Modifier::HIDDEN, Modifier::empty(), "\x1b[28m")]
+    #[case::unchanged(Modifier::BOLD, Modifier::BOLD, "")]
+    fn modifier_diff(#[case] from: Modifier, #[case] to: Modifier, #[case] expected: &str) {
+        let mut output = Vec::new();
+        ModifierDiff { from, to }.queue(&mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn draw_toggling_bold_emits_only_bold() {
+        let mut next = Buffer::with_lines(["abc"]);
+        next.set_style(Rect::new(1, 0, 1, 1), Modifier::BOLD);
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .draw(
+                next.content()
+                    .iter()
+                    .enumerate()
+                    .map(|(x, cell)| (x as u16, 0, cell)),
+            )
+            .unwrap();
+        Backend::flush(&mut backend).unwrap();
+
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        assert!(output.contains("a\x1b[1mb\x1b[22mc"), "{output:?}");
+    }
+
+    #[test]
+    fn draw_crossed_out_text() {
+        let previous = Buffer::with_lines(["abc"]);
+        let mut next = Buffer::with_lines(["abc"]);
+        next.set_style(Rect::new(1, 0, 1, 1), Modifier::CROSSED_OUT);
+
+        let updates = previous.diff(&next);
+        assert_eq!(updates, [(1, 0, &next[(1, 0)])]);
+
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend
+            .draw(
+                next.content()
+                    .iter()
+                    .enumerate()
+                    .map(|(x, cell)| (x as u16, 0, cell)),
+            )
+            .unwrap();
+        Backend::flush(&mut backend).unwrap();
+
+        let output = String::from_utf8(backend.writer().clone()).unwrap();
+        let crossed_out = output
+            .find("\x1b[9mb")
+            .expect("SGR 9 before the crossed out text");
+        let not_crossed_out = output
+            .find("\x1b[29mc")
+            .expect("SGR 29 after the crossed out text");
+        assert!(output.find('a').unwrap() < crossed_out);
+        assert!(crossed_out < not_crossed_out);
+    }
+
     mod modifier {
         use super::*;
 
@@ -813,4 +1043,80 @@ mod tests {
             Style::default().underline_color(Color::Red)
         );
     }
+
+    /// A writer which counts the number of calls to `write`
+    #[derive(Debug, Default)]
+    struct CountingWriter {
+        bytes: Vec<u8>,
+        writes: usize,
+    }
+
+    impl Write for CountingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.writes += 1;
+            self.bytes.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn draw_writes_once_per_flush() {
+        let previous = Buffer::empty(Rect::new(0, 0, 10, 2));
+        let mut next = Buffer::with_lines(["abc  def", "ghi"]);
+        next.set_style(Rect::new(0, 1, 2, 1), Style::new().fg(Color::Red));
+        let updates = previous.diff(&next);
+        assert!(updates.len() > 1);
+
+        let mut backend = CrosstermBackend::new(CountingWriter::default());
+        backend.draw(updates.into_iter()).unwrap();
+        assert_eq!(backend.writer().writes, 0);
+
+        Backend::flush(&mut backend).unwrap();
+        assert_eq!(backend.writer().writes, 1);
+        let output = String::from_utf8(backend.writer().bytes.clone()).unwrap();
+        assert!(output.contains("abc"));
+        assert!(output.contains("def"));
+        assert!(output.ends_with("\x1b[0m"));
+
+        // nothing is written when there is nothing to flush
+        Backend::flush(&mut backend).unwrap();
+        assert_eq!(backend.writer().writes, 1);
+    }
+
+    #[test]
+    fn drop_flushes_the_buffer() {
+        let mut output = Vec::new();
+        let mut backend = CrosstermBackend::new(&mut output);
+        backend.write_all(b"abc").unwrap();
+        drop(backend);
+        assert_eq!(output, b"abc");
+    }
+
+    #[test]
+    fn with_buffer_capacity() {
+        let backend = CrosstermBackend::new(Vec::new()).with_buffer_capacity(1024);
+        assert!(backend.buffer.capacity() >= 1024);
+        assert!(backend.buffer.is_empty());
+    }
+
+    #[test]
+    fn bracketed_paste() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.enable_bracketed_paste().unwrap();
+        assert_eq!(backend.writer(), b"\x1b[?2004h");
+        backend.disable_bracketed_paste().unwrap();
+        assert_eq!(backend.writer(), b"\x1b[?2004h\x1b[?2004l");
+    }
+
+    #[test]
+    fn focus_change() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.enable_focus_change().unwrap();
+        assert_eq!(backend.writer(), b"\x1b[?1004h");
+        backend.disable_focus_change().unwrap();
+        assert_eq!(backend.writer(), b"\x1b[?1004h\x1b[?1004l");
+    }
 }
@@ -52,6 +52,7 @@ macro_rules! line {
 
 #[cfg(test)]
 mod tests {
+    use ratatui_core::style::Stylize;
     use ratatui_core::text::{Line, Span};
 
     #[test]
@@ -66,6 +67,15 @@ mod tests {
         assert_eq!(line, Line::from(vec!["hello".into(), "world".into()]));
     }
 
+    #[test]
+    fn line_mixed_literal_and_styled_span() {
+        let line = line!["Error: ", "fatal".red().bold()];
+        assert_eq!(
+            line,
+            Line::default().spans([Span::raw("Error: "), Span::raw("fatal").red().bold()])
+        );
+    }
+
     #[test]
     fn line_vec_count_syntax() {
         let line = line!["hello"; 2];
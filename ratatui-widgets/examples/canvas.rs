@@ -68,6 +68,7 @@ pub fn render_canvas(frame: &mut Frame, area: Rect) {
             ctx.draw(&Map {
                 resolution: MapResolution::High,
                 color: Color::White,
+                ..Default::default()
             });
             ctx.layer();
             ctx.draw(&Line::new(0.0, 10.0, 10.0, 10.0, Color::Blue));
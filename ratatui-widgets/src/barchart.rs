@@ -1,11 +1,15 @@
 //! The [`BarChart`] widget and its related types (e.g. [`Bar`], [`BarGroup`]).
 
+use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Direction, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Color, Style, Styled};
 use ratatui_core::symbols;
 use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
@@ -106,6 +110,44 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// Function used to format the values printed on each bar
+    label_formatter: Option<LabelFormatter<'a>>,
+    /// Whether the color of the values is chosen to contrast with the color of the bars
+    auto_label_contrast: bool,
+}
+
+/// A function formatting the value of a [`Bar`] into its label
+///
+/// The function is reference counted so that the [`BarChart`] can still be cloned, and two
+/// formatters are considered equal only when they point to the same function (e.g. when one is a
+/// clone of the other), not when two separate closures behave the same.
+#[derive(Clone)]
+struct LabelFormatter<'a>(Arc<dyn Fn(u64) -> String + Send + Sync + 'a>);
+
+impl LabelFormatter<'_> {
+    fn format(&self, value: u64) -> String {
+        (self.0)(value)
+    }
+}
+
+impl fmt::Debug for LabelFormatter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LabelFormatter")
+    }
+}
+
+impl PartialEq for LabelFormatter<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for LabelFormatter<'_> {}
+
+impl Hash for LabelFormatter<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
 }
 
 impl Default for BarChart<'_> {
@@ -123,6 +165,8 @@ impl Default for BarChart<'_> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            label_formatter: None,
+            auto_label_contrast: false,
         }
     }
 }
@@ -362,6 +406,67 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Set the function used to format the value printed on each bar.
+    ///
+    /// By default, the value of a bar is printed as is. The formatter receives the value of the
+    /// bar and returns its label, e.g. to display `1.2k` instead of `1200`. It is not used for
+    /// bars with a [`Bar::text_value`].
+    ///
+    /// Labels are rendered the same way as unformatted values: in vertical bar charts, a label
+    /// that does not fit in the width of its bar is omitted, and in horizontal bar charts, a label
+    /// is truncated to the width of the chart.
+    ///
+    /// Two bar charts with formatters are only equal if they share the same formatter, e.g. when
+    /// one is a clone of the other.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// let chart = BarChart::default()
+    ///     .data(&[("CPU", 45), ("MEM", 80)])
+    ///     .label_formatter(|value| format!("{value}%"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_formatter<F>(mut self, formatter: F) -> Self
+    where
+        F: Fn(u64) -> String + Send + Sync + 'a,
+    {
+        self.label_formatter = Some(LabelFormatter(Arc::new(formatter)));
+        self
+    }
+
+    /// Set whether the color of the values printed on the bars is chosen automatically.
+    ///
+    /// When enabled, the values are printed in black or white, whichever contrasts the most with
+    /// the color of the bar, over a background of the color of the bar. The choice is based on the
+    /// [relative luminance] of the bar color: values over bars whose luminance is above `0.179`
+    /// (where the contrast ratio with black and white is the same) are printed in black.
+    ///
+    /// This has no effect on bars without a foreground color or with [`Color::Reset`]. The
+    /// [value style](Bar::value_style) of a bar takes precedence over the chosen color.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::BarChart;
+    ///
+    /// let chart = BarChart::default()
+    ///     .bar_style(Style::new().yellow())
+    ///     .auto_label_contrast(true);
+    /// ```
+    ///
+    /// [relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_label_contrast(mut self, auto_label_contrast: bool) -> Self {
+        self.auto_label_contrast = auto_label_contrast;
+        self
+    }
+
     /// Set the direction of the bars.
     ///
     /// [`Vertical`](ratatui_core::layout::Direction::Vertical) bars are the default.
@@ -532,8 +637,9 @@ impl BarChart<'_> {
                     buf,
                     bar_value_area,
                     bar_length as usize,
-                    self.value_style,
+                    self.bar_value_style(bar),
                     self.bar_style,
+                    self.label_formatter.as_ref(),
                 );
 
                 bar_y += self.bar_gap + self.bar_width;
@@ -601,6 +707,23 @@ impl BarChart<'_> {
         }
     }
 
+    /// Returns the default style of the value printed on the given bar.
+    ///
+    /// When [`BarChart::auto_label_contrast`] is enabled, the value is printed in the color
+    /// contrasting the most with the color of the bar.
+    fn bar_value_style(&self, bar: &Bar) -> Style {
+        if !self.auto_label_contrast {
+            return self.value_style;
+        }
+        let Some(bar_color) = self.bar_style.patch(bar.style).fg else {
+            return self.value_style;
+        };
+        match contrasting_color(bar_color) {
+            Some(color) => self.value_style.fg(color).bg(bar_color),
+            None => self.value_style,
+        }
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
         self.max
@@ -647,7 +770,14 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                let value_area = Rect::new(bar_x, bar_y, self.bar_width, 1);
+                bar.render_value(
+                    buf,
+                    value_area,
+                    self.bar_value_style(bar),
+                    *ticks,
+                    self.label_formatter.as_ref(),
+                );
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -656,6 +786,31 @@ impl BarChart<'_> {
     }
 }
 
+/// Returns black or white, whichever has the best contrast when printed over the given color.
+///
+/// Returns `None` for [`Color::Reset`] as its actual color is unknown.
+fn contrasting_color(color: Color) -> Option<Color> {
+    /// The relative luminance above which black has a better contrast ratio than white
+    const LUMINANCE_THRESHOLD: f64 = 0.179;
+
+    let (r, g, b) = color.to_rgb()?;
+    // the sRGB transfer function, converting a component to linear light
+    let linear = |c: u8| {
+        let c = f64::from(c) / 255.0;
+        if c <= 0.04045 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let luminance = 0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b);
+    if luminance > LUMINANCE_THRESHOLD {
+        Some(Color::Black)
+    } else {
+        Some(Color::White)
+    }
+}
+
 impl Widget for BarChart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -693,10 +848,13 @@ impl Styled for BarChart<'_> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
     use itertools::iproduct;
     use ratatui_core::layout::Alignment;
     use ratatui_core::style::{Color, Modifier, Stylize};
     use ratatui_core::text::Span;
+    use rstest::rstest;
 
     use super::*;
     use crate::borders::BorderType;
@@ -1119,6 +1277,149 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn label_formatter_with_suffix() {
+        let chart = BarChart::default()
+            .bar_width(3)
+            .data(&[("a", 4), ("b", 8)])
+            .label_formatter(|value| format!("{value}%"));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["    ███", "4%█ 8%█", " a   b "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn label_formatter_omits_labels_wider_than_bar() {
+        let chart = BarChart::default()
+            .bar_width(2)
+            .data(&[("a", 1200), ("b", 45)])
+            .label_formatter(|value| format!("{}.{}k", value / 1000, value % 1000 / 100));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 2));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["██   ", "a  b "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn label_formatter_does_not_override_text_value() {
+        let chart = BarChart::default()
+            .bar_width(3)
+            .data(BarGroup::new([Bar::default().value(8).text_value("max")]))
+            .label_formatter(|value| format!("{value}%"));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["max"]));
+    }
+
+    #[test]
+    fn label_formatter_horizontal_truncated() {
+        let chart = BarChart::default()
+            .direction(Direction::Horizontal)
+            .data(&[("a", 2), ("b", 4)])
+            .label_formatter(|value| format!("{value} units"));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["a 2 uni", "       ", "b 4 uni"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn label_formatter_keeps_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        let chart = BarChart::default().label_formatter(|value| format!("{value}%"));
+        assert_send_sync(&chart);
+        assert_eq!(chart.clone(), chart);
+        assert_ne!(
+            chart,
+            BarChart::default().label_formatter(|value| format!("{value}%"))
+        );
+    }
+
+    #[rstest]
+    #[case::black(Color::Black, Some(Color::White))]
+    #[case::blue(Color::Blue, Some(Color::White))]
+    #[case::dark_rgb(Color::Rgb(30, 30, 60), Some(Color::White))]
+    #[case::dark_indexed(Color::Indexed(17), Some(Color::White))]
+    #[case::mid_gray(Color::Rgb(112, 112, 112), Some(Color::White))]
+    #[case::yellow(Color::Yellow, Some(Color::Black))]
+    #[case::white(Color::White, Some(Color::Black))]
+    #[case::light_rgb(Color::Rgb(200, 220, 255), Some(Color::Black))]
+    #[case::light_grayscale(Color::Indexed(250), Some(Color::Black))]
+    #[case::reset(Color::Reset, None)]
+    fn contrasting_color(#[case] color: Color, #[case] expected: Option<Color>) {
+        assert_eq!(super::contrasting_color(color), expected);
+    }
+
+    #[test]
+    fn auto_label_contrast() {
+        let chart = BarChart::default()
+            .bar_width(3)
+            .data(BarGroup::new([
+                Bar::default().value(5).style(Color::Blue),
+                Bar::default().value(5).style(Color::Yellow),
+            ]))
+            .auto_label_contrast(true);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["█5█ █5█"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::new().blue());
+        expected.set_style(Rect::new(4, 0, 3, 1), Style::new().yellow());
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().white().on_blue());
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().black().on_yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn auto_label_contrast_horizontal() {
+        let chart = BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_style(Color::Yellow)
+            .data(&[("a", 4)])
+            .auto_label_contrast(true);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["a 4██"]);
+        expected.set_style(Rect::new(2, 0, 3, 1), Style::new().yellow());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().black().on_yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn auto_label_contrast_disabled_by_default() {
+        let chart = BarChart::default()
+            .bar_style(Color::Yellow)
+            .data(&[("a", 4)]);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 2));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["4", "a"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn auto_label_contrast_bar_value_style_takes_precedence() {
+        let chart = BarChart::default()
+            .bar_style(Color::Yellow)
+            .data(BarGroup::new([Bar::default()
+                .value(4)
+                .value_style(Color::Red)]))
+            .auto_label_contrast(true);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["4"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().on_yellow());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_group_label_center() {
         // test the centered group position when one bar is outside the group
@@ -1143,6 +1444,28 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn group_gap_between_groups() {
+        let group = BarGroup::from(&[("a", 8), ("b", 8)]);
+        let chart = BarChart::default()
+            .data(group.clone().label(Line::from("G1").centered()))
+            .data(group.label(Line::from("G2").centered()))
+            .bar_width(2)
+            .bar_gap(1)
+            .group_gap(3);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 4));
+        chart.render(buffer.area, &mut buffer);
+        // bars of a group are 1 cell apart and groups are 3 cells apart
+        let expected = Buffer::with_lines([
+            "██ ██    ██ ██",
+            "8█ 8█    8█ 8█",
+            "a  b     a  b ",
+            " G1       G2  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_group_label_right() {
         let chart: BarChart<'_> = BarChart::default().data(
@@ -7,6 +7,8 @@ use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 use unicode_width::UnicodeWidthStr;
 
+use super::LabelFormatter;
+
 /// A bar to be shown by the [`BarChart`](super::BarChart) widget.
 ///
 /// Here is an explanation of a `Bar`'s components.
@@ -192,9 +194,22 @@ impl<'a> Bar<'a> {
         self
     }
 
+    /// Returns the label displayed for the value of the bar.
+    ///
+    /// [`text_value`](Bar::text_value) is used if set, otherwise the value is formatted with the
+    /// `formatter`, or converted to string if there is none.
+    fn value_label(&self, formatter: Option<&LabelFormatter>) -> String {
+        self.text_value.clone().unwrap_or_else(|| {
+            formatter.map_or_else(
+                || self.value.to_string(),
+                |formatter| formatter.format(self.value),
+            )
+        })
+    }
+
     /// Render the value of the bar.
     ///
-    /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
+    /// The label of the value is computed by [`Bar::value_label`].
     /// The value is rendered using `value_style`. If the value width is greater than the
     /// bar width, then the value is split into 2 parts. the first part is rendered in the bar
     /// using `value_style`. The second part is rendered outside the bar using `bar_style`
@@ -205,15 +220,15 @@ impl<'a> Bar<'a> {
         bar_length: usize,
         default_value_style: Style,
         bar_style: Style,
+        formatter: Option<&LabelFormatter>,
     ) {
-        let value = self.value.to_string();
-        let text = self.text_value.as_ref().unwrap_or(&value);
+        let text = self.value_label(formatter);
 
         if !text.is_empty() {
             let style = default_value_style.patch(self.value_style);
             // Since the value may be longer than the bar itself, we need to use 2 different styles
             // while rendering. Render the first part with the default value style
-            buf.set_stringn(area.x, area.y, text, bar_length, style);
+            buf.set_stringn(area.x, area.y, &text, bar_length, style);
             // render the second part with the bar_style
             if text.len() > bar_length {
                 let (first, second) = text.split_at(bar_length);
@@ -233,23 +248,22 @@ impl<'a> Bar<'a> {
     pub(super) fn render_value(
         &self,
         buf: &mut Buffer,
-        max_width: u16,
-        x: u16,
-        y: u16,
+        area: Rect,
         default_value_style: Style,
         ticks: u64,
+        formatter: Option<&LabelFormatter>,
     ) {
         if self.value != 0 {
             const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
+            let value_label = self.value_label(formatter);
+            let max_width = area.width;
             let width = value_label.width() as u16;
             // if we have enough space or the ticks are greater equal than 1 cell (8)
             // then print the value
             if width < max_width || (width == max_width && ticks >= TICKS_PER_LINE) {
                 buf.set_string(
-                    x + (max_width.saturating_sub(value_label.len() as u16) >> 1),
-                    y,
+                    area.x + (max_width.saturating_sub(width) >> 1),
+                    area.y,
                     value_label,
                     default_value_style.patch(self.value_style),
                 );
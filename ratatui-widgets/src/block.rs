@@ -6,20 +6,25 @@
 //! [title](Block::title) and [padding](Block::padding).
 #![allow(deprecated)] // to avoid having to add `#[deprecated]` to every use of `Title`
 
+use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use itertools::Itertools;
+use ratatui_core::accessibility::{Annotation, Role};
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme, ThemeRole};
 use ratatui_core::symbols::border;
+use ratatui_core::symbols::line::{self, Connections};
 use ratatui_core::text::Line;
 use ratatui_core::widgets::Widget;
 
+pub use self::blocked::{Blocked, WidgetExt};
 pub use self::padding::Padding;
 pub use self::title::{Position, Title};
 use crate::borders::{BorderType, Borders};
 
+mod blocked;
 mod padding;
 pub mod title;
 
@@ -122,6 +127,8 @@ pub struct Block<'a> {
     /// The symbols used to render the border. The default is plain lines but one can choose to
     /// have rounded or doubled lines instead or a custom set of symbols
     border_set: border::Set,
+    /// Whether the borders are merged with the lines already drawn in the buffer
+    collapse_borders: bool,
     /// Widget style
     style: Style,
     /// Block padding
@@ -139,6 +146,7 @@ impl<'a> Block<'a> {
             borders: Borders::NONE,
             border_style: Style::new(),
             border_set: BorderType::Plain.to_border_set(),
+            collapse_borders: false,
             style: Style::new(),
             padding: Padding::ZERO,
         }
@@ -432,6 +440,30 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Patches the style of the entire block with the style of the given role of the theme
+    ///
+    /// Does nothing if `theme` is `None`, so that the widget can be built the same way whether
+    /// the application uses a theme or not. See [`Theme`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize, Theme, ThemeRole};
+    /// use ratatui::widgets::Block;
+    ///
+    /// let theme = Theme::new().with_style(ThemeRole::Error, Style::new().red());
+    /// let block = Block::bordered().themed(Some(&theme), ThemeRole::Error);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn themed(mut self, theme: Option<&Theme>, role: ThemeRole) -> Self {
+        if let Some(theme) = theme {
+            self.style = self.style.patch(theme.style(role));
+        }
+        self
+    }
+
     /// Defines which borders to display.
     ///
     /// [`Borders`] can also be styled with [`Block::border_style`] and [`Block::border_type`].
@@ -496,6 +528,43 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Merges the borders with the lines already drawn where they meet.
+    ///
+    /// When blocks are rendered so that their borders overlap (e.g. with a negative
+    /// [`Layout::spacing`]), the borders of the last rendered block replace the ones below. With
+    /// this option, each border symbol is instead combined with the line symbol it is drawn over,
+    /// so that the lines meet with the correct junction symbols (`├ ┤ ┬ ┴ ┼`).
+    ///
+    /// Only the lines of the same type are merged. This is supported for the
+    /// [`Plain`](BorderType::Plain), [`Rounded`](BorderType::Rounded),
+    /// [`Double`](BorderType::Double), [`Thick`](BorderType::Thick) and dashed border types and
+    /// has no effect with other border symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::buffer::Buffer;
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::{Block, Widget};
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+    /// Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buf);
+    /// Block::bordered()
+    ///     .collapse_borders(true)
+    ///     .render(Rect::new(3, 0, 4, 3), &mut buf);
+    /// assert_eq!(
+    ///     buf,
+    ///     Buffer::with_lines(["┌──┬──┐", "│  │  │", "└──┴──┘"])
+    /// );
+    /// ```
+    ///
+    /// [`Layout::spacing`]: ratatui_core::layout::Layout::spacing
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn collapse_borders(mut self, collapse: bool) -> Self {
+        self.collapse_borders = collapse;
+        self
+    }
+
     /// Defines the padding inside a `Block`.
     ///
     /// See [`Padding`] for more information.
@@ -614,6 +683,10 @@ impl Widget for &Block<'_> {
 
 impl Block<'_> {
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(line_set) = self.line_set().filter(|_| self.collapse_borders) {
+            self.render_collapsed_borders(area, buf, &line_set);
+            return;
+        }
         self.render_left_side(area, buf);
         self.render_top_side(area, buf);
         self.render_right_side(area, buf);
@@ -625,9 +698,78 @@ impl Block<'_> {
         self.render_top_left_corner(buf, area);
     }
 
+    /// Returns the line symbols matching the border symbols, if any
+    fn line_set(&self) -> Option<line::Set> {
+        [
+            (border::PLAIN, line::NORMAL),
+            (border::ROUNDED, line::ROUNDED),
+            (border::DOUBLE, line::DOUBLE),
+            (border::THICK, line::THICK),
+            (border::LIGHT_DOUBLE_DASHED, line::LIGHT_DOUBLE_DASHED),
+            (border::HEAVY_DOUBLE_DASHED, line::HEAVY_DOUBLE_DASHED),
+            (border::LIGHT_TRIPLE_DASHED, line::LIGHT_TRIPLE_DASHED),
+            (border::HEAVY_TRIPLE_DASHED, line::HEAVY_TRIPLE_DASHED),
+            (border::LIGHT_QUADRUPLE_DASHED, line::LIGHT_QUADRUPLE_DASHED),
+            (border::HEAVY_QUADRUPLE_DASHED, line::HEAVY_QUADRUPLE_DASHED),
+        ]
+        .into_iter()
+        .find_map(|(border_set, line_set)| (border_set == self.border_set).then_some(line_set))
+    }
+
+    /// Renders the borders, merging each border symbol with the line symbol it is drawn over
+    fn render_collapsed_borders(&self, area: Rect, buf: &mut Buffer, line_set: &line::Set) {
+        let (left, right) = (area.left(), area.right() - 1);
+        let (top, bottom) = (area.top(), area.bottom() - 1);
+        let rows = [top, bottom].into_iter().dedup();
+        let columns = [left, right].into_iter().dedup();
+        let cells = rows
+            .flat_map(|y| (left..=right).map(move |x| (x, y)))
+            .chain(columns.flat_map(|x| (top + 1..bottom).map(move |y| (x, y))));
+        for (x, y) in cells {
+            // the lines stop at the edges of the area
+            let mut connections = Connections::empty();
+            if (y == top && self.borders.contains(Borders::TOP))
+                || (y == bottom && self.borders.contains(Borders::BOTTOM))
+            {
+                connections.set(Connections::LEFT, x != left);
+                connections.set(Connections::RIGHT, x != right);
+            }
+            if (x == left && self.borders.contains(Borders::LEFT))
+                || (x == right && self.borders.contains(Borders::RIGHT))
+            {
+                connections.set(Connections::UP, y != top);
+                connections.set(Connections::DOWN, y != bottom);
+            }
+            if connections.is_empty() {
+                continue;
+            }
+            let cell = &mut buf[(x, y)];
+            connections |= line_set.connections(cell.symbol()).unwrap_or_default();
+            if let Some(symbol) = line_set.connecting(connections) {
+                cell.set_symbol(symbol).set_style(self.border_style);
+            }
+        }
+    }
+
     fn render_titles(&self, area: Rect, buf: &mut Buffer) {
         self.render_title_position(Position::Top, area, buf);
         self.render_title_position(Position::Bottom, area, buf);
+        self.annotate_titles(area, buf);
+    }
+
+    /// Pushes an accessibility annotation for each title, in the order the titles were added
+    ///
+    /// The area of each annotation is the row in which the title is rendered.
+    fn annotate_titles(&self, area: Rect, buf: &mut Buffer) {
+        let Some(tree) = buf.accessibility_tree_mut() else {
+            return;
+        };
+        for (position, title) in &self.titles {
+            let titles_area = self.titles_area(area, position.unwrap_or(self.titles_position));
+            if !titles_area.is_empty() {
+                tree.push(Annotation::new(Role::Title, title.to_string(), titles_area));
+            }
+        }
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
@@ -1148,6 +1290,7 @@ mod tests {
                 borders: Borders::NONE,
                 border_style: Style::new(),
                 border_set: BorderType::Plain.to_border_set(),
+                collapse_borders: false,
                 style: Style::new(),
                 padding: Padding::ZERO,
             }
@@ -1168,6 +1311,22 @@ mod tests {
             .padding(_DEFAULT_PADDING);
     }
 
+    #[test]
+    fn themed() {
+        let theme = Theme::new()
+            .with_style(ThemeRole::Primary, Style::new().white())
+            .with_style(ThemeRole::Error, Style::new().red().bold());
+        let block = Block::new()
+            .style(Style::new().on_blue())
+            .themed(Some(&theme), ThemeRole::Error);
+        assert_eq!(block.style, Style::new().red().on_blue().bold());
+
+        let block = Block::new()
+            .style(Style::new().on_blue())
+            .themed(None, ThemeRole::Error);
+        assert_eq!(block.style, Style::new().on_blue());
+    }
+
     /// Ensure Style from/into works the way a user would use it.
     #[test]
     fn style_into_works_from_user_view() {
@@ -1361,6 +1520,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn block_title_style_under_span_styles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Block::new()
+            .title_style(Style::new().yellow())
+            .title(Line::from(vec!["plain".into(), "x".red()]))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["plainx".yellow()]);
+        expected.set_style(Rect::new(5, 0, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn title_border_style() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1647,4 +1818,120 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn accessibility_tree_contains_titles() {
+        let block = Block::bordered()
+            .title("Top")
+            .title_bottom(Line::from("Bottom").right_aligned());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        buffer.enable_accessibility_tree(true);
+        block.render(buffer.area, &mut buffer);
+        let tree = buffer.accessibility_tree().unwrap();
+        assert_eq!(
+            tree.annotations(),
+            [
+                Annotation::new(Role::Title, "Top", Rect::new(1, 0, 8, 1)),
+                Annotation::new(Role::Title, "Bottom", Rect::new(1, 2, 8, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_collapsed_borders_side_by_side() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        let block = Block::bordered().collapse_borders(true);
+        block.clone().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        block.render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┬──┐",
+            "│  │  │",
+            "└──┴──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_collapsed_borders_stacked() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 5));
+        let block = Block::bordered().collapse_borders(true);
+        block.clone().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        block.render(Rect::new(0, 2, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┐",
+            "│  │",
+            "├──┤",
+            "│  │",
+            "└──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_collapsed_borders_in_grid() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+        let block = Block::bordered().collapse_borders(true);
+        for (x, y) in [(0, 0), (3, 0), (0, 2), (3, 2)] {
+            block.clone().render(Rect::new(x, y, 4, 3), &mut buffer);
+        }
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┬──┐",
+            "│  │  │",
+            "├──┼──┤",
+            "│  │  │",
+            "└──┴──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_collapsed_borders_with_sides() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Block::bordered().render(buffer.area, &mut buffer);
+        Block::new()
+            .borders(Borders::LEFT)
+            .collapse_borders(true)
+            .render(Rect::new(2, 0, 3, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌─┬─┐",
+            "│ │ │",
+            "└─┴─┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_collapsed_borders_with_different_border_type() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered()
+            .border_type(BorderType::Double)
+            .collapse_borders(true)
+            .render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──╔══╗",
+            "│  ║  ║",
+            "└──╚══╝",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_borders_without_collapse_replace_lines() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered().render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┌──┐",
+            "│  │  │",
+            "└──└──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
 }
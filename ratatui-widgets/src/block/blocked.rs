@@ -0,0 +1,126 @@
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::widgets::Widget;
+
+use crate::block::Block;
+
+/// An extension trait for [`Widget`]s that provides a way to surround them with a [`Block`].
+///
+/// This is implemented for all widgets. Many widgets already have a `block` method of their own
+/// which takes precedence over this one, this trait is mostly useful for widgets that don't (e.g.
+/// custom widgets or `Line`).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::text::Line;
+/// use ratatui::widgets::{Block, WidgetExt};
+///
+/// let widget = Line::from("Hello, world!").block(Block::bordered().title("Greeting"));
+/// ```
+pub trait WidgetExt: Sized {
+    /// Surrounds the widget with the given [`Block`].
+    ///
+    /// The returned [`Blocked`] widget renders the block in the whole area and the widget in the
+    /// [inner area](Block::inner) of the block.
+    fn block(self, block: Block<'_>) -> Blocked<'_, Self>;
+}
+
+impl<W: Widget> WidgetExt for W {
+    fn block(self, block: Block<'_>) -> Blocked<'_, Self> {
+        Blocked::new(block, self)
+    }
+}
+
+/// A widget surrounded by a [`Block`].
+///
+/// This is usually created with [`WidgetExt::block`]. When rendered, the block is rendered in the
+/// whole area and the widget is rendered in the [inner area](Block::inner) of the block.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::text::Line;
+/// use ratatui::widgets::{Block, Blocked};
+///
+/// let widget = Blocked::new(Block::bordered(), Line::from("Hello, world!"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Blocked<'a, W> {
+    block: Block<'a>,
+    widget: W,
+}
+
+impl<'a, W> Blocked<'a, W> {
+    /// Creates a new `Blocked` widget which surrounds the `widget` with the `block`.
+    pub const fn new(block: Block<'a>, widget: W) -> Self {
+        Self { block, widget }
+    }
+}
+
+impl<W: Widget> Widget for Blocked<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        self.block.render(area, buf);
+        self.widget.render(inner, buf);
+    }
+}
+
+impl<W> Widget for &Blocked<'_, W>
+where
+    for<'w> &'w W: Widget,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        (&self.block).render(area, buf);
+        (&self.widget).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_core::text::Line;
+
+    use super::*;
+    use crate::block::Padding;
+
+    #[test]
+    fn render_widget_in_inner_area() {
+        let widget = Line::from("Hello world").block(Block::bordered().title("Title"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        widget.render(buf.area, &mut buf);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌Title──┐",
+            "│Hello w│",
+            "└───────┘",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_padding() {
+        let block = Block::bordered().padding(Padding::horizontal(1));
+        let widget = Blocked::new(block, Line::from("abcdef"));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+        (&widget).render(buf.area, &mut buf);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌─────┐",
+            "│ abc │",
+            "└─────┘",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_block_style_under_widget() {
+        let widget = Line::from("ab")
+            .red()
+            .block(Block::new().style(Style::new().on_blue()));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        widget.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ab ".red().on_blue()]));
+    }
+}
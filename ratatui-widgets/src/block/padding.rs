@@ -1,3 +1,5 @@
+use ratatui_core::layout::Rect;
+
 /// Defines the padding for a [`Block`].
 ///
 /// See the [`padding`] method of [`Block`] to configure its padding.
@@ -156,10 +158,49 @@ impl Padding {
             bottom: value,
         }
     }
+
+    /// Returns the area inside the padding, i.e. `area` shrunk by the padding on each side.
+    ///
+    /// If the padding is larger than the area, the inner area is empty and positioned inside the
+    /// given area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::Padding;
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// assert_eq!(Padding::new(1, 2, 0, 1).inner(area), Rect::new(1, 0, 7, 4));
+    /// ```
+    pub const fn inner(self, area: Rect) -> Rect {
+        let left = if self.left < area.width {
+            self.left
+        } else {
+            area.width
+        };
+        let top = if self.top < area.height {
+            self.top
+        } else {
+            area.height
+        };
+        Rect {
+            x: area.x + left,
+            y: area.y + top,
+            width: area
+                .width
+                .saturating_sub(self.left.saturating_add(self.right)),
+            height: area
+                .height
+                .saturating_sub(self.top.saturating_add(self.bottom)),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use rstest::rstest;
+
     use super::*;
 
     #[test]
@@ -188,6 +229,16 @@ mod tests {
         assert_eq!(Padding::bottom(1), Padding::new(0, 0, 0, 1));
     }
 
+    #[rstest]
+    #[case::zero(Padding::ZERO, Rect::new(2, 3, 10, 6))]
+    #[case::uniform(Padding::uniform(1), Rect::new(3, 4, 8, 4))]
+    #[case::each_side(Padding::new(1, 2, 3, 0), Rect::new(3, 6, 7, 3))]
+    #[case::exact_fit(Padding::symmetric(5, 3), Rect::new(7, 6, 0, 0))]
+    #[case::too_large(Padding::new(20, 0, 0, 10), Rect::new(12, 3, 0, 0))]
+    fn inner(#[case] padding: Padding, #[case] expected: Rect) {
+        assert_eq!(padding.inner(Rect::new(2, 3, 10, 6)), expected);
+    }
+
     #[test]
     const fn can_be_const() {
         const _PADDING: Padding = Padding::new(1, 1, 1, 1);
@@ -6,6 +6,7 @@
 //! The available shapes are:
 //!
 //! - [`Circle`]: A basic circle
+//! - [`FilledRectangle`]: A rectangle with its interior painted
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
 //! - [`Points`]: A scatter of points
@@ -26,22 +27,27 @@ use ratatui_core::layout::Rect;
 use ratatui_core::style::{Color, Style};
 use ratatui_core::symbols::{self, Marker};
 use ratatui_core::text::Line as TextLine;
-use ratatui_core::widgets::Widget;
+use ratatui_core::widgets::{StatefulWidget, Widget};
 
 pub use self::circle::Circle;
-pub use self::line::Line;
-pub use self::map::{Map, MapResolution};
+pub use self::filled_rectangle::FilledRectangle;
+pub use self::line::{ArrowEnd, Line};
+pub use self::map::{Map, MapPolylines, MapProjection, MapResolution};
 pub use self::points::Points;
 pub use self::rectangle::Rectangle;
 use crate::block::{Block, BlockExt};
 
 mod circle;
+mod filled_rectangle;
 mod line;
 mod map;
 mod points;
 mod rectangle;
 mod world;
 
+/// A bounding box in canvas coordinates as `(min_x, min_y, max_x, max_y)`
+pub type Rect2D = (f64, f64, f64, f64);
+
 /// Something that can be drawn on a [`Canvas`].
 ///
 /// You may implement your own canvas custom widgets by implementing this trait.
@@ -51,6 +57,15 @@ pub trait Shape {
     /// This is the only method required to implement a custom widget that can be drawn on a
     /// [`Canvas`].
     fn draw(&self, painter: &mut Painter);
+
+    /// Returns the bounding box of this [`Shape`] in canvas coordinates.
+    ///
+    /// This can be used for layout or culling, and is used by [`Canvas::auto_bounds`] to fit the
+    /// canvas to the drawn shapes. The default implementation returns `None`, meaning that the
+    /// extent of the shape is unknown.
+    fn bounds(&self) -> Option<Rect2D> {
+        None
+    }
 }
 
 /// Label to draw some text on the canvas
@@ -331,7 +346,9 @@ impl Grid for HalfBlockGrid {
     }
 
     fn reset(&mut self) {
-        self.pixels.fill(vec![Color::Reset; self.width as usize]);
+        for row in &mut self.pixels {
+            row.fill(Color::Reset);
+        }
     }
 
     fn paint(&mut self, x: usize, y: usize, color: Color) {
@@ -347,6 +364,8 @@ impl Grid for HalfBlockGrid {
 pub struct Painter<'a, 'b> {
     context: &'a mut Context<'b>,
     resolution: (f64, f64),
+    /// The clip bounds converted to grid coordinates as `(min_x, min_y, max_x, max_y)`
+    grid_clip: Option<Rect2D>,
 }
 
 impl Painter<'_, '_> {
@@ -364,7 +383,7 @@ impl Painter<'_, '_> {
     /// `(x, y)` coordinates to the location of a point on the grid.
     ///
     /// Points are rounded to the nearest grid cell (with points exactly in the center of a cell
-    /// rounding up).
+    /// rounding up). Points outside of the [clip bounds](Canvas::clip_bounds) return `None`.
     ///
     /// # Examples
     ///
@@ -396,6 +415,11 @@ impl Painter<'_, '_> {
         if x < left || x > right || y < bottom || y > top {
             return None;
         }
+        if let Some((min_x, min_y, max_x, max_y)) = self.context.clip_bounds {
+            if x < min_x || x > max_x || y < min_y || y > max_y {
+                return None;
+            }
+        }
         let width = right - left;
         let height = top - bottom;
         if width <= 0.0 || height <= 0.0 {
@@ -408,6 +432,8 @@ impl Painter<'_, '_> {
 
     /// Paint a point of the grid
     ///
+    /// Points outside of the [clip bounds](Canvas::clip_bounds) are not painted.
+    ///
     /// # Example
     ///
     /// ```
@@ -420,6 +446,12 @@ impl Painter<'_, '_> {
     /// painter.paint(1, 3, Color::Red);
     /// ```
     pub fn paint(&mut self, x: usize, y: usize, color: Color) {
+        if let Some((min_x, min_y, max_x, max_y)) = self.grid_clip {
+            let (x, y) = (x as f64, y as f64);
+            if x < min_x || x > max_x || y < min_y || y > max_y {
+                return;
+            }
+        }
         self.context.grid.paint(x, y, color);
     }
 
@@ -439,18 +471,69 @@ impl Painter<'_, '_> {
     pub const fn bounds(&self) -> (&[f64; 2], &[f64; 2]) {
         (&self.context.x_bounds, &self.context.y_bounds)
     }
+
+    /// Canvas clip bounds as `(min_x, min_y, max_x, max_y)`, if any.
+    ///
+    /// Shapes that compute grid points themselves should stay within these bounds, as the points
+    /// outside of them are not painted. See [`Canvas::clip_bounds`].
+    pub const fn clip_bounds(&self) -> Option<Rect2D> {
+        self.context.clip_bounds
+    }
+
+    /// Converts the clip bounds of the context to grid coordinates
+    ///
+    /// The corners are rounded the same way as in [`Painter::get_point`] so that every point
+    /// inside the clip bounds is converted to a grid point inside the grid clip bounds.
+    fn grid_clip(context: &Context, resolution: (f64, f64)) -> Option<Rect2D> {
+        let (min_x, min_y, max_x, max_y) = context.clip_bounds?;
+        let [left, right] = context.x_bounds;
+        let [bottom, top] = context.y_bounds;
+        let x_scale = (resolution.0 - 1.0) / (right - left);
+        let y_scale = (resolution.1 - 1.0) / (top - bottom);
+        Some((
+            ((min_x - left) * x_scale).round(),
+            ((top - max_y) * y_scale).round(),
+            ((max_x - left) * x_scale).round(),
+            ((top - min_y) * y_scale).round(),
+        ))
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
     fn from(context: &'a mut Context<'b>) -> Self {
         let resolution = context.grid.resolution();
+        let grid_clip = Painter::grid_clip(context, resolution);
         Self {
             context,
             resolution,
+            grid_clip,
         }
     }
 }
 
+/// Creates a new blank grid of the given size for the marker
+/// Widens bounds with no extent to a unit around their center, so that points can be placed in them
+fn widen_degenerate([min, max]: [f64; 2]) -> [f64; 2] {
+    if max - min > 0.0 {
+        return [min, max];
+    }
+    let center = (min + max) / 2.0;
+    [center - 0.5, center + 0.5]
+}
+
+fn new_grid(width: u16, height: u16, marker: Marker) -> Box<dyn Grid> {
+    let dot = symbols::DOT.chars().next().unwrap();
+    let block = symbols::block::FULL.chars().next().unwrap();
+    let bar = symbols::bar::HALF.chars().next().unwrap();
+    match marker {
+        Marker::Dot => Box::new(CharGrid::new(width, height, dot)),
+        Marker::Block => Box::new(CharGrid::new(width, height, block)),
+        Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
+        Marker::Braille => Box::new(BrailleGrid::new(width, height)),
+        Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
+    }
+}
+
 /// Holds the state of the [`Canvas`] when painting to it.
 ///
 /// This is used by the [`Canvas`] widget to draw shapes on the grid. It can be useful to think of
@@ -463,6 +546,9 @@ pub struct Context<'a> {
     dirty: bool,
     layers: Vec<Layer>,
     labels: Vec<Label<'a>>,
+    shape_bounds: Option<Rect2D>,
+    clip_bounds: Option<Rect2D>,
+    bounds_only: bool,
 }
 
 impl<'a> Context<'a> {
@@ -496,16 +582,11 @@ impl<'a> Context<'a> {
         y_bounds: [f64; 2],
         marker: Marker,
     ) -> Self {
-        let dot = symbols::DOT.chars().next().unwrap();
-        let block = symbols::block::FULL.chars().next().unwrap();
-        let bar = symbols::bar::HALF.chars().next().unwrap();
-        let grid: Box<dyn Grid> = match marker {
-            Marker::Dot => Box::new(CharGrid::new(width, height, dot)),
-            Marker::Block => Box::new(CharGrid::new(width, height, block)),
-            Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
-            Marker::Braille => Box::new(BrailleGrid::new(width, height)),
-            Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
-        };
+        Self::with_grid(new_grid(width, height, marker), x_bounds, y_bounds)
+    }
+
+    /// Create a new Context which paints on the given grid
+    fn with_grid(grid: Box<dyn Grid>, x_bounds: [f64; 2], y_bounds: [f64; 2]) -> Self {
         Self {
             x_bounds,
             y_bounds,
@@ -513,6 +594,18 @@ impl<'a> Context<'a> {
             dirty: false,
             layers: Vec::new(),
             labels: Vec::new(),
+            shape_bounds: None,
+            clip_bounds: None,
+            bounds_only: false,
+        }
+    }
+
+    /// Create a new Context which only collects the bounds of the drawn shapes, without painting
+    /// them
+    fn bounds_only(x_bounds: [f64; 2], y_bounds: [f64; 2], marker: Marker) -> Self {
+        Self {
+            bounds_only: true,
+            ..Self::with_grid(new_grid(0, 0, marker), x_bounds, y_bounds)
         }
     }
 
@@ -522,6 +615,19 @@ impl<'a> Context<'a> {
         S: Shape,
     {
         self.dirty = true;
+        if let Some(bounds) = shape.bounds() {
+            self.shape_bounds = Some(self.shape_bounds.map_or(bounds, |current| {
+                (
+                    current.0.min(bounds.0),
+                    current.1.min(bounds.1),
+                    current.2.max(bounds.2),
+                    current.3.max(bounds.3),
+                )
+            }));
+        }
+        if self.bounds_only {
+            return;
+        }
         let mut painter = Painter::from(self);
         shape.draw(&mut painter);
     }
@@ -564,6 +670,66 @@ impl<'a> Context<'a> {
     }
 }
 
+/// State of the [`Canvas`] widget
+///
+/// The canvas paints the shapes on a grid of the size of the canvas, which is allocated on each
+/// render. When the canvas is rendered as a [`StatefulWidget`] with a `CanvasState`, the grid is
+/// kept in the state and cleared in place on the next render instead. This avoids allocating the
+/// grid on each frame of an animation. The grid is allocated again when the size or the marker of
+/// the canvas changes.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use ratatui::widgets::canvas::{Canvas, CanvasState, Circle};
+/// use ratatui::Frame;
+///
+/// # fn ui(frame: &mut Frame, state: &mut CanvasState, radius: f64) {
+/// # let area = Rect::default();
+/// // The state should be stored outside of the function in your application state.
+/// let canvas = Canvas::default()
+///     .x_bounds([-10.0, 10.0])
+///     .y_bounds([-10.0, 10.0])
+///     .paint(|ctx| {
+///         ctx.draw(&Circle {
+///             x: 0.0,
+///             y: 0.0,
+///             radius,
+///             color: Color::Red,
+///         });
+///     });
+/// frame.render_stateful_widget(&canvas, area, state);
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct CanvasState {
+    grid: Option<CachedGrid>,
+}
+
+/// A grid kept by the [`CanvasState`] between renders
+#[derive(Debug)]
+struct CachedGrid {
+    width: u16,
+    height: u16,
+    marker: Marker,
+    grid: Box<dyn Grid>,
+}
+
+impl CanvasState {
+    /// Takes the cached grid if it matches the given size and marker, cleared
+    fn take_grid(&mut self, width: u16, height: u16, marker: Marker) -> Option<Box<dyn Grid>> {
+        let cached = self.grid.take()?;
+        if (cached.width, cached.height, cached.marker) != (width, height, marker) {
+            return None;
+        }
+        let mut grid = cached.grid;
+        grid.reset();
+        Some(grid)
+    }
+}
+
 /// The Canvas widget provides a means to draw shapes (Lines, Rectangles, Circles, etc.) on a grid.
 ///
 /// By default the grid is made of Braille patterns but you may change the marker to use a different
@@ -605,15 +771,10 @@ impl<'a> Context<'a> {
 ///         ctx.draw(&Map {
 ///             resolution: MapResolution::High,
 ///             color: Color::White,
+///             ..Default::default()
 ///         });
 ///         ctx.layer();
-///         ctx.draw(&Line {
-///             x1: 0.0,
-///             y1: 10.0,
-///             x2: 10.0,
-///             y2: 10.0,
-///             color: Color::White,
-///         });
+///         ctx.draw(&Line::new(0.0, 10.0, 10.0, 10.0, Color::White));
 ///         ctx.draw(&Rectangle {
 ///             x: 10.0,
 ///             y: 20.0,
@@ -636,6 +797,9 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: Marker,
+    auto_bounds: bool,
+    auto_bounds_padding: f64,
+    clip_bounds: Option<Rect2D>,
 }
 
 impl<F> Default for Canvas<'_, F>
@@ -650,6 +814,9 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: Marker::Braille,
+            auto_bounds: false,
+            auto_bounds_padding: 0.0,
+            clip_bounds: None,
         }
     }
 }
@@ -691,6 +858,76 @@ where
         self
     }
 
+    /// Fit the viewport of the canvas to the drawn shapes.
+    ///
+    /// When enabled, the bounds are computed from the [`Shape::bounds`] of the shapes drawn by the
+    /// paint closure, which is therefore called twice when rendering (the shapes are only painted
+    /// on the second call). An axis on which the shapes have no extent, e.g. for a single point, is
+    /// widened by `0.5` on each side. The bounds set with [`Canvas::x_bounds`] and
+    /// [`Canvas::y_bounds`] are used when no shape reports its bounds.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::{Canvas, Points};
+    ///
+    /// Canvas::default()
+    ///     .auto_bounds(true)
+    ///     .auto_bounds_padding(1.0)
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Points::new(&[(10.0, 5.0), (20.0, 15.0)], Color::Red));
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_bounds(mut self, auto_bounds: bool) -> Self {
+        self.auto_bounds = auto_bounds;
+        self
+    }
+
+    /// Set the padding added on each side of the bounds computed by [`Canvas::auto_bounds`].
+    ///
+    /// The padding is expressed in canvas coordinates and defaults to `0.0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_bounds_padding(mut self, padding: f64) -> Self {
+        self.auto_bounds_padding = padding;
+        self
+    }
+
+    /// Restrict painting to a region of the canvas coordinate space.
+    ///
+    /// The clip bounds are expressed in canvas coordinates as `(min_x, min_y, max_x, max_y)`. The
+    /// points of the shapes and the labels outside of these bounds are not painted, which is
+    /// useful when several canvases share the same space. Use `None` (the default) to paint the
+    /// whole canvas.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::{Canvas, Line};
+    ///
+    /// // only the left half of the line is painted
+    /// Canvas::default()
+    ///     .x_bounds([0.0, 10.0])
+    ///     .y_bounds([0.0, 10.0])
+    ///     .clip_bounds(Some((0.0, 0.0, 5.0, 10.0)))
+    ///     .paint(|ctx| {
+    ///         ctx.draw(&Line::new(0.0, 5.0, 10.0, 5.0, Color::Red));
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn clip_bounds(mut self, bounds: Option<Rect2D>) -> Self {
+        self.clip_bounds = bounds;
+        self
+    }
+
     /// Store the closure that will be used to draw to the [`Canvas`]
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -755,6 +992,28 @@ where
     }
 }
 
+impl<F> Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    /// Returns the bounds fitting the shapes drawn by the paint closure, including the padding
+    ///
+    /// The shapes are not painted, only their bounds are collected. An axis on which the shapes
+    /// have no extent (e.g. a single point) is widened so that the shapes can still be drawn.
+    fn fitted_bounds(&self, painter: &F) -> ([f64; 2], [f64; 2]) {
+        let mut ctx = Context::bounds_only(self.x_bounds, self.y_bounds, self.marker);
+        painter(&mut ctx);
+        let Some((min_x, min_y, max_x, max_y)) = ctx.shape_bounds else {
+            return (self.x_bounds, self.y_bounds);
+        };
+        let padding = self.auto_bounds_padding;
+        (
+            widen_degenerate([min_x - padding, max_x + padding]),
+            widen_degenerate([min_y - padding, max_y + padding]),
+        )
+    }
+}
+
 impl<F> Widget for Canvas<'_, F>
 where
     F: Fn(&mut Context),
@@ -769,6 +1028,27 @@ where
     F: Fn(&mut Context),
 {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_state(area, buf, None);
+    }
+}
+
+impl<F> StatefulWidget for &Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    type State = CanvasState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_with_state(area, buf, Some(state));
+    }
+}
+
+impl<F> Canvas<'_, F>
+where
+    F: Fn(&mut Context),
+{
+    /// Renders the canvas, painting on the grid cached in the state if there is one
+    fn render_with_state(&self, area: Rect, buf: &mut Buffer, mut state: Option<&mut CanvasState>) {
         self.block.as_ref().render(area, buf);
         let canvas_area = self.block.inner_if_some(area);
         if canvas_area.is_empty() {
@@ -783,14 +1063,20 @@ where
             return;
         };
 
+        let (x_bounds, y_bounds) = if self.auto_bounds {
+            self.fitted_bounds(painter)
+        } else {
+            (self.x_bounds, self.y_bounds)
+        };
+
         // Create a blank context that match the size of the canvas
-        let mut ctx = Context::new(
-            canvas_area.width,
-            canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
-            self.marker,
-        );
+        let (grid_width, grid_height) = (canvas_area.width, canvas_area.height);
+        let grid = state
+            .as_deref_mut()
+            .and_then(|state| state.take_grid(grid_width, grid_height, self.marker))
+            .unwrap_or_else(|| new_grid(grid_width, grid_height, self.marker));
+        let mut ctx = Context::with_grid(grid, x_bounds, y_bounds);
+        ctx.clip_bounds = self.clip_bounds;
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
@@ -815,12 +1101,12 @@ where
         }
 
         // Finally draw the labels
-        let left = self.x_bounds[0];
-        let right = self.x_bounds[1];
-        let top = self.y_bounds[1];
-        let bottom = self.y_bounds[0];
-        let width = (self.x_bounds[1] - self.x_bounds[0]).abs();
-        let height = (self.y_bounds[1] - self.y_bounds[0]).abs();
+        let left = x_bounds[0];
+        let right = x_bounds[1];
+        let top = y_bounds[1];
+        let bottom = y_bounds[0];
+        let width = (x_bounds[1] - x_bounds[0]).abs();
+        let height = (y_bounds[1] - y_bounds[0]).abs();
         let resolution = {
             let width = f64::from(canvas_area.width - 1);
             let height = f64::from(canvas_area.height - 1);
@@ -830,11 +1116,26 @@ where
             .labels
             .iter()
             .filter(|l| l.x >= left && l.x <= right && l.y <= top && l.y >= bottom)
+            .filter(|l| {
+                self.clip_bounds
+                    .map_or(true, |(min_x, min_y, max_x, max_y)| {
+                        l.x >= min_x && l.x <= max_x && l.y >= min_y && l.y <= max_y
+                    })
+            })
         {
             let x = ((label.x - left) * resolution.0 / width) as u16 + canvas_area.left();
             let y = ((top - label.y) * resolution.1 / height) as u16 + canvas_area.top();
             buf.set_line(x, y, &label.line, canvas_area.right() - x);
         }
+
+        if let Some(state) = state {
+            state.grid = Some(CachedGrid {
+                width: grid_width,
+                height: grid_height,
+                marker: self.marker,
+                grid: ctx.grid,
+            });
+        }
     }
 }
 
@@ -842,6 +1143,7 @@ where
 mod tests {
     use indoc::indoc;
     use ratatui_core::buffer::Cell;
+    use rstest::rstest;
 
     use super::*;
 
@@ -850,20 +1152,8 @@ mod tests {
     fn test_marker(marker: Marker, expected: &str) {
         let area = Rect::new(0, 0, 5, 5);
         let mut buf = Buffer::filled(area, Cell::new("x"));
-        let horizontal_line = Line {
-            x1: 0.0,
-            y1: 0.0,
-            x2: 10.0,
-            y2: 0.0,
-            color: Color::Reset,
-        };
-        let vertical_line = Line {
-            x1: 0.0,
-            y1: 0.0,
-            x2: 0.0,
-            y2: 10.0,
-            color: Color::Reset,
-        };
+        let horizontal_line = Line::new(0.0, 0.0, 10.0, 0.0, Color::Reset);
+        let vertical_line = Line::new(0.0, 0.0, 0.0, 10.0, Color::Reset);
         Canvas::default()
             .marker(marker)
             .paint(|ctx| {
@@ -921,6 +1211,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn auto_bounds_fit_points() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Canvas::default()
+            .marker(Marker::Block)
+            .auto_bounds(true)
+            .paint(|ctx| {
+                ctx.draw(&Points::new(&[(10.0, 5.0), (20.0, 15.0)], Color::Reset));
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["    █", "     ", "█    "]));
+    }
+
+    #[test]
+    fn auto_bounds_fit_lines() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Canvas::default()
+            .marker(Marker::Block)
+            .auto_bounds(true)
+            .auto_bounds_padding(25.0)
+            .paint(|ctx| {
+                ctx.draw(&Line::new(-50.0, 0.0, 50.0, 0.0, Color::Reset));
+                ctx.draw(&Line::new(0.0, -50.0, 0.0, 50.0, Color::Reset));
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["     ", "  █  ", " ███ ", "  █  ", "     "])
+        );
+    }
+
+    #[test]
+    fn auto_bounds_fit_a_single_point() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Canvas::default()
+            .marker(Marker::Block)
+            .auto_bounds(true)
+            .paint(|ctx| ctx.draw(&Points::new(&[(10.0, 5.0)], Color::Reset)))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["     ", "  █  ", "     "]));
+    }
+
+    #[test]
+    fn auto_bounds_fit_a_horizontal_line() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Canvas::default()
+            .marker(Marker::Block)
+            .auto_bounds(true)
+            .paint(|ctx| ctx.draw(&Line::new(0.0, 5.0, 10.0, 5.0, Color::Reset)))
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["     ", "█████", "     "]));
+    }
+
+    #[test]
+    fn auto_bounds_without_shape_bounds() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Canvas::default()
+            .marker(Marker::Block)
+            .auto_bounds(true)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|ctx| ctx.print(0.0, 10.0, "x"))
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["x    ", "     ", "     ", "     ", "     "])
+        );
+    }
+
     #[test]
     fn test_dot_marker() {
         test_marker(
@@ -935,4 +1294,128 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn clip_bounds_points() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 4.0])
+            .clip_bounds(Some((0.0, 0.0, 2.0, 2.0)))
+            .paint(|ctx| {
+                ctx.draw(&Points::new(
+                    &[(0.0, 0.0), (2.0, 2.0), (3.0, 1.0), (4.0, 4.0)],
+                    Color::Reset,
+                ));
+                ctx.print(0.0, 4.0, "x");
+                ctx.print(1.0, 1.0, "y");
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["     ", "     ", "  █  ", " y   ", "█    "])
+        );
+    }
+
+    #[test]
+    fn clip_bounds_lines() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 4.0])
+            .clip_bounds(Some((1.0, 1.0, 3.0, 4.0)))
+            .paint(|ctx| {
+                ctx.draw(&Line::new(0.0, 2.0, 4.0, 2.0, Color::Reset));
+                ctx.draw(&Line::new(2.0, 0.0, 2.0, 4.0, Color::Reset));
+            })
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["  █  ", "  █  ", " ███ ", "  █  ", "     "])
+        );
+    }
+
+    #[test]
+    fn clip_bounds_grid_points() {
+        struct Fill;
+
+        impl Shape for Fill {
+            fn draw(&self, painter: &mut Painter) {
+                for x in 0..5 {
+                    for y in 0..5 {
+                        painter.paint(x, y, Color::Reset);
+                    }
+                }
+            }
+        }
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 4.0])
+            .y_bounds([0.0, 4.0])
+            .clip_bounds(Some((1.0, 1.0, 3.0, 3.0)))
+            .paint(|ctx| ctx.draw(&Fill))
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["     ", " ███ ", " ███ ", " ███ ", "     "])
+        );
+    }
+
+    #[test]
+    fn clip_bounds_get_point() {
+        let mut ctx = Context::new(5, 5, [0.0, 4.0], [0.0, 4.0], Marker::Block);
+        ctx.clip_bounds = Some((1.0, 1.0, 3.0, 3.0));
+        let painter = Painter::from(&mut ctx);
+        assert_eq!(painter.get_point(2.0, 2.0), Some((2, 2)));
+        assert_eq!(painter.get_point(0.0, 2.0), None);
+        assert_eq!(painter.get_point(2.0, 4.0), None);
+    }
+
+    #[rstest]
+    #[case::braille(Marker::Braille)]
+    #[case::half_block(Marker::HalfBlock)]
+    #[case::block(Marker::Block)]
+    fn render_with_state_matches_fresh_grid(#[case] marker: Marker) {
+        let mut state = CanvasState::default();
+        for (x, color) in [(1.0, Color::Red), (3.0, Color::Blue), (2.0, Color::Green)] {
+            let canvas = Canvas::default()
+                .marker(marker)
+                .x_bounds([0.0, 4.0])
+                .y_bounds([0.0, 4.0])
+                .paint(|ctx| {
+                    ctx.draw(&Line::new(x, 0.0, x, 4.0, color));
+                    ctx.layer();
+                    ctx.draw(&Line::new(0.0, x, 4.0, x, Color::Yellow));
+                });
+            let mut fresh = Buffer::empty(Rect::new(0, 0, 5, 5));
+            Widget::render(&canvas, fresh.area, &mut fresh);
+            let mut reused = Buffer::empty(Rect::new(0, 0, 5, 5));
+            StatefulWidget::render(&canvas, reused.area, &mut reused, &mut state);
+            assert_eq!(reused, fresh);
+        }
+    }
+
+    #[test]
+    fn render_with_state_reallocates_grid_on_change() {
+        let mut state = CanvasState::default();
+        for (marker, width) in [(Marker::Braille, 5), (Marker::Block, 5), (Marker::Block, 3)] {
+            let canvas = Canvas::default()
+                .marker(marker)
+                .x_bounds([0.0, 4.0])
+                .y_bounds([0.0, 4.0])
+                .paint(|ctx| ctx.draw(&Line::new(0.0, 2.0, 4.0, 2.0, Color::Red)));
+            let area = Rect::new(0, 0, width, 5);
+            let mut fresh = Buffer::empty(area);
+            Widget::render(&canvas, area, &mut fresh);
+            let mut reused = Buffer::empty(area);
+            StatefulWidget::render(&canvas, area, &mut reused, &mut state);
+            assert_eq!(reused, fresh);
+            let cached = state.grid.as_ref().unwrap();
+            assert_eq!((cached.width, cached.marker), (width, marker));
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use ratatui_core::style::Color;
 
-use crate::canvas::{Painter, Shape};
+use crate::canvas::{Painter, Rect2D, Shape};
 
 /// A circle with a given center and radius and with a given color
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -38,6 +38,16 @@ impl Shape for Circle {
             }
         }
     }
+
+    fn bounds(&self) -> Option<Rect2D> {
+        let radius = self.radius.abs();
+        Some((
+            self.x - radius,
+            self.y - radius,
+            self.x + radius,
+            self.y + radius,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -48,7 +58,7 @@ mod tests {
     use ratatui_core::symbols::Marker;
     use ratatui_core::widgets::Widget;
 
-    use crate::canvas::{Canvas, Circle};
+    use crate::canvas::{Canvas, Circle, Shape};
 
     #[test]
     fn test_it_draws_a_circle() {
@@ -75,4 +85,10 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn bounds() {
+        let circle = Circle::new(5.0, 2.0, 3.0, Color::Red);
+        assert_eq!(circle.bounds(), Some((2.0, -1.0, 8.0, 5.0)));
+    }
 }
@@ -0,0 +1,159 @@
+use ratatui_core::style::Color;
+
+use crate::canvas::{Painter, Rect2D, Shape};
+
+/// A rectangle with its interior painted, to draw on a [`Canvas`](crate::canvas::Canvas)
+///
+/// All the points of the grid within the rectangle are painted in its color (clipped to the bounds
+/// of the canvas), which is useful for bar-style drawings. A rectangle with no width or height is
+/// drawn as a line or a point. Use [`Rectangle`](crate::canvas::Rectangle) to draw only the
+/// outline.
+///
+/// Sizes used here are **not** in terminal cell. This is much more similar to the
+/// mathematic coordinate system.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::canvas::FilledRectangle;
+///
+/// let bar = FilledRectangle::new(0.0, 0.0, 2.0, 10.0, Color::Blue);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FilledRectangle {
+    /// The `x` position of the rectangle.
+    ///
+    /// The rectangle is positioned from its bottom left corner.
+    pub x: f64,
+    /// The `y` position of the rectangle.
+    ///
+    /// The rectangle is positioned from its bottom left corner.
+    pub y: f64,
+    /// The width of the rectangle.
+    pub width: f64,
+    /// The height of the rectangle.
+    pub height: f64,
+    /// The color of the rectangle.
+    pub color: Color,
+}
+
+impl FilledRectangle {
+    /// Create a new filled rectangle with the given position, size, and color
+    pub const fn new(x: f64, y: f64, width: f64, height: f64, color: Color) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+            color,
+        }
+    }
+}
+
+impl Shape for FilledRectangle {
+    fn draw(&self, painter: &mut Painter) {
+        let Some((mut left, mut bottom, mut right, mut top)) = self.bounds() else {
+            return;
+        };
+        let ([min_x, max_x], [min_y, max_y]) = painter.bounds();
+        (left, bottom, right, top) = (
+            left.max(*min_x),
+            bottom.max(*min_y),
+            right.min(*max_x),
+            top.min(*max_y),
+        );
+        if let Some((min_x, min_y, max_x, max_y)) = painter.clip_bounds() {
+            (left, bottom, right, top) = (
+                left.max(min_x),
+                bottom.max(min_y),
+                right.min(max_x),
+                top.min(max_y),
+            );
+        }
+        let (Some((x1, y1)), Some((x2, y2))) = (
+            painter.get_point(left, top),
+            painter.get_point(right, bottom),
+        ) else {
+            return;
+        };
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                painter.paint(x, y, self.color);
+            }
+        }
+    }
+
+    fn bounds(&self) -> Option<Rect2D> {
+        let (x1, x2) = (self.x, self.x + self.width);
+        let (y1, y2) = (self.y, self.y + self.height);
+        Some((x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::layout::Rect;
+    use ratatui_core::style::{Style, Stylize};
+    use ratatui_core::symbols::Marker;
+    use ratatui_core::widgets::Widget;
+
+    use super::*;
+    use crate::canvas::Canvas;
+
+    #[test]
+    fn draw() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 5.0])
+            .y_bounds([0.0, 3.0])
+            .paint(|context| {
+                context.draw(&FilledRectangle::new(1.0, 1.0, 3.0, 2.0, Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([" ████ ", " ████ ", " ████ ", "      "]);
+        expected.set_style(Rect::new(1, 0, 4, 3), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_clipped_to_the_canvas() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 3.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| {
+                context.draw(&FilledRectangle::new(2.0, -5.0, -10.0, 20.0, Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["███ ", "███ "]);
+        expected.set_style(Rect::new(0, 0, 3, 2), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn draw_without_area() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 3));
+        let canvas = Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 2.0])
+            .y_bounds([0.0, 2.0])
+            .paint(|context| {
+                context.draw(&FilledRectangle::new(1.0, 0.0, 0.0, 2.0, Color::Red));
+                context.draw(&FilledRectangle::new(5.0, 5.0, 1.0, 1.0, Color::Red));
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([" █ ", " █ ", " █ "]);
+        expected.set_style(Rect::new(1, 0, 1, 3), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn bounds() {
+        let rectangle = FilledRectangle::new(1.0, 2.0, -3.0, -4.0, Color::Red);
+        assert_eq!(rectangle.bounds(), Some((-2.0, -2.0, 1.0, 2.0)));
+    }
+}
@@ -1,7 +1,9 @@
+use core::f64::consts::FRAC_1_SQRT_2;
+
 use line_clipping::{cohen_sutherland, LineSegment, Point, Window};
 use ratatui_core::style::Color;
 
-use crate::canvas::{Painter, Shape};
+use crate::canvas::{Painter, Rect2D, Shape};
 
 /// A line from `(x1, y1)` to `(x2, y2)` with the given color
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -14,10 +16,31 @@ pub struct Line {
     pub x2: f64,
     /// `y` of the ending point
     pub y2: f64,
-    /// Color of the line
+    /// Color of the line, or of its starting point if `end_color` is set
     pub color: Color,
+    /// Color of the ending point of the line, see [`Line::end_color`]
+    end_color: Option<Color>,
+    /// Dash pattern of the line, see [`Line::dash`]
+    dash: Option<(f64, f64)>,
+    /// Endpoints of the line which are drawn with an arrowhead, see [`Line::arrow`]
+    arrow: ArrowEnd,
+}
+
+/// Endpoints of a [`Line`] which are drawn with an arrowhead
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ArrowEnd {
+    /// No arrowhead is drawn
+    #[default]
+    None,
+    /// An arrowhead is drawn at the ending point `(x2, y2)`
+    End,
+    /// An arrowhead is drawn at both the starting and the ending points
+    Both,
 }
 
+/// Length of the strokes of an arrowhead, in points of the grid
+const ARROW_LENGTH: f64 = 3.0;
+
 impl Line {
     /// Create a new line from `(x1, y1)` to `(x2, y2)` with the given color
     pub const fn new(x1: f64, y1: f64, x2: f64, y2: f64, color: Color) -> Self {
@@ -27,56 +50,255 @@ impl Line {
             x2,
             y2,
             color,
+            end_color: None,
+            dash: None,
+            arrow: ArrowEnd::None,
         }
     }
-}
 
-impl Shape for Line {
-    #[expect(clippy::similar_names)]
-    fn draw(&self, painter: &mut Painter) {
-        let (x_bounds, y_bounds) = painter.bounds();
-        let Some((world_x1, world_y1, world_x2, world_y2)) =
-            clip_line(x_bounds, y_bounds, self.x1, self.y1, self.x2, self.y2)
-        else {
+    /// Sets the dash pattern of the line
+    ///
+    /// The line is painted for `on` and skipped for `off`, repeatedly from its starting point. Both
+    /// lengths are measured along the line in canvas coordinates. The line is drawn solid if `on`
+    /// is not positive or `off` is negative.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::Line;
+    ///
+    /// let line = Line::new(0.0, 0.0, 10.0, 0.0, Color::White).dash(2.0, 1.0);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn dash(mut self, on: f64, off: f64) -> Self {
+        self.dash = Some((on, off));
+        self
+    }
+
+    /// Sets the endpoints of the line which are drawn with an arrowhead
+    ///
+    /// The arrowhead is made of two strokes going back from the endpoint at a 45° angle. Its size
+    /// is a few points of the grid, so that it looks the same whatever the bounds of the canvas.
+    /// The arrowhead is always solid, even if the line is dashed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::{ArrowEnd, Line};
+    ///
+    /// let line = Line::new(0.0, 0.0, 10.0, 0.0, Color::White).arrow(ArrowEnd::End);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn arrow(mut self, arrow: ArrowEnd) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Sets the color of the ending point of the line, drawing it as a gradient
+    ///
+    /// Each painted point is colored by blending `color` with `end_color` (see [`Color::blend`])
+    /// according to its position along the line. Arrowheads have the color of their endpoint.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use ratatui::widgets::canvas::Line;
+    ///
+    /// let line = Line::new(0.0, 0.0, 10.0, 0.0, Color::Red).end_color(Color::Blue);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn end_color(mut self, end_color: Color) -> Self {
+        self.end_color = Some(end_color);
+        self
+    }
+
+    /// Returns the color at the given ratio of the length of the line, from its starting point
+    fn color_at(&self, ratio: f64) -> Color {
+        match self.end_color {
+            #[expect(clippy::cast_possible_truncation)]
+            Some(end_color) => self.color.blend(end_color, ratio as f32),
+            None => self.color,
+        }
+    }
+
+    /// Returns the point at the given distance from the start of the line
+    fn point_at(&self, distance: f64, length: f64) -> (f64, f64) {
+        let ratio = distance / length;
+        (
+            (self.x2 - self.x1).mul_add(ratio, self.x1),
+            (self.y2 - self.y1).mul_add(ratio, self.y1),
+        )
+    }
+
+    /// Draws the line as dashes of length `on` separated by gaps of length `off`
+    fn draw_dashed(&self, painter: &mut Painter, on: f64, off: f64) {
+        let length = (self.x2 - self.x1).hypot(self.y2 - self.y1);
+        if length == 0.0 {
+            draw_segment(painter, self.x1, self.y1, self.x2, self.y2, |_| self.color);
             return;
-        };
-        let Some((x1, y1)) = painter.get_point(world_x1, world_y1) else {
+        }
+        let mut start = 0.0;
+        while start <= length {
+            let end = (start + on).min(length);
+            let (x1, y1) = self.point_at(start, length);
+            let (x2, y2) = self.point_at(end, length);
+            let color = |ratio: f64| self.color_at((end - start).mul_add(ratio, start) / length);
+            draw_segment(painter, x1, y1, x2, y2, color);
+            start += on + off;
+        }
+    }
+
+    /// Draws an arrowhead at `to`, pointing away from `from`, with the given color
+    fn draw_arrowhead(painter: &mut Painter, from: (f64, f64), to: (f64, f64), color: Color) {
+        // work in grid units so that the arrowhead is not distorted by the bounds of the canvas
+        let ([left, right], [bottom, top]) = painter.bounds();
+        let unit_x = (right - left) / (painter.resolution.0 - 1.0).max(1.0);
+        let unit_y = (top - bottom) / (painter.resolution.1 - 1.0).max(1.0);
+        if unit_x <= 0.0 || unit_y <= 0.0 {
             return;
-        };
-        let Some((x2, y2)) = painter.get_point(world_x2, world_y2) else {
+        }
+        let back_x = (from.0 - to.0) / unit_x;
+        let back_y = (from.1 - to.1) / unit_y;
+        let length = back_x.hypot(back_y);
+        if length == 0.0 {
             return;
-        };
+        }
+        let scale = ARROW_LENGTH * FRAC_1_SQRT_2 / length;
+        for (dx, dy) in [
+            (back_x - back_y, back_x + back_y),
+            (back_x + back_y, back_y - back_x),
+        ] {
+            let x = (dx * scale).mul_add(unit_x, to.0);
+            let y = (dy * scale).mul_add(unit_y, to.1);
+            draw_segment(painter, to.0, to.1, x, y, |_| color);
+        }
+    }
+}
 
-        let (dx, x_range) = if x2 >= x1 {
-            (x2 - x1, x1..=x2)
+impl Shape for Line {
+    fn draw(&self, painter: &mut Painter) {
+        match self.dash {
+            Some((on, off)) if on > 0.0 && off >= 0.0 => self.draw_dashed(painter, on, off),
+            _ => draw_segment(painter, self.x1, self.y1, self.x2, self.y2, |ratio| {
+                self.color_at(ratio)
+            }),
+        }
+        let (start, end) = ((self.x1, self.y1), (self.x2, self.y2));
+        match self.arrow {
+            ArrowEnd::None => {}
+            ArrowEnd::End => Self::draw_arrowhead(painter, start, end, self.color_at(1.0)),
+            ArrowEnd::Both => {
+                Self::draw_arrowhead(painter, start, end, self.color_at(1.0));
+                Self::draw_arrowhead(painter, end, start, self.color_at(0.0));
+            }
+        }
+    }
+
+    fn bounds(&self) -> Option<Rect2D> {
+        Some((
+            self.x1.min(self.x2),
+            self.y1.min(self.y2),
+            self.x1.max(self.x2),
+            self.y1.max(self.y2),
+        ))
+    }
+}
+
+/// Draws a solid line from `(x1, y1)` to `(x2, y2)`, clipped to the bounds and the clip bounds of
+/// the painter
+///
+/// Each point is painted with the color returned by `color` for the ratio of the length of the
+/// line at which the point is, from `0.0` at `(x1, y1)` to `1.0` at `(x2, y2)`.
+#[expect(clippy::similar_names)]
+fn draw_segment(
+    painter: &mut Painter,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    color: impl Fn(f64) -> Color,
+) {
+    let (&[mut left, mut right], &[mut bottom, mut top]) = painter.bounds();
+    if let Some((min_x, min_y, max_x, max_y)) = painter.clip_bounds() {
+        left = left.max(min_x);
+        right = right.min(max_x);
+        bottom = bottom.max(min_y);
+        top = top.min(max_y);
+    }
+    if left > right || bottom > top {
+        return;
+    }
+    let Some((world_x1, world_y1, world_x2, world_y2)) =
+        clip_line(&[left, right], &[bottom, top], x1, y1, x2, y2)
+    else {
+        return;
+    };
+    // the ratios of the length of the line at which the clipped line starts and ends
+    let length_squared = (x2 - x1).mul_add(x2 - x1, (y2 - y1) * (y2 - y1));
+    let ratio_of = |x: f64, y: f64| {
+        if length_squared == 0.0 {
+            0.0
         } else {
-            (x1 - x2, x2..=x1)
-        };
-        let (dy, y_range) = if y2 >= y1 {
-            (y2 - y1, y1..=y2)
+            (x - x1).mul_add(x2 - x1, (y - y1) * (y2 - y1)) / length_squared
+        }
+    };
+    let (start_ratio, end_ratio) = (ratio_of(world_x1, world_y1), ratio_of(world_x2, world_y2));
+    let Some((x1, y1)) = painter.get_point(world_x1, world_y1) else {
+        return;
+    };
+    let Some((x2, y2)) = painter.get_point(world_x2, world_y2) else {
+        return;
+    };
+
+    let (dx, x_range) = if x2 >= x1 {
+        (x2 - x1, x1..=x2)
+    } else {
+        (x1 - x2, x2..=x1)
+    };
+    let (dy, y_range) = if y2 >= y1 {
+        (y2 - y1, y1..=y2)
+    } else {
+        (y1 - y2, y2..=y1)
+    };
+
+    // the points are interpolated along the longest axis of the line
+    let color = |x: usize, y: usize| {
+        let (from, to, at) = if dy < dx { (x1, x2, x) } else { (y1, y2, y) };
+        let ratio = if from == to {
+            0.0
         } else {
-            (y1 - y2, y2..=y1)
+            (at as f64 - from as f64) / (to as f64 - from as f64)
         };
+        color((end_ratio - start_ratio).mul_add(ratio, start_ratio))
+    };
 
-        if dx == 0 {
-            for y in y_range {
-                painter.paint(x1, y, self.color);
-            }
-        } else if dy == 0 {
-            for x in x_range {
-                painter.paint(x, y1, self.color);
-            }
-        } else if dy < dx {
-            if x1 > x2 {
-                draw_line_low(painter, x2, y2, x1, y1, self.color);
-            } else {
-                draw_line_low(painter, x1, y1, x2, y2, self.color);
-            }
-        } else if y1 > y2 {
-            draw_line_high(painter, x2, y2, x1, y1, self.color);
+    if dx == 0 {
+        for y in y_range {
+            painter.paint(x1, y, color(x1, y));
+        }
+    } else if dy == 0 {
+        for x in x_range {
+            painter.paint(x, y1, color(x, y1));
+        }
+    } else if dy < dx {
+        if x1 > x2 {
+            draw_line_low(painter, x2, y2, x1, y1, color);
         } else {
-            draw_line_high(painter, x1, y1, x2, y2, self.color);
+            draw_line_low(painter, x1, y1, x2, y2, color);
         }
+    } else if y1 > y2 {
+        draw_line_high(painter, x2, y2, x1, y1, color);
+    } else {
+        draw_line_high(painter, x1, y1, x2, y2, color);
     }
 }
 
@@ -101,13 +323,20 @@ fn clip_line(
     }
 }
 
-fn draw_line_low(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usize, color: Color) {
+fn draw_line_low(
+    painter: &mut Painter,
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+    color: impl Fn(usize, usize) -> Color,
+) {
     let dx = (x2 - x1) as isize;
     let dy = (y2 as isize - y1 as isize).abs();
     let mut d = 2 * dy - dx;
     let mut y = y1;
     for x in x1..=x2 {
-        painter.paint(x, y, color);
+        painter.paint(x, y, color(x, y));
         if d > 0 {
             y = if y1 > y2 {
                 y.saturating_sub(1)
@@ -120,13 +349,20 @@ fn draw_line_low(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usi
     }
 }
 
-fn draw_line_high(painter: &mut Painter, x1: usize, y1: usize, x2: usize, y2: usize, color: Color) {
+fn draw_line_high(
+    painter: &mut Painter,
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+    color: impl Fn(usize, usize) -> Color,
+) {
     let dx = (x2 as isize - x1 as isize).abs();
     let dy = (y2 - y1) as isize;
     let mut d = 2 * dx - dy;
     let mut x = x1;
     for y in y1..=y2 {
-        painter.paint(x, y, color);
+        painter.paint(x, y, color(x, y));
         if d > 0 {
             x = if x1 > x2 {
                 x.saturating_sub(1)
@@ -303,4 +539,164 @@ mod tests {
         }
         assert_eq!(buffer, expected);
     }
+
+    #[rstest]
+    #[case::ascending(Line::new(1.0, 2.0, 3.0, 4.0, Color::Red), (1.0, 2.0, 3.0, 4.0))]
+    #[case::descending(Line::new(3.0, 4.0, -1.0, -2.0, Color::Red), (-1.0, -2.0, 3.0, 4.0))]
+    #[case::vertical(Line::new(1.0, 4.0, 1.0, 0.0, Color::Red), (1.0, 0.0, 1.0, 4.0))]
+    fn bounds(#[case] line: Line, #[case] expected: Rect2D) {
+        assert_eq!(line.bounds(), Some(expected));
+    }
+
+    #[rstest]
+    #[case::solid(Line::new(0.0, 0.0, 9.0, 0.0, Color::Red), "••••••••••")]
+    #[case::dashed(Line::new(0.0, 0.0, 9.0, 0.0, Color::Red).dash(2.0, 2.0), "••• ••• ••")]
+    #[case::dotted(Line::new(0.0, 0.0, 9.0, 0.0, Color::Red).dash(0.25, 2.75), "•  •  •  •")]
+    #[case::reversed(Line::new(9.0, 0.0, 0.0, 0.0, Color::Red).dash(2.0, 3.0), "  •••  •••")]
+    #[case::without_gaps(Line::new(0.0, 0.0, 9.0, 0.0, Color::Red).dash(1.0, 0.0), "••••••••••")]
+    #[case::invalid(Line::new(0.0, 0.0, 9.0, 0.0, Color::Red).dash(0.0, 2.0), "••••••••••")]
+    fn dash(#[case] line: Line, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines([expected]);
+        for cell in &mut expected.content {
+            if cell.symbol() == "•" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::none(ArrowEnd::None, [
+        "          ",
+        "          ",
+        "          ",
+        " ████████ ",
+        "          ",
+        "          ",
+        "          ",
+    ])]
+    #[case::end(ArrowEnd::End, [
+        "          ",
+        "      █   ",
+        "       █  ",
+        " ████████ ",
+        "       █  ",
+        "      █   ",
+        "          ",
+    ])]
+    #[case::both(ArrowEnd::Both, [
+        "          ",
+        "   █  █   ",
+        "  █    █  ",
+        " ████████ ",
+        "  █    █  ",
+        "   █  █   ",
+        "          ",
+    ])]
+    fn arrowhead<'expected_line, ExpectedLines>(
+        #[case] arrow: ArrowEnd,
+        #[case] expected: ExpectedLines,
+    ) where
+        ExpectedLines: IntoIterator,
+        ExpectedLines::Item: Into<ratatui_core::text::Line<'expected_line>>,
+    {
+        let line = Line::new(1.0, 3.0, 8.0, 3.0, Color::Red).arrow(arrow);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 7));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 6.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(expected);
+        for cell in &mut expected.content {
+            if cell.symbol() == "█" {
+                cell.set_style(Style::new().red());
+            }
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn dash_diagonal() {
+        let line = Line::new(0.0, 0.0, 9.0, 9.0, Color::Red).dash(2.0, 2.0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 10));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 9.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+        let painted = buffer
+            .content
+            .iter()
+            .filter(|cell| cell.symbol() == "•")
+            .count();
+        // the diagonal is about 12.7 long, so 4 dashes of at most 2 cells each are drawn
+        assert!(painted < 10, "{painted} cells painted");
+        assert!(painted > 4, "{painted} cells painted");
+    }
+
+    #[rstest]
+    #[case::forward(Line::new(0.0, 0.0, 10.0, 0.0, Color::Rgb(0, 0, 0)))]
+    #[case::backward(Line::new(10.0, 0.0, 0.0, 0.0, Color::Rgb(0, 0, 0)))]
+    fn gradient(#[case] line: Line) {
+        let end_color = Color::Rgb(200, 100, 0);
+        let line = line.end_color(end_color);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+
+        let start = if line.x1 == 0.0 { 0 } else { 10 };
+        assert_eq!(buffer[(start, 0)].fg, line.color);
+        assert_eq!(buffer[(10 - start, 0)].fg, end_color);
+        assert_eq!(buffer[(5, 0)].fg, line.color.blend(end_color, 0.5));
+    }
+
+    #[test]
+    fn gradient_clipped() {
+        // the visible part of the line starts at its middle
+        let line = Line::new(-10.0, 0.0, 10.0, 0.0, Color::Rgb(0, 0, 0)).end_color(Color::White);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, line.color.blend(Color::White, 0.5));
+        assert_eq!(buffer[(10, 0)].fg, Color::White);
+    }
+
+    #[test]
+    fn gradient_dashed() {
+        let line = Line::new(0.0, 0.0, 10.0, 0.0, Color::Rgb(0, 0, 0))
+            .end_color(Color::White)
+            .dash(1.0, 1.0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 1));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 1.0])
+            .paint(|context| context.draw(&line))
+            .render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, line.color);
+        assert_eq!(buffer[(4, 0)].fg, line.color.blend(Color::White, 0.4));
+        assert_eq!(buffer[(10, 0)].fg, Color::White);
+    }
 }
@@ -2,7 +2,7 @@ use ratatui_core::style::Color;
 use strum::{Display, EnumString};
 
 use crate::canvas::world::{WORLD_HIGH_RESOLUTION, WORLD_LOW_RESOLUTION};
-use crate::canvas::{Painter, Shape};
+use crate::canvas::{Line, Painter, Rect2D, Shape};
 
 /// Defines how many points are going to be used to draw a [`Map`].
 ///
@@ -31,9 +31,64 @@ impl MapResolution {
     }
 }
 
+/// Defines how the `(longitude, latitude)` coordinates of a [`Map`] are placed on the canvas.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MapProjection {
+    /// The longitude and latitude are used as `x` and `y` coordinates as is.
+    ///
+    /// The whole world is visible with `x` bounds of `[-180.0, 180.0]` and `y` bounds of
+    /// `[-90.0, 90.0]`.
+    #[default]
+    Equirectangular,
+    /// The [Mercator projection](https://en.wikipedia.org/wiki/Mercator_projection).
+    ///
+    /// The longitude is used as the `x` coordinate, and the latitude is stretched away from the
+    /// equator, expressed in degrees for the `y` coordinate. Latitudes beyond
+    /// [`MapProjection::MERCATOR_MAX_LATITUDE`] are clamped, so the whole world is visible with
+    /// bounds of `[-180.0, 180.0]` on both axes.
+    Mercator,
+}
+
+impl MapProjection {
+    /// The latitude (in degrees) at which [`MapProjection::Mercator`] clamps the coordinates.
+    ///
+    /// This is the limit used by the Web Mercator projection, at which the projected world is
+    /// square.
+    pub const MERCATOR_MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+    /// Converts `(longitude, latitude)` coordinates to canvas coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::canvas::MapProjection;
+    ///
+    /// assert_eq!(
+    ///     MapProjection::Equirectangular.project(10.0, 45.0),
+    ///     (10.0, 45.0)
+    /// );
+    /// let (x, y) = MapProjection::Mercator.project(10.0, 45.0);
+    /// assert_eq!(x, 10.0);
+    /// assert!((y - 50.498).abs() < 0.001);
+    /// ```
+    pub fn project(self, longitude: f64, latitude: f64) -> (f64, f64) {
+        match self {
+            Self::Equirectangular => (longitude, latitude),
+            Self::Mercator => {
+                let latitude = latitude
+                    .clamp(-Self::MERCATOR_MAX_LATITUDE, Self::MERCATOR_MAX_LATITUDE)
+                    .to_radians();
+                let y = (core::f64::consts::FRAC_PI_4 + latitude / 2.0).tan().ln();
+                (longitude, y.to_degrees())
+            }
+        }
+    }
+}
+
 /// A world map
 ///
-/// A world map can be rendered with different [resolutions](MapResolution) and [colors](Color).
+/// A world map can be rendered with different [resolutions](MapResolution), [colors](Color) and
+/// [projections](MapProjection).
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Map {
     /// The resolution of the map.
@@ -44,26 +99,111 @@ pub struct Map {
     ///
     /// This is the color of the points of the map.
     pub color: Color,
+    /// Map projection
+    ///
+    /// This is how the coordinates of the map are placed on the canvas.
+    pub projection: MapProjection,
 }
 
 impl Shape for Map {
     fn draw(&self, painter: &mut Painter) {
-        for (x, y) in self.resolution.data() {
-            if let Some((x, y)) = painter.get_point(*x, *y) {
+        for &(longitude, latitude) in self.resolution.data() {
+            let (x, y) = self.projection.project(longitude, latitude);
+            if let Some((x, y)) = painter.get_point(x, y) {
                 painter.paint(x, y, self.color);
             }
         }
     }
 }
 
+/// Polylines drawn from custom map data
+///
+/// Each polyline is a sequence of `(longitude, latitude)` pairs, drawn like the outlines of the
+/// [`Map`] shape (with the same [projections](MapProjection)) but joined by lines. This is useful
+/// to draw the outline of regions (e.g. a country or a custom area) from your own coordinate data.
+/// Close a polyline by repeating its first point at the end to draw a polygon.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::Color;
+/// use ratatui::widgets::canvas::{Canvas, MapPolylines};
+///
+/// let region: &[(f64, f64)] = &[(-10.0, 35.0), (30.0, 35.0), (30.0, 60.0), (-10.0, 35.0)];
+/// Canvas::default()
+///     .x_bounds([-180.0, 180.0])
+///     .y_bounds([-90.0, 90.0])
+///     .paint(|ctx| {
+///         ctx.draw(&MapPolylines::new(&[region], Color::Yellow));
+///     });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MapPolylines<'a> {
+    /// The polylines to draw, as lists of `(longitude, latitude)` pairs
+    pub polylines: &'a [&'a [(f64, f64)]],
+    /// Color of the polylines
+    pub color: Color,
+    /// Projection of the coordinates of the polylines
+    pub projection: MapProjection,
+}
+
+impl<'a> MapPolylines<'a> {
+    /// Create a new `MapPolylines` shape with the given polylines and color
+    ///
+    /// The polylines use the [`MapProjection::Equirectangular`] projection, which can be changed
+    /// with [`MapPolylines::projection`].
+    pub const fn new(polylines: &'a [&'a [(f64, f64)]], color: Color) -> Self {
+        Self {
+            polylines,
+            color,
+            projection: MapProjection::Equirectangular,
+        }
+    }
+
+    /// Set the projection of the coordinates of the polylines
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn projection(mut self, projection: MapProjection) -> Self {
+        self.projection = projection;
+        self
+    }
+}
+
+impl Shape for MapPolylines<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        for polyline in self.polylines {
+            for segment in polyline.windows(2) {
+                let (x1, y1) = self.projection.project(segment[0].0, segment[0].1);
+                let (x2, y2) = self.projection.project(segment[1].0, segment[1].1);
+                Line::new(x1, y1, x2, y2, self.color).draw(painter);
+            }
+        }
+    }
+
+    fn bounds(&self) -> Option<Rect2D> {
+        self.polylines
+            .iter()
+            .flat_map(|polyline| polyline.iter())
+            .map(|&(longitude, latitude)| self.projection.project(longitude, latitude))
+            .fold(None, |bounds, (x, y)| {
+                Some(bounds.map_or((x, y, x, y), |(min_x, min_y, max_x, max_y)| {
+                    (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+                }))
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::string::ToString;
+    use alloc::vec;
 
     use ratatui_core::buffer::Buffer;
     use ratatui_core::layout::Rect;
     use ratatui_core::symbols::Marker;
     use ratatui_core::widgets::Widget;
+    use rstest::rstest;
     use strum::ParseError;
 
     use super::*;
@@ -90,6 +230,7 @@ mod tests {
         let map = Map::default();
         assert_eq!(map.resolution, MapResolution::Low);
         assert_eq!(map.color, Color::Reset);
+        assert_eq!(map.projection, MapProjection::Equirectangular);
     }
 
     #[test]
@@ -206,4 +347,90 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn draw_polylines() {
+        let polygon: &[(f64, f64)] = &[
+            (-90.0, -45.0),
+            (90.0, -45.0),
+            (90.0, 45.0),
+            (-90.0, 45.0),
+            (-90.0, -45.0),
+        ];
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([-180.0, 180.0])
+            .y_bounds([-90.0, 90.0])
+            .paint(|context| {
+                context.draw(&MapPolylines::new(&[polygon], Color::Reset));
+            })
+            .render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "         ",
+            "  •••••  ",
+            "  •   •  ",
+            "  •••••  ",
+            "         ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn polylines_bounds() {
+        let first: &[(f64, f64)] = &[(-10.0, 20.0), (30.0, 40.0)];
+        let second: &[(f64, f64)] = &[(5.0, -15.0)];
+        assert_eq!(
+            MapPolylines::new(&[first, second], Color::Red).bounds(),
+            Some((-10.0, -15.0, 30.0, 40.0))
+        );
+        assert_eq!(MapPolylines::new(&[&[]], Color::Red).bounds(), None);
+    }
+
+    #[rstest]
+    #[case::equirectangular_equator(MapProjection::Equirectangular, (10.0, 0.0), (10.0, 0.0))]
+    #[case::equirectangular(MapProjection::Equirectangular, (-30.0, 60.0), (-30.0, 60.0))]
+    #[case::mercator_equator(MapProjection::Mercator, (10.0, 0.0), (10.0, 0.0))]
+    #[case::mercator_north(MapProjection::Mercator, (-30.0, 60.0), (-30.0, 75.456))]
+    #[case::mercator_south(MapProjection::Mercator, (-30.0, -60.0), (-30.0, -75.456))]
+    #[case::mercator_max_latitude(MapProjection::Mercator, (0.0, MapProjection::MERCATOR_MAX_LATITUDE), (0.0, 180.0))]
+    #[case::mercator_north_pole(MapProjection::Mercator, (0.0, 90.0), (0.0, 180.0))]
+    #[case::mercator_south_pole(MapProjection::Mercator, (0.0, -90.0), (0.0, -180.0))]
+    fn project(
+        #[case] projection: MapProjection,
+        #[case] (longitude, latitude): (f64, f64),
+        #[case] expected: (f64, f64),
+    ) {
+        let (x, y) = projection.project(longitude, latitude);
+        assert!((x - expected.0).abs() < 0.001, "{x} != {}", expected.0);
+        assert!((y - expected.1).abs() < 0.001, "{y} != {}", expected.1);
+    }
+
+    #[rstest]
+    #[case::equirectangular(MapProjection::Equirectangular, 2)]
+    #[case::mercator(MapProjection::Mercator, 1)]
+    fn draw_projected_point(#[case] projection: MapProjection, #[case] row: usize) {
+        let point: &[(f64, f64)] = &[(0.0, 60.0), (0.0, 60.0)];
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 13));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([-1.0, 1.0])
+            .y_bounds([-90.0, 90.0])
+            .paint(|context| {
+                context.draw(&MapPolylines::new(&[point], Color::Reset).projection(projection));
+            })
+            .render(buffer.area, &mut buffer);
+        let mut expected = vec!["   "; 13];
+        expected[row] = " █ ";
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn map_projection_from_str() {
+        assert_eq!("Mercator".parse(), Ok(MapProjection::Mercator));
+        assert_eq!(
+            MapProjection::Equirectangular.to_string(),
+            "Equirectangular"
+        );
+    }
 }
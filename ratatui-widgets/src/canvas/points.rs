@@ -1,6 +1,6 @@
 use ratatui_core::style::Color;
 
-use crate::canvas::{Painter, Shape};
+use crate::canvas::{Painter, Rect2D, Shape};
 
 /// A group of points with a given color
 #[derive(Debug, Default, Clone, PartialEq)]
@@ -26,4 +26,34 @@ impl Shape for Points<'_> {
             }
         }
     }
+
+    fn bounds(&self) -> Option<Rect2D> {
+        let (&(x, y), coords) = self.coords.split_first()?;
+        Some(
+            coords
+                .iter()
+                .fold((x, y, x, y), |(min_x, min_y, max_x, max_y), &(x, y)| {
+                    (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let coords = [(1.0, 5.0), (-2.0, 3.0), (4.0, -1.0)];
+        assert_eq!(
+            Points::new(&coords, Color::Red).bounds(),
+            Some((-2.0, -1.0, 4.0, 5.0))
+        );
+        assert_eq!(
+            Points::new(&[(1.0, 2.0)], Color::Red).bounds(),
+            Some((1.0, 2.0, 1.0, 2.0))
+        );
+        assert_eq!(Points::new(&[], Color::Red).bounds(), None);
+    }
 }
@@ -1,6 +1,6 @@
 use ratatui_core::style::Color;
 
-use crate::canvas::{Line, Painter, Shape};
+use crate::canvas::{Line, Painter, Rect2D, Shape};
 
 /// A rectangle to draw on a [`Canvas`](crate::canvas::Canvas)
 ///
@@ -40,39 +40,33 @@ impl Rectangle {
 impl Shape for Rectangle {
     fn draw(&self, painter: &mut Painter) {
         let lines: [Line; 4] = [
-            Line {
-                x1: self.x,
-                y1: self.y,
-                x2: self.x,
-                y2: self.y + self.height,
-                color: self.color,
-            },
-            Line {
-                x1: self.x,
-                y1: self.y + self.height,
-                x2: self.x + self.width,
-                y2: self.y + self.height,
-                color: self.color,
-            },
-            Line {
-                x1: self.x + self.width,
-                y1: self.y,
-                x2: self.x + self.width,
-                y2: self.y + self.height,
-                color: self.color,
-            },
-            Line {
-                x1: self.x,
-                y1: self.y,
-                x2: self.x + self.width,
-                y2: self.y,
-                color: self.color,
-            },
+            Line::new(self.x, self.y, self.x, self.y + self.height, self.color),
+            Line::new(
+                self.x,
+                self.y + self.height,
+                self.x + self.width,
+                self.y + self.height,
+                self.color,
+            ),
+            Line::new(
+                self.x + self.width,
+                self.y,
+                self.x + self.width,
+                self.y + self.height,
+                self.color,
+            ),
+            Line::new(self.x, self.y, self.x + self.width, self.y, self.color),
         ];
         for line in &lines {
             line.draw(painter);
         }
     }
+
+    fn bounds(&self) -> Option<Rect2D> {
+        let (x1, x2) = (self.x, self.x + self.width);
+        let (y1, y2) = (self.y, self.y + self.height);
+        Some((x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)))
+    }
 }
 
 #[cfg(test)]
@@ -199,4 +193,13 @@ mod tests {
         expected.set_style(buffer.area.inner(Margin::new(3, 3)), Style::reset());
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn bounds() {
+        let rectangle = Rectangle::new(1.0, 2.0, 3.0, 4.0, Color::Red);
+        assert_eq!(rectangle.bounds(), Some((1.0, 2.0, 4.0, 6.0)));
+
+        let rectangle = Rectangle::new(1.0, 2.0, -3.0, -4.0, Color::Red);
+        assert_eq!(rectangle.bounds(), Some((-2.0, -2.0, 1.0, 2.0)));
+    }
 }
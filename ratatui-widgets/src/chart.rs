@@ -1,6 +1,9 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
+use alloc::format;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::cmp::max;
+use core::fmt;
 use core::ops::Not;
 
 use ratatui_core::buffer::Buffer;
@@ -20,9 +23,10 @@ use crate::canvas::{Canvas, Line as CanvasLine, Points};
 /// X axis this is the right, for a Y axis, this is the top.
 ///
 /// You can also set the bounds and labels on this axis using respectively [`Axis::bounds`] and
-/// [`Axis::labels`].
+/// [`Axis::labels`]. The labels can also be generated from the values at the ticks of the axis
+/// with [`Axis::labels_from`].
 ///
-/// See [`Chart::x_axis`] and [`Chart::y_axis`] to set an axis on a chart.
+/// See [`Chart::x_axis`], [`Chart::y_axis`] and [`Chart::y_axis_right`] to set an axis on a chart.
 ///
 /// # Example
 ///
@@ -48,6 +52,30 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// The callback generating the labels from the values at the ticks of the axis
+    labels_from: Option<AxisLabelFormatter<'a>>,
+    /// The number of labels generated by `labels_from`
+    tick_count: Option<usize>,
+}
+
+/// The callback of [`Axis::labels_from`]
+///
+/// The callback is reference counted so that the [`Axis`] can still be cloned, and two callbacks
+/// are considered equal only when they point to the same function (e.g. when one is a clone of the
+/// other), not when two separate closures behave the same.
+#[derive(Clone)]
+struct AxisLabelFormatter<'a>(Arc<dyn Fn(f64) -> Line<'static> + Send + Sync + 'a>);
+
+impl fmt::Debug for AxisLabelFormatter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("AxisLabelFormatter")
+    }
+}
+
+impl PartialEq for AxisLabelFormatter<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl<'a> Axis<'a> {
@@ -151,6 +179,71 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Generates the axis labels from the values at the ticks of the axis
+    ///
+    /// The callback is called with the value at each of the [`Axis::tick_count`] ticks, evenly
+    /// spread between the [bounds](Axis::bounds) of the axis (both included), and returns the
+    /// label of the tick as anything that can be converted into an owned [`Line`] (e.g. a `String`
+    /// or a `Span<'static>`). This is useful to format the values yourself, e.g. to display Unix
+    /// timestamps as human-readable times. The generated labels replace those set with
+    /// [`Axis::labels`].
+    ///
+    /// The labels are generated when the axis is set on the [`Chart`] (e.g. with
+    /// [`Chart::x_axis`]), so the bounds and the tick count of the axis must be set before. Two
+    /// axes with callbacks are only equal if they share the same callback, e.g. when one is a
+    /// clone of the other.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart};
+    ///
+    /// let x_axis = Axis::default()
+    ///     .bounds([0.0, 3600.0])
+    ///     .tick_count(5)
+    ///     .labels_from(|seconds| format!("{}min", seconds / 60.0));
+    /// let chart = Chart::new(vec![]).x_axis(x_axis);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn labels_from<F, L>(mut self, labels_from: F) -> Self
+    where
+        F: Fn(f64) -> L + Send + Sync + 'a,
+        L: Into<Line<'static>>,
+    {
+        self.labels_from = Some(AxisLabelFormatter(Arc::new(move |value| {
+            labels_from(value).into()
+        })));
+        self
+    }
+
+    /// Sets the number of labels generated by [`Axis::labels_from`]
+    ///
+    /// The first and last ticks are at the bounds of the axis. The default is 3, and values lower
+    /// than 2 are treated as 2. This has no effect when the labels are set with [`Axis::labels`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tick_count(mut self, count: usize) -> Self {
+        self.tick_count = Some(count);
+        self
+    }
+
+    /// Replaces the labels by the ones generated by [`Axis::labels_from`], if set
+    fn with_generated_labels(mut self) -> Self {
+        let Some(labels_from) = &self.labels_from else {
+            return self;
+        };
+        let count = self.tick_count.unwrap_or(3).max(2);
+        let [min, max] = self.bounds;
+        let step = (max - min) / (count - 1) as f64;
+        self.labels = (0..count)
+            .map(|i| (labels_from.0)((i as f64).mul_add(step, min)))
+            .collect();
+        self
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -288,6 +381,18 @@ impl LegendPosition {
     }
 }
 
+/// The vertical axis of a [`Chart`] a [`Dataset`] is plotted against
+///
+/// See [`Dataset::y_axis_position`] and [`Chart::y_axis_right`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum YAxisPosition {
+    /// The Y axis on the left of the chart, set with [`Chart::y_axis`]. This is the **default**.
+    #[default]
+    Left,
+    /// The Y axis on the right of the chart, set with [`Chart::y_axis_right`]
+    Right,
+}
+
 /// A group of data points
 ///
 /// This is the main element composing a [`Chart`].
@@ -328,6 +433,8 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// The Y axis whose bounds are used to plot this dataset
+    y_axis_position: YAxisPosition,
 }
 
 impl<'a> Dataset<'a> {
@@ -395,6 +502,31 @@ impl<'a> Dataset<'a> {
         self
     }
 
+    /// Sets the Y axis this dataset is plotted against
+    ///
+    /// The Y coordinates of the data points are projected using the bounds of this axis. Datasets
+    /// bound to [`YAxisPosition::Right`] use the bounds of [`Chart::y_axis_right`], or those of
+    /// [`Chart::y_axis`] if the chart has no right axis.
+    ///
+    /// The default is [`YAxisPosition::Left`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Dataset, YAxisPosition};
+    ///
+    /// let volume = Dataset::default()
+    ///     .name("volume")
+    ///     .y_axis_position(YAxisPosition::Right);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn y_axis_position(mut self, position: YAxisPosition) -> Self {
+        self.y_axis_position = position;
+        self
+    }
+
     /// Sets the style of this dataset
     ///
     /// The given style will be used to draw the legend and the data points. Currently the legend
@@ -430,14 +562,20 @@ struct ChartLayout {
     title_x: Option<Position>,
     /// Location of the title of the y axis
     title_y: Option<Position>,
+    /// Location of the title of the right y axis
+    title_y_right: Option<Position>,
     /// Location of the first label of the x axis
     label_x: Option<u16>,
     /// Location of the first label of the y axis
     label_y: Option<u16>,
+    /// X coordinate of the labels of the right y axis
+    label_y_right: Option<u16>,
     /// Y coordinate of the horizontal axis
     axis_x: Option<u16>,
     /// X coordinate of the vertical axis
     axis_y: Option<u16>,
+    /// X coordinate of the right vertical axis
+    axis_y_right: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
     /// Area of the graph
@@ -456,10 +594,12 @@ struct ChartLayout {
 /// for a complete documentation of what is possible.
 ///
 /// Finally, you can pass all of that to the `Chart` via [`Chart::new`], [`Chart::x_axis`] and
-/// [`Chart::y_axis`].
+/// [`Chart::y_axis`]. A secondary Y axis with its own bounds can be displayed on the right with
+/// [`Chart::y_axis_right`], see [`Dataset::y_axis_position`] to plot a dataset against it.
 ///
 /// Additionally, `Chart` allows configuring the legend [position](Chart::legend_position) and
-/// [hiding constraints](Chart::hidden_legend_constraints).
+/// [hiding constraints](Chart::hidden_legend_constraints). A [crosshair](Chart::crosshair) can also
+/// be drawn through a data coordinate, e.g. to highlight the data point under the mouse cursor.
 ///
 /// # Examples
 ///
@@ -514,6 +654,8 @@ pub struct Chart<'a> {
     x_axis: Axis<'a>,
     /// The vertical axis
     y_axis: Axis<'a>,
+    /// The secondary vertical axis, on the right
+    y_axis_right: Option<Axis<'a>>,
     /// A reference to the datasets
     datasets: Vec<Dataset<'a>>,
     /// The widget base style
@@ -523,6 +665,12 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// The data coordinate through which guide lines are drawn
+    crosshair: Option<(f64, f64)>,
+    /// The style of the crosshair guide lines and label
+    crosshair_style: Style,
+    /// Whether the value of the crosshair coordinate is displayed
+    crosshair_label: bool,
 }
 
 impl<'a> Chart<'a> {
@@ -558,10 +706,14 @@ impl<'a> Chart<'a> {
             block: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
+            y_axis_right: None,
             style: Style::default(),
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            crosshair: None,
+            crosshair_style: Style::new(),
+            crosshair_label: false,
         }
     }
 
@@ -608,7 +760,7 @@ impl<'a> Chart<'a> {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn x_axis(mut self, axis: Axis<'a>) -> Self {
-        self.x_axis = axis;
+        self.x_axis = axis.with_generated_labels();
         self
     }
 
@@ -632,7 +784,40 @@ impl<'a> Chart<'a> {
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn y_axis(mut self, axis: Axis<'a>) -> Self {
-        self.y_axis = axis;
+        self.y_axis = axis.with_generated_labels();
+        self
+    }
+
+    /// Sets the secondary Y [`Axis`], displayed on the right of the chart
+    ///
+    /// Its bounds are used to plot the datasets bound to [`YAxisPosition::Right`], e.g. to display
+    /// a price and a volume with different scales on the same chart. Its labels are displayed on
+    /// the right of the axis, and its title at the top right of the chart.
+    ///
+    /// The default is no right axis.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart, Dataset, YAxisPosition};
+    ///
+    /// let prices = [(0.0, 10.0), (1.0, 12.0)];
+    /// let volumes = [(0.0, 3000.0), (1.0, 5000.0)];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default().name("price").data(&prices),
+    ///     Dataset::default()
+    ///         .name("volume")
+    ///         .data(&volumes)
+    ///         .y_axis_position(YAxisPosition::Right),
+    /// ])
+    /// .y_axis(Axis::default().bounds([0.0, 20.0]).labels(["0", "20"]))
+    /// .y_axis_right(Axis::default().bounds([0.0, 10000.0]).labels(["0", "10k"]));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn y_axis_right(mut self, axis: Axis<'a>) -> Self {
+        self.y_axis_right = Some(axis.with_generated_labels());
         self
     }
 
@@ -727,6 +912,174 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets the data coordinate of a crosshair, or hide it
+    ///
+    /// The crosshair is made of a vertical and a horizontal guide line going through the given
+    /// `(x, y)` coordinate, e.g. to highlight the data point under the mouse cursor. The guide
+    /// lines are clipped to the graph area: a coordinate outside of the bounds of an axis does not
+    /// draw the line perpendicular to it.
+    ///
+    /// The default is [`None`], which hides the crosshair.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart};
+    ///
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .crosshair(Some((2.5, 7.0)))
+    ///     .crosshair_label(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn crosshair(mut self, coordinate: Option<(f64, f64)>) -> Self {
+        self.crosshair = coordinate;
+        self
+    }
+
+    /// Sets the style of the crosshair guide lines and label
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// See [`Chart::crosshair`] to set the crosshair coordinate.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn crosshair_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.crosshair_style = style.into();
+        self
+    }
+
+    /// Sets whether the value of the crosshair coordinate is displayed
+    ///
+    /// When enabled, the coordinate is displayed as `(x, y)` next to the center of the crosshair,
+    /// on its right if it fits in the graph area or on its left otherwise.
+    ///
+    /// The default is `false`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// See [`Chart::crosshair`] to set the crosshair coordinate.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn crosshair_label(mut self, show_label: bool) -> Self {
+        self.crosshair_label = show_label;
+        self
+    }
+
+    /// Returns the position of the cell where the given data coordinate is drawn
+    ///
+    /// `area` is the area the chart is rendered in. Returns `None` if the coordinate is outside of
+    /// the bounds of the axes or if the chart is rendered in an area too small to draw the graph.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::{Axis, Chart};
+    ///
+    /// let chart = Chart::new(vec![])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// assert_eq!(
+    ///     chart.data_to_position(area, (2.0, 3.0)),
+    ///     Some(Position::new(2, 7))
+    /// );
+    /// ```
+    pub fn data_to_position(&self, area: Rect, coordinate: (f64, f64)) -> Option<Position> {
+        let graph_area = self.graph_area(area)?;
+        let x = self.x_to_column(graph_area, coordinate.0)?;
+        let y = self.y_to_row(graph_area, coordinate.1)?;
+        Some(Position::new(x, y))
+    }
+
+    /// Returns the data coordinate drawn at the given cell position
+    ///
+    /// This is the inverse of [`Chart::data_to_position`], e.g. to convert the position of the
+    /// mouse cursor into a data coordinate. `area` is the area the chart is rendered in. Returns
+    /// `None` if the position is outside of the graph area, i.e. on the axes, labels or block of
+    /// the chart.
+    pub fn position_to_data(&self, area: Rect, position: Position) -> Option<(f64, f64)> {
+        let graph_area = self.graph_area(area)?;
+        if !graph_area.contains(position) {
+            return None;
+        }
+        let [left, right] = self.x_axis.bounds;
+        let [bottom, top] = self.y_axis.bounds;
+        let column = f64::from(position.x - graph_area.x);
+        let row = f64::from(position.y - graph_area.y);
+        let x = left + column * (right - left) / f64::from(graph_area.width - 1).max(1.0);
+        let y = top - row * (top - bottom) / f64::from(graph_area.height - 1).max(1.0);
+        Some((x, y))
+    }
+
+    /// Returns the data point drawn the closest to the given cell position
+    ///
+    /// The returned value is `(dataset_index, point_index, (x, y))`, where `dataset_index` is the
+    /// index of the dataset in the chart and `point_index` the index of the point in the data of
+    /// the dataset. The distance is measured in cells on the screen, so this can be used to find
+    /// the data point under the mouse cursor, e.g. to display a tooltip. Points outside of the
+    /// bounds of the axes are ignored. The points of each dataset are projected with the bounds
+    /// of its [Y axis](Dataset::y_axis_position).
+    ///
+    /// `area` is the area the chart is rendered in. Returns `None` if the position is outside of
+    /// the graph area or if there are no visible points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Position, Rect};
+    /// use ratatui::widgets::{Axis, Chart, Dataset};
+    ///
+    /// let data = [(1.0, 1.0), (6.0, 8.0)];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data)])
+    ///     .x_axis(Axis::default().bounds([0.0, 10.0]))
+    ///     .y_axis(Axis::default().bounds([0.0, 10.0]));
+    /// let area = Rect::new(0, 0, 11, 11);
+    /// let nearest = chart.nearest_point(area, Position::new(5, 4));
+    /// assert_eq!(nearest, Some((0, 1, (6.0, 8.0))));
+    /// ```
+    pub fn nearest_point(
+        &self,
+        area: Rect,
+        position: Position,
+    ) -> Option<(usize, usize, (f64, f64))> {
+        let graph_area = self.graph_area(area)?;
+        if !graph_area.contains(position) {
+            return None;
+        }
+        let column = f64::from(position.x - graph_area.x);
+        let row = f64::from(position.y - graph_area.y);
+        self.datasets
+            .iter()
+            .enumerate()
+            .flat_map(|(dataset_index, dataset)| {
+                let y_axis = self.dataset_y_axis(dataset);
+                dataset
+                    .data
+                    .iter()
+                    .enumerate()
+                    .map(move |(point_index, point)| (dataset_index, point_index, *point, y_axis))
+            })
+            .filter_map(|(dataset_index, point_index, point, y_axis)| {
+                let dx = self.x_offset(graph_area, point.0)? - column;
+                let dy = Self::y_offset(graph_area, point.1, y_axis)? - row;
+                Some((dx * dx + dy * dy, (dataset_index, point_index, point)))
+            })
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, nearest)| nearest)
+    }
+
+    /// Returns the area where the datasets are drawn when the chart is rendered in the given area
+    fn graph_area(&self, area: Rect) -> Option<Rect> {
+        let chart_area = self.block.inner_if_some(area);
+        self.layout(chart_area).map(|layout| layout.graph_area)
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
@@ -757,7 +1110,26 @@ impl<'a> Chart<'a> {
             x += 1;
         }
 
-        let graph_width = area.right().saturating_sub(x);
+        let mut right = area.right();
+        let mut label_y_right = None;
+        let mut axis_y_right = None;
+        if let Some(axis) = self.y_axis_right.as_ref().filter(|a| !a.labels.is_empty()) {
+            let labels_width = axis
+                .labels
+                .iter()
+                .map(Line::width)
+                .max()
+                .unwrap_or_default() as u16;
+            let labels_width = labels_width.min(area.width / 3);
+            if x + labels_width + 1 < right {
+                right -= labels_width;
+                label_y_right = Some(right);
+                right -= 1;
+                axis_y_right = Some(right);
+            }
+        }
+
+        let graph_width = right.saturating_sub(x);
         let graph_height = y.saturating_sub(area.top()).saturating_add(1);
         debug_assert_ne!(
             graph_width, 0,
@@ -785,57 +1157,89 @@ impl<'a> Chart<'a> {
             }
         }
 
-        let mut legend_area = None;
-        if let Some(legend_position) = self.legend_position {
-            let legends = self
-                .datasets
-                .iter()
-                .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
-
-            if let Some(inner_width) = legends.clone().max() {
-                let legend_width = inner_width + 2;
-                let legend_height = legends.count() as u16 + 2;
-
-                let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
-                    .flex(Flex::Start)
-                    .areas(graph_area);
-
-                if inner_width > 0
-                    && legend_width <= max_legend_width.width
-                    && legend_height <= max_legend_height.height
-                {
-                    legend_area = legend_position.layout(
-                        graph_area,
-                        legend_width,
-                        legend_height,
-                        title_x
-                            .and(self.x_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                        title_y
-                            .and(self.y_axis.title.as_ref())
-                            .map(|t| t.width() as u16)
-                            .unwrap_or_default(),
-                    );
-                }
+        let mut title_y_right = None;
+        if let Some(title) = self.y_axis_right.as_ref().and_then(|a| a.title.as_ref()) {
+            let w = title.width() as u16;
+            let left_title_width = title_y
+                .and(self.y_axis.title.as_ref())
+                .map_or(0, |t| t.width() as u16 + 1);
+            if w + left_title_width < graph_area.width && graph_area.height > 2 {
+                title_y_right = Some(Position::new(graph_area.right() - w, area.top()));
             }
         }
+
+        let legend_area = self.legend_area(graph_area, title_x, title_y, title_y_right);
         Some(ChartLayout {
             title_x,
             title_y,
+            title_y_right,
             label_x,
             label_y,
+            label_y_right,
             axis_x,
             axis_y,
+            axis_y_right,
             legend_area,
             graph_area,
         })
     }
 
+    /// Returns the area of the legend in the graph area, if it is shown
+    fn legend_area(
+        &self,
+        graph_area: Rect,
+        title_x: Option<Position>,
+        title_y: Option<Position>,
+        title_y_right: Option<Position>,
+    ) -> Option<Rect> {
+        let legend_position = self.legend_position?;
+        let legends = self
+            .datasets
+            .iter()
+            .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
+        let inner_width = legends.clone().max()?;
+        let legend_width = inner_width + 2;
+        let legend_height = legends.count() as u16 + 2;
+
+        let [max_legend_width] = Layout::horizontal([self.hidden_legend_constraints.0])
+            .flex(Flex::Start)
+            .areas(graph_area);
+
+        let [max_legend_height] = Layout::vertical([self.hidden_legend_constraints.1])
+            .flex(Flex::Start)
+            .areas(graph_area);
+
+        if inner_width == 0
+            || legend_width > max_legend_width.width
+            || legend_height > max_legend_height.height
+        {
+            return None;
+        }
+        let legend = legend_position.layout(
+            graph_area,
+            legend_width,
+            legend_height,
+            title_x
+                .and(self.x_axis.title.as_ref())
+                .map(|t| t.width() as u16)
+                .unwrap_or_default(),
+            title_y
+                .and(self.y_axis.title.as_ref())
+                .map(|t| t.width() as u16)
+                .unwrap_or_default(),
+        )?;
+        // move the legend below the title of the right axis when they overlap
+        match title_y_right {
+            Some(title) if legend.y == title.y && legend.right() > title.x => {
+                (legend.bottom() < graph_area.bottom()).then(|| Rect {
+                    y: legend.y + 1,
+                    ..legend
+                })
+            }
+            _ => Some(legend),
+        }
+    }
+
     fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
         let mut max_width = self
             .y_axis
@@ -949,22 +1353,116 @@ impl<'a> Chart<'a> {
         chart_area: Rect,
         graph_area: Rect,
     ) {
-        let Some(x) = layout.label_y else { return };
-        let labels = &self.y_axis.labels;
+        if let Some(x) = layout.label_y {
+            let width = (graph_area.left() - chart_area.left()).saturating_sub(1);
+            Self::render_y_axis_labels(buf, &self.y_axis, x, width, graph_area);
+        }
+        if let (Some(x), Some(axis)) = (layout.label_y_right, &self.y_axis_right) {
+            let width = chart_area.right() - x;
+            Self::render_y_axis_labels(buf, axis, x, width, graph_area);
+        }
+    }
+
+    /// Renders the labels of a Y axis in the column of the given width starting at `x`
+    fn render_y_axis_labels(buf: &mut Buffer, axis: &Axis, x: u16, width: u16, graph_area: Rect) {
+        let labels = &axis.labels;
         let labels_len = labels.len() as u16;
         for (i, label) in labels.iter().enumerate() {
             let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
             if dy < graph_area.bottom() {
-                let label_area = Rect::new(
-                    x,
-                    graph_area.bottom().saturating_sub(1) - dy,
-                    (graph_area.left() - chart_area.left()).saturating_sub(1),
-                    1,
-                );
-                Self::render_label(buf, label, label_area, self.y_axis.labels_alignment);
+                let label_area = Rect::new(x, graph_area.bottom().saturating_sub(1) - dy, width, 1);
+                Self::render_label(buf, label, label_area, axis.labels_alignment);
             }
         }
     }
+
+    /// Returns the Y axis whose bounds are used to plot the given dataset
+    const fn dataset_y_axis(&self, dataset: &Dataset) -> &Axis<'a> {
+        match (dataset.y_axis_position, &self.y_axis_right) {
+            (YAxisPosition::Right, Some(axis)) => axis,
+            _ => &self.y_axis,
+        }
+    }
+
+    /// Returns the column of the graph area where the given x coordinate is drawn
+    ///
+    /// Returns `None` if the coordinate is outside of the bounds of the x axis.
+    fn x_to_column(&self, graph_area: Rect, x: f64) -> Option<u16> {
+        let offset = self.x_offset(graph_area, x)?;
+        Some(graph_area.left() + offset.round() as u16)
+    }
+
+    /// Returns the row of the graph area where the given y coordinate is drawn
+    ///
+    /// Returns `None` if the coordinate is outside of the bounds of the y axis.
+    fn y_to_row(&self, graph_area: Rect, y: f64) -> Option<u16> {
+        let offset = Self::y_offset(graph_area, y, &self.y_axis)?;
+        Some(graph_area.top() + offset.round() as u16)
+    }
+
+    /// Returns the fractional number of columns between the left of the graph area and the given
+    /// x coordinate
+    fn x_offset(&self, graph_area: Rect, x: f64) -> Option<f64> {
+        let [left, right] = self.x_axis.bounds;
+        if graph_area.width == 0 || right <= left || !(left..=right).contains(&x) {
+            return None;
+        }
+        Some((x - left) * f64::from(graph_area.width - 1) / (right - left))
+    }
+
+    /// Returns the fractional number of rows between the top of the graph area and the given y
+    /// coordinate of the given axis
+    fn y_offset(graph_area: Rect, y: f64, axis: &Axis) -> Option<f64> {
+        let [bottom, top] = axis.bounds;
+        if graph_area.height == 0 || top <= bottom || !(bottom..=top).contains(&y) {
+            return None;
+        }
+        Some((top - y) * f64::from(graph_area.height - 1) / (top - bottom))
+    }
+
+    fn render_crosshair(&self, buf: &mut Buffer, graph_area: Rect) {
+        let Some((x, y)) = self.crosshair else {
+            return;
+        };
+        let column = self.x_to_column(graph_area, x);
+        let row = self.y_to_row(graph_area, y);
+        if let Some(column) = column {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(column, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(self.crosshair_style);
+            }
+        }
+        if let Some(row) = row {
+            for x in graph_area.left()..graph_area.right() {
+                buf[(x, row)]
+                    .set_symbol(symbols::line::HORIZONTAL)
+                    .set_style(self.crosshair_style);
+            }
+        }
+        let (Some(column), Some(row)) = (column, row) else {
+            return;
+        };
+        buf[(column, row)].set_symbol(symbols::line::CROSS);
+
+        if self.crosshair_label {
+            let label = format!("({x}, {y})");
+            let width = label.len() as u16;
+            let label_x = if column + 1 + width <= graph_area.right() {
+                column + 1
+            } else {
+                column.saturating_sub(width).max(graph_area.left())
+            };
+            let label_area = Rect::new(label_x, row, width, 1).intersection(graph_area);
+            buf.set_stringn(
+                label_area.x,
+                label_area.y,
+                label,
+                label_area.width as usize,
+                self.crosshair_style,
+            );
+        }
+    }
 }
 
 impl Widget for Chart<'_> {
@@ -1009,19 +1507,32 @@ impl Widget for &Chart<'_> {
             }
         }
 
+        if let (Some(x), Some(axis)) = (layout.axis_y_right, &self.y_axis_right) {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(axis.style);
+            }
+        }
+
         if let Some(y) = layout.axis_x {
             if let Some(x) = layout.axis_y {
                 buf[(x, y)]
                     .set_symbol(symbols::line::BOTTOM_LEFT)
                     .set_style(self.x_axis.style);
             }
+            if let Some(x) = layout.axis_y_right {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::BOTTOM_RIGHT)
+                    .set_style(self.x_axis.style);
+            }
         }
 
         for dataset in &self.datasets {
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
                 .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
+                .y_bounds(self.dataset_y_axis(dataset).bounds)
                 .marker(dataset.marker)
                 .paint(|ctx| {
                     ctx.draw(&Points {
@@ -1031,24 +1542,24 @@ impl Widget for &Chart<'_> {
                     match dataset.graph_type {
                         GraphType::Line => {
                             for data in dataset.data.windows(2) {
-                                ctx.draw(&CanvasLine {
-                                    x1: data[0].0,
-                                    y1: data[0].1,
-                                    x2: data[1].0,
-                                    y2: data[1].1,
-                                    color: dataset.style.fg.unwrap_or(Color::Reset),
-                                });
+                                ctx.draw(&CanvasLine::new(
+                                    data[0].0,
+                                    data[0].1,
+                                    data[1].0,
+                                    data[1].1,
+                                    dataset.style.fg.unwrap_or(Color::Reset),
+                                ));
                             }
                         }
                         GraphType::Bar => {
                             for (x, y) in dataset.data {
-                                ctx.draw(&CanvasLine {
-                                    x1: *x,
-                                    y1: 0.0,
-                                    x2: *x,
-                                    y2: *y,
-                                    color: dataset.style.fg.unwrap_or(Color::Reset),
-                                });
+                                ctx.draw(&CanvasLine::new(
+                                    *x,
+                                    0.0,
+                                    *x,
+                                    *y,
+                                    dataset.style.fg.unwrap_or(Color::Reset),
+                                ));
                             }
                         }
                         GraphType::Scatter => {}
@@ -1057,6 +1568,8 @@ impl Widget for &Chart<'_> {
                 .render(graph_area, buf);
         }
 
+        self.render_crosshair(buf, graph_area);
+
         if let Some(Position { x, y }) = layout.title_x {
             let title = self.x_axis.title.as_ref().unwrap();
             let width = graph_area
@@ -1093,6 +1606,21 @@ impl Widget for &Chart<'_> {
             buf.set_line(x, y, title, width);
         }
 
+        if let Some(Position { x, y }) = layout.title_y_right {
+            let title = self.y_axis_right.as_ref().unwrap().title.as_ref().unwrap();
+            let width = title.width() as u16;
+            buf.set_style(
+                Rect {
+                    x,
+                    y,
+                    width,
+                    height: 1,
+                },
+                original_style,
+            );
+            buf.set_line(x, y, title, width);
+        }
+
         if let Some(legend_area) = layout.legend_area {
             buf.set_style(legend_area, original_style);
             Block::bordered().render(legend_area, buf);
@@ -1160,6 +1688,7 @@ mod tests {
     use alloc::{format, vec};
 
     use ratatui_core::style::{Modifier, Stylize};
+    use ratatui_core::text::Span;
     use rstest::rstest;
     use strum::ParseError;
 
@@ -1305,6 +1834,207 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn legend_entries_use_dataset_style() {
+        let datasets = vec![
+            Dataset::default().name("a").red(),
+            Dataset::default().name("b").blue(),
+        ];
+        let widget = Chart::new(datasets);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 16));
+        widget.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(17, 0)].symbol(), "┌");
+        assert_eq!(buffer[(18, 1)].symbol(), "a");
+        assert_eq!(buffer[(18, 1)].fg, Color::Red);
+        assert_eq!(buffer[(18, 2)].symbol(), "b");
+        assert_eq!(buffer[(18, 2)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn legend_hides_when_chart_area_is_too_small() {
+        let datasets = vec![
+            Dataset::default().name("a").red(),
+            Dataset::default().name("b").blue(),
+        ];
+        let widget = Chart::new(datasets);
+
+        // the legend is 4 rows high, which is more than 25% of 12 rows
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 12));
+        (&widget).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(buffer.area));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 16));
+        widget.render(buffer.area, &mut buffer);
+        assert_ne!(buffer, Buffer::empty(buffer.area));
+    }
+
+    fn crosshair_chart<'a>() -> Chart<'a> {
+        Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]))
+    }
+
+    #[test]
+    fn crosshair_is_hidden_by_default() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        crosshair_chart().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(buffer.area));
+    }
+
+    #[test]
+    fn crosshair_at_projected_column_and_row() {
+        let chart = crosshair_chart().crosshair(Some((3.0, 1.0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "   │       ",
+            "   │       ",
+            "   │       ",
+            "───┼───────",
+            "   │       ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn crosshair_is_clipped_to_graph_area() {
+        let chart = crosshair_chart().crosshair(Some((12.0, 4.0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "───────────",
+            "           ",
+            "           ",
+            "           ",
+            "           ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn crosshair_with_axes() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 10.0]).labels(["0", "10"]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]).labels(["0", "4"]))
+            .crosshair(Some((10.0, 0.0)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 7));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "4│           │",
+            " │           │",
+            " │           │",
+            " │           │",
+            "0│───────────┼",
+            " └────────────",
+            " 0          10",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn crosshair_label() {
+        let chart = crosshair_chart()
+            .crosshair(Some((2.0, 3.0)))
+            .crosshair_label(true)
+            .crosshair_style(Style::new().red());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "  │        ",
+            "──┼(2, 3)──",
+            "  │        ",
+            "  │        ",
+            "  │        ",
+        ]);
+        expected.set_style(Rect::new(2, 0, 1, 5), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 11, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn crosshair_label_on_the_left_when_too_wide() {
+        let chart = crosshair_chart()
+            .crosshair(Some((8.0, 3.0)))
+            .crosshair_label(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "        │  ",
+            "──(8, 3)┼──",
+            "        │  ",
+            "        │  ",
+            "        │  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn data_to_position() {
+        let chart = crosshair_chart().block(Block::bordered());
+        let area = Rect::new(0, 0, 13, 7);
+        assert_eq!(
+            chart.data_to_position(area, (0.0, 4.0)),
+            Some(Position::new(1, 1))
+        );
+        assert_eq!(
+            chart.data_to_position(area, (10.0, 0.0)),
+            Some(Position::new(11, 5))
+        );
+        assert_eq!(
+            chart.data_to_position(area, (3.0, 1.0)),
+            Some(Position::new(4, 4))
+        );
+        assert_eq!(chart.data_to_position(area, (11.0, 1.0)), None);
+        assert_eq!(chart.data_to_position(Rect::ZERO, (3.0, 1.0)), None);
+    }
+
+    #[test]
+    fn position_to_data() {
+        let chart = crosshair_chart().block(Block::bordered());
+        let area = Rect::new(0, 0, 13, 7);
+        assert_eq!(
+            chart.position_to_data(area, Position::new(1, 1)),
+            Some((0.0, 4.0))
+        );
+        assert_eq!(
+            chart.position_to_data(area, Position::new(4, 4)),
+            Some((3.0, 1.0))
+        );
+        // on the block
+        assert_eq!(chart.position_to_data(area, Position::new(0, 4)), None);
+    }
+
+    #[rstest]
+    #[case::first_point(Position::new(2, 5), Some((0, 0, (0.0, 0.0))))]
+    #[case::closest_column(Position::new(5, 0), Some((0, 1, (4.0, 2.0))))]
+    #[case::other_dataset(Position::new(10, 3), Some((1, 0, (9.0, 1.0))))]
+    #[case::row_matters(Position::new(8, 5), Some((1, 1, (6.0, 0.0))))]
+    #[case::on_the_axis(Position::new(1, 3), None)]
+    fn nearest_point(
+        #[case] position: Position,
+        #[case] expected: Option<(usize, usize, (f64, f64))>,
+    ) {
+        let first = [(0.0, 0.0), (4.0, 2.0), (20.0, 3.0)];
+        let second = [(9.0, 1.0), (6.0, 0.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&first),
+            Dataset::default().data(&second),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 10.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]).labels(["0", "4"]));
+        // the y axis labels and line take the first two columns
+        let area = Rect::new(0, 0, 13, 6);
+        assert_eq!(chart.nearest_point(area, position), expected);
+    }
+
+    #[test]
+    fn nearest_point_without_data() {
+        let chart = crosshair_chart();
+        let area = Rect::new(0, 0, 11, 5);
+        assert_eq!(chart.nearest_point(area, Position::new(3, 3)), None);
+    }
+
     #[test]
     fn test_chart_have_a_topleft_legend() {
         let chart = Chart::new(vec![Dataset::default().name("Ds1")])
@@ -1545,4 +2275,173 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[rstest]
+    #[case::left_axis(YAxisPosition::Left, true, 2)]
+    #[case::right_axis(YAxisPosition::Right, true, 3)]
+    #[case::right_axis_fallback_to_left(YAxisPosition::Right, false, 2)]
+    fn dataset_projected_with_its_y_axis_bounds(
+        #[case] position: YAxisPosition,
+        #[case] has_right_axis: bool,
+        #[case] row: usize,
+    ) {
+        let data = [(2.0, 2.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .y_axis_position(position);
+        let mut chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        if has_right_axis {
+            chart = chart.y_axis_right(Axis::default().bounds([0.0, 10.0]));
+        }
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = vec!["     "; 5];
+        expected[row] = "  █  ";
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_right_y_axis() {
+        let left = [(0.0, 0.0), (4.0, 4.0)];
+        let right = [(0.0, 100.0), (4.0, 0.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .data(&left)
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Line),
+            Dataset::default()
+                .data(&right)
+                .marker(symbols::Marker::Dot)
+                .graph_type(GraphType::Line)
+                .y_axis_position(YAxisPosition::Right),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]).labels(["0", "4"]))
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, 4.0])
+                .labels(["0", "4"])
+                .title("L"),
+        )
+        .y_axis_right(
+            Axis::default()
+                .bounds([0.0, 100.0])
+                .labels(["0", "100"])
+                .title("R"),
+        );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 7));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "4│L   R│100",
+            " │ • █ │   ",
+            " │  •  │   ",
+            " │ █ • │   ",
+            "0│█   •│0  ",
+            " └─────┘   ",
+            " 0    4    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn nearest_point_with_right_y_axis() {
+        let left = [(0.0, 4.0)];
+        let right = [(4.0, 10.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&left),
+            Dataset::default()
+                .data(&right)
+                .y_axis_position(YAxisPosition::Right),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis_right(Axis::default().bounds([0.0, 40.0]));
+        let area = Rect::new(0, 0, 5, 5);
+        // (4.0, 10.0) is drawn a quarter of the way up using the bounds of the right axis
+        assert_eq!(
+            chart.nearest_point(area, Position::new(4, 3)),
+            Some((1, 0, (4.0, 10.0)))
+        );
+        assert_eq!(
+            chart.nearest_point(area, Position::new(0, 0)),
+            Some((0, 0, (0.0, 4.0)))
+        );
+    }
+
+    #[test]
+    fn y_axis_position_to_string() {
+        assert_eq!(YAxisPosition::Left.to_string(), "Left");
+        assert_eq!("Right".parse(), Ok(YAxisPosition::Right));
+    }
+
+    #[test]
+    fn legend_below_right_y_axis_title() {
+        let data = [(0.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default().name("data").data(&data)])
+            .y_axis_right(Axis::default().title("R"))
+            .hidden_legend_constraints((Constraint::Min(0), Constraint::Min(0)));
+        let layout = chart.layout(Rect::new(0, 0, 20, 10)).unwrap();
+        assert_eq!(layout.title_y_right, Some(Position::new(19, 0)));
+        assert_eq!(layout.legend_area, Some(Rect::new(14, 1, 6, 3)));
+    }
+
+    #[rstest]
+    #[case::default_tick_count(None, vec![0.0, 50.0, 100.0])]
+    #[case::five_ticks(Some(5), vec![0.0, 25.0, 50.0, 75.0, 100.0])]
+    #[case::at_least_two_ticks(Some(0), vec![0.0, 100.0])]
+    fn labels_from_is_called_at_ticks(
+        #[case] tick_count: Option<usize>,
+        #[case] expected: Vec<f64>,
+    ) {
+        let mut axis = Axis::default()
+            .bounds([0.0, 100.0])
+            .labels_from(|value| format!("{value}"));
+        if let Some(tick_count) = tick_count {
+            axis = axis.tick_count(tick_count);
+        }
+        let chart = Chart::new(vec![]).x_axis(axis);
+        let labels = expected.iter().map(|value| Line::from(format!("{value}")));
+        assert!(chart.x_axis.labels.into_iter().eq(labels));
+    }
+
+    #[test]
+    fn labels_from_keeps_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        let axis = Axis::default().labels_from(|value| format!("{value}"));
+        assert_send_sync(&axis);
+        assert_send_sync(&Chart::new(vec![]).x_axis(axis.clone()));
+        assert_eq!(axis.clone(), axis);
+        assert_ne!(
+            axis,
+            Axis::default().labels_from(|value| format!("{value}"))
+        );
+    }
+
+    #[test]
+    fn render_labels_from() {
+        let chart = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 120.0])
+                    .labels_from(|seconds| Span::from(format!("{}m", seconds / 60.0))),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 1.0])
+                    .tick_count(2)
+                    .labels_from(|value| format!("{value}")),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 4));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "1│          ",
+            "0│          ",
+            " └──────────",
+            "0m    1m  2m",
+        ]);
+        assert_eq!(buffer, expected);
+    }
 }
@@ -26,9 +26,47 @@ use ratatui_core::widgets::Widget;
 ///
 /// For a more complete example how to utilize `Clear` to realize popups see
 /// the example `examples/popup.rs`
+///
+/// # Keeping the style
+///
+/// `Clear` resets the style of the cells along with their symbols. To only clear the symbols and
+/// keep the existing style (e.g. the background of a block rendered below), use
+/// [`Clear::keep_style`] which returns a [`ClearSymbols`] widget.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Clear;
 
+/// A widget which clears the symbols of a certain area while keeping the style of its cells.
+///
+/// Each cell in the area is set to a space, its foreground and background colors and modifiers are
+/// left untouched. Unlike [`Clear`], the area is restricted to the buffer, so the widget can be
+/// rendered in an area which exceeds the buffer.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::{Block, Clear};
+/// use ratatui::Frame;
+///
+/// fn draw_on_clear(f: &mut Frame, area: Rect) {
+///     f.render_widget(Block::new().on_blue(), f.area());
+///     f.render_widget(Clear::keep_style(), area); // <- the area stays blue
+///     f.render_widget("Hello", area);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ClearSymbols;
+
+impl Clear {
+    /// Creates a widget which clears the symbols of the area but keeps the style of its cells
+    ///
+    /// See [`ClearSymbols`] for more details.
+    pub const fn keep_style() -> ClearSymbols {
+        ClearSymbols
+    }
+}
+
 impl Widget for Clear {
     fn render(self, area: Rect, buf: &mut Buffer) {
         Widget::render(&self, area, buf);
@@ -45,10 +83,28 @@ impl Widget for &Clear {
     }
 }
 
+impl Widget for ClearSymbols {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &ClearSymbols {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                buf[(x, y)].set_char(' ').set_skip(false);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::buffer::Buffer;
     use ratatui_core::layout::Rect;
+    use ratatui_core::style::{Style, Stylize};
     use ratatui_core::widgets::Widget;
 
     use super::*;
@@ -69,4 +125,24 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn render_keep_style() {
+        let mut buffer = Buffer::with_lines(["xxxxx"; 3]);
+        buffer.set_style(buffer.area, Style::new().blue().on_red().bold());
+        Clear::keep_style().render(Rect::new(1, 1, 3, 1), &mut buffer);
+        let mut expected = Buffer::with_lines(["xxxxx", "x   x", "xxxxx"]);
+        expected.set_style(expected.area, Style::new().blue().on_red().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_keep_style_outside_of_buffer() {
+        let mut buffer = Buffer::with_lines(["xxx"; 2]);
+        buffer.set_style(buffer.area, Style::new().on_red());
+        ClearSymbols.render(Rect::new(1, 1, 5, 5), &mut buffer);
+        let mut expected = Buffer::with_lines(["xxx", "x  "]);
+        expected.set_style(expected.area, Style::new().on_red());
+        assert_eq!(buffer, expected);
+    }
 }
@@ -1,5 +1,8 @@
 //! The [`Gauge`] widget is used to display a horizontal progress bar.
 use alloc::format;
+use alloc::vec::Vec;
+use core::iter;
+use core::ops::Range;
 
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
@@ -19,7 +22,8 @@ use crate::block::{Block, BlockExt};
 /// The associated label is always centered horizontally and vertically. If not set with
 /// [`Gauge::label`], the label is the percentage of the bar filled.
 ///
-/// You might want to have a higher precision bar using [`Gauge::use_unicode`].
+/// You might want to have a higher precision bar using [`Gauge::use_unicode`], or a bar made of
+/// discrete blocks using [`Gauge::segments`].
 ///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
@@ -45,6 +49,7 @@ pub struct Gauge<'a> {
     ratio: f64,
     label: Option<Span<'a>>,
     use_unicode: bool,
+    segments: u16,
     style: Style,
     gauge_style: Style,
 }
@@ -62,20 +67,14 @@ impl<'a> Gauge<'a> {
 
     /// Sets the bar progression from a percentage.
     ///
-    /// # Panics
-    ///
-    /// This method panics if `percent` is **not** between 0 and 100 inclusively.
+    /// Values above 100 are clamped to 100.
     ///
     /// # See also
     ///
     /// See [`Gauge::ratio`] to set from a float.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn percent(mut self, percent: u16) -> Self {
-        assert!(
-            percent <= 100,
-            "Percentage should be between 0 and 100 inclusively."
-        );
-        self.ratio = f64::from(percent) / 100.0;
+        self.ratio = f64::from(percent.min(100)) / 100.0;
         self
     }
 
@@ -84,20 +83,18 @@ impl<'a> Gauge<'a> {
     /// `ratio` is the ratio between filled bar over empty bar (i.e. `3/4` completion is `0.75`).
     /// This is more easily seen as a floating point percentage (e.g. 42% = `0.42`).
     ///
-    /// # Panics
-    ///
-    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    /// Values outside of `0.0..=1.0` are clamped to that range, and `NaN` is treated as `0.0`.
     ///
     /// # See also
     ///
     /// See [`Gauge::percent`] to set from a percentage.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn ratio(mut self, ratio: f64) -> Self {
-        assert!(
-            (0.0..=1.0).contains(&ratio),
-            "Ratio should be between 0 and 1 inclusively."
-        );
-        self.ratio = ratio;
+        self.ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
         self
     }
 
@@ -147,6 +144,28 @@ impl<'a> Gauge<'a> {
         self.use_unicode = unicode;
         self
     }
+
+    /// Divides the bar into a number of discrete segments.
+    ///
+    /// The segments share the width of the bar and are separated by a one cell gap when they are
+    /// wide enough. The number of lit segments is proportional to the ratio, rounded to the nearest
+    /// segment (e.g. `0.6` lights 2 out of 4 segments, `0.65` lights 3).
+    ///
+    /// When segments are set, [`Gauge::use_unicode`] has no effect. Set to `0` (the default) to
+    /// display a continuous bar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Gauge;
+    ///
+    /// let gauge = Gauge::default().segments(10).percent(40);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn segments(mut self, segments: u16) -> Self {
+        self.segments = segments;
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
@@ -187,9 +206,14 @@ impl Gauge<'_> {
         } else {
             gauge_area.left() + filled_width.round() as u16
         };
+        let filled = if self.segments == 0 {
+            iter::once(gauge_area.left()..end).collect()
+        } else {
+            self.lit_segments(gauge_area)
+        };
         for y in gauge_area.top()..gauge_area.bottom() {
             // render the filled area (left to end)
-            for x in gauge_area.left()..end {
+            for x in filled.iter().flat_map(Clone::clone) {
                 // Use full block for the filled part of the gauge and spaces for the part that is
                 // covered by the label. Note that the background and foreground colors are swapped
                 // for the label part, otherwise the gauge will be inverted
@@ -205,13 +229,32 @@ impl Gauge<'_> {
                         .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
                 }
             }
-            if self.use_unicode && self.ratio < 1.0 {
+            if self.use_unicode && self.segments == 0 && self.ratio < 1.0 {
                 buf[(end, y)].set_symbol(get_unicode_block(filled_width % 1.0));
             }
         }
         // render the label
         buf.set_span(label_col, label_row, label, clamped_label_width);
     }
+
+    /// Returns the columns of the lit segments
+    ///
+    /// Each segment is followed by a one column gap (the last one being outside of the gauge) when
+    /// there is enough room for it, otherwise the segments are contiguous.
+    fn lit_segments(&self, gauge_area: Rect) -> Vec<Range<u16>> {
+        let segments = u32::from(self.segments);
+        let width = u32::from(gauge_area.width);
+        let gap = u32::from(width + 1 >= 2 * segments);
+        let boundary = |index: u32| (index * (width + gap) / segments) as u16;
+        let lit = (f64::from(self.segments) * self.ratio).round() as u32;
+        (0..lit.min(segments))
+            .map(|index| {
+                let start = gauge_area.left() + boundary(index);
+                let end = gauge_area.left() + boundary(index + 1) - gap as u16;
+                start..end
+            })
+            .collect()
+    }
 }
 
 fn get_unicode_block<'a>(frac: f64) -> &'a str {
@@ -480,25 +523,27 @@ impl Styled for LineGauge<'_> {
 mod tests {
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
     use ratatui_core::symbols;
+    use rstest::rstest;
 
     use super::*;
 
     #[test]
-    #[should_panic = "Percentage should be between 0 and 100 inclusively"]
-    fn gauge_invalid_percentage() {
-        let _ = Gauge::default().percent(110);
-    }
-
-    #[test]
-    #[should_panic = "Ratio should be between 0 and 1 inclusively"]
-    fn gauge_invalid_ratio_upper_bound() {
-        let _ = Gauge::default().ratio(1.1);
+    fn gauge_percentage_is_clamped() {
+        let gauge = Gauge::default().percent(110);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["███100% ██"]));
     }
 
-    #[test]
-    #[should_panic = "Ratio should be between 0 and 1 inclusively"]
-    fn gauge_invalid_ratio_lower_bound() {
-        let _ = Gauge::default().ratio(-0.5);
+    #[rstest]
+    #[case::upper_bound(1.5, "███100% ██")]
+    #[case::lower_bound(-0.2, "    0%    ")]
+    #[case::nan(f64::NAN, "    0%    ")]
+    fn gauge_ratio_is_clamped(#[case] ratio: f64, #[case] expected: &str) {
+        let gauge = Gauge::default().ratio(ratio);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
     }
 
     #[test]
@@ -513,6 +558,41 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::empty(0.0, "           ")]
+    #[case::on_first_boundary(0.25, "██         ")]
+    #[case::on_middle_boundary(0.5, "██ ██      ")]
+    #[case::rounds_down(0.6, "██ ██      ")]
+    #[case::rounds_half_up(0.625, "██ ██ ██   ")]
+    #[case::rounds_up(0.65, "██ ██ ██   ")]
+    #[case::full(1.0, "██ ██ ██ ██")]
+    fn gauge_segments(#[case] ratio: f64, #[case] expected: &str) {
+        let gauge = Gauge::default().segments(4).ratio(ratio).label("");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn gauge_segments_ignore_unicode() {
+        let gauge = Gauge::default()
+            .segments(2)
+            .use_unicode(true)
+            .ratio(0.7)
+            .label("");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["████     "]));
+    }
+
+    #[test]
+    fn gauge_segments_without_room_for_gaps() {
+        let gauge = Gauge::default().segments(4).ratio(0.5).label("");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        gauge.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["███   "]));
+    }
+
     #[test]
     fn line_gauge_can_be_stylized() {
         assert_eq!(
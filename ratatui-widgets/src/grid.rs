@@ -0,0 +1,245 @@
+//! The [`Grid`] widget draws a grid of intersecting lines.
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::symbols::line::{self, Connections};
+use ratatui_core::widgets::Widget;
+
+/// A widget to draw a grid of horizontal and vertical lines (e.g. the borders of a spreadsheet).
+///
+/// The grid is defined by the positions of its horizontal lines (rows) and vertical lines
+/// (columns), relative to the area the grid is rendered in. Horizontal lines span from the first
+/// to the last column and vertical lines span from the first to the last row. When there are no
+/// columns (resp. rows), the horizontal (resp. vertical) lines span the whole area.
+///
+/// Each cell of a line is drawn with the symbol of the [`line::Set`] connecting it to its
+/// neighbors, so that lines meet with the correct junction symbols (`┌ ┬ ┼ ├ ┤ ┴ ┘` etc.).
+/// Positions outside of the area are ignored.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::Grid;
+///
+/// // ┌──┬──┐
+/// // │  │  │
+/// // ├──┼──┤
+/// // │  │  │
+/// // └──┴──┘
+/// let grid = Grid::new([0, 2, 4], [0, 3, 6]);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Grid {
+    rows: Vec<u16>,
+    columns: Vec<u16>,
+    line_set: line::Set,
+    style: Style,
+}
+
+impl Grid {
+    /// Creates a new `Grid` with horizontal lines at the given `rows` and vertical lines at the
+    /// given `columns`.
+    ///
+    /// The positions are relative to the top left corner of the area the grid is rendered in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Grid;
+    ///
+    /// let grid = Grid::new([0, 2, 4], [0, 10, 20, 30]);
+    /// ```
+    pub fn new<R, C>(rows: R, columns: C) -> Self
+    where
+        R: IntoIterator<Item = u16>,
+        C: IntoIterator<Item = u16>,
+    {
+        Self {
+            rows: rows.into_iter().collect(),
+            columns: columns.into_iter().collect(),
+            line_set: line::NORMAL,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the symbols used to draw the lines.
+    ///
+    /// Defaults to [`line::NORMAL`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::symbols::line;
+    /// use ratatui::widgets::Grid;
+    ///
+    /// let grid = Grid::new([0, 2], [0, 4]).line_set(line::DOUBLE);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_set(mut self, line_set: line::Set) -> Self {
+        self.line_set = line_set;
+        self
+    }
+
+    /// Sets the style of the lines.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// Only the cells of the lines are styled, the cells between the lines are left untouched.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the neighbors connected to the cell at the given position of the grid
+    ///
+    /// `first` and `last` are the positions of the first and last rows and columns.
+    fn connections(&self, x: u16, y: u16, first: (u16, u16), last: (u16, u16)) -> Connections {
+        let mut connections = Connections::empty();
+        let x_range = first.0..=last.0;
+        let y_range = first.1..=last.1;
+        if self.rows.contains(&y) && x_range.contains(&x) {
+            connections.set(Connections::LEFT, x > first.0);
+            connections.set(Connections::RIGHT, x < last.0);
+        }
+        if self.columns.contains(&x) && y_range.contains(&y) {
+            connections.set(Connections::UP, y > first.1);
+            connections.set(Connections::DOWN, y < last.1);
+        }
+        connections
+    }
+}
+
+impl Widget for Grid {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Grid {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (width, height) = (area.width, area.height);
+        let visible = |positions: &[u16], size: u16| {
+            let visible = positions.iter().filter(|&&position| position < size);
+            let first = visible.clone().min().copied();
+            (first, visible.max().copied())
+        };
+        let (first_column, last_column) = visible(&self.columns, width);
+        let (first_row, last_row) = visible(&self.rows, height);
+        let first = (first_column.unwrap_or(0), first_row.unwrap_or(0));
+        let last = (
+            last_column.unwrap_or(width - 1),
+            last_row.unwrap_or(height - 1),
+        );
+        for y in 0..height {
+            for x in 0..width {
+                let connections = self.connections(x, y, first, last);
+                if let Some(symbol) = self.line_set.connecting(connections) {
+                    buf[(area.x + x, area.y + y)]
+                        .set_symbol(symbol)
+                        .set_style(self.style);
+                }
+            }
+        }
+    }
+}
+
+impl Styled for Grid {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::Stylize;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[track_caller]
+    fn render(grid: &Grid, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        grid.render(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn render_grid() {
+        let grid = Grid::new([0, 2, 4], [0, 3, 6]);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┬──┐",
+            "│  │  │",
+            "├──┼──┤",
+            "│  │  │",
+            "└──┴──┘",
+        ]);
+        assert_eq!(render(&grid, 7, 5), expected);
+    }
+
+    #[rstest]
+    #[case::cross((3, 2), "┼")]
+    #[case::top_junction((3, 0), "┬")]
+    #[case::bottom_junction((3, 4), "┴")]
+    #[case::left_junction((0, 2), "├")]
+    #[case::right_junction((6, 2), "┤")]
+    #[case::corner((6, 4), "┘")]
+    fn junction_symbol(#[case] position: (u16, u16), #[case] expected: &str) {
+        let buf = render(&Grid::new([0, 2, 4], [0, 3, 6]), 7, 5);
+        assert_eq!(buf[position].symbol(), expected);
+    }
+
+    #[test]
+    fn render_lines_without_columns_span_the_area() {
+        let grid = Grid::new([1], []);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "    ",
+            "────",
+            "    ",
+        ]);
+        assert_eq!(render(&grid, 4, 3), expected);
+    }
+
+    #[test]
+    fn render_ignores_positions_outside_of_area() {
+        let grid = Grid::new([0, 2, 10], [0, 3, 10]);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┐",
+            "│  │",
+            "└──┘",
+        ]);
+        assert_eq!(render(&grid, 4, 3), expected);
+    }
+
+    #[test]
+    fn render_with_line_set_and_style() {
+        let grid = Grid::new([0, 1], [0, 2]).line_set(line::DOUBLE).red();
+        let mut expected = Buffer::with_lines(["╔═╗", "╚═╝"]);
+        expected.set_style(expected.area, Style::new().red());
+        assert_eq!(render(&grid, 3, 2), expected);
+    }
+
+    #[test]
+    fn render_empty_grid() {
+        let buf = render(&Grid::default(), 3, 2);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 3, 2)));
+    }
+}
@@ -40,6 +40,7 @@
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`Grid`]: draws a grid of intersecting lines.
 //! - [`Gauge`]: displays progress percentage using block characters.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
@@ -47,9 +48,12 @@
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`ScrollView`]: displays a scrollable view over content larger than its area.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
+//! - [`Spinner`]: displays an animated spinner with an optional label.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Tree`]: displays a tree of collapsible items and allows selection.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
@@ -57,6 +61,7 @@
 //! [`Canvas`]: crate::canvas::Canvas
 //! [`Chart`]: crate::chart::Chart
 //! [`Clear`]: crate::clear::Clear
+//! [`Grid`]: crate::grid::Grid
 //! [`Gauge`]: crate::gauge::Gauge
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
@@ -64,9 +69,12 @@
 //! [`RatatuiMascot`]: crate::mascot::RatatuiMascot
 //! [`Paragraph`]: crate::paragraph::Paragraph
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
+//! [`ScrollView`]: crate::scroll_view::ScrollView
 //! [`Sparkline`]: crate::sparkline::Sparkline
+//! [`Spinner`]: crate::spinner::Spinner
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`Tree`]: crate::tree::Tree
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
@@ -94,14 +102,18 @@ pub mod canvas;
 pub mod chart;
 pub mod clear;
 pub mod gauge;
+pub mod grid;
 pub mod list;
 pub mod logo;
 pub mod mascot;
 pub mod paragraph;
+pub mod scroll_view;
 pub mod scrollbar;
 pub mod sparkline;
+pub mod spinner;
 pub mod table;
 pub mod tabs;
+pub mod tree;
 
 mod reflow;
 
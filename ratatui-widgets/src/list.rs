@@ -1,6 +1,7 @@
 //! The [`List`] widget is used to display a list of items and allows selecting one or multiple
 //! items.
 
+use alloc::string::String;
 use alloc::vec::Vec;
 
 use ratatui_core::style::{Style, Styled};
@@ -39,10 +40,13 @@ mod state;
 /// # Fluent setters
 ///
 /// - [`List::highlight_style`] sets the style of the selected item.
+/// - [`List::multi_highlight_style`] sets the style of the items in the multi-selection.
 /// - [`List::highlight_symbol`] sets the symbol to be displayed in front of the selected item.
+/// - [`List::unselected_symbol`] sets the symbol to be displayed in front of the other items.
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
 /// - [`List::direction`] sets the list direction
+/// - [`List::highlight_matches`] sets a pattern and style to highlight matching substrings
 ///
 /// # Examples
 ///
@@ -117,14 +121,26 @@ pub struct List<'a> {
     pub(crate) direction: ListDirection,
     /// Style used to render selected item
     pub(crate) highlight_style: Style,
+    /// Style used to render the items in the multi-selection
+    pub(crate) multi_highlight_style: Style,
     /// Symbol in front of the selected item (Shift all items to the right)
     pub(crate) highlight_symbol: Option<Line<'a>>,
+    /// Symbol in front of the items that are not selected and don't have their own marker
+    pub(crate) unselected_symbol: Option<Line<'a>>,
     /// Whether to repeat the highlight symbol for each line of the selected item
     pub(crate) repeat_highlight_symbol: bool,
     /// Decides when to allocate spacing for the selection symbol
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     pub(crate) scroll_padding: usize,
+    /// Whether to keep the selected item in the middle of the list
+    pub(crate) center_selection: bool,
+    /// Substring to highlight in every item
+    pub(crate) match_pattern: Option<String>,
+    /// Style used to render the substrings matching `match_pattern`
+    pub(crate) match_style: Style,
+    /// Whether `match_pattern` is matched case-insensitively
+    pub(crate) match_ignore_case: bool,
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -302,6 +318,34 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the symbol to be displayed in front of the items that are not selected
+    ///
+    /// By default there are no unselected symbol. An item can override this symbol with its own
+    /// [marker](ListItem::marker).
+    ///
+    /// The symbols are displayed in a column as wide as the widest of the highlight symbol, the
+    /// unselected symbol and the item markers, so the items stay aligned. This column is always
+    /// allocated when a marker is set, unless the [highlight spacing](List::highlight_spacing) is
+    /// [`HighlightSpacing::Never`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .highlight_symbol("> ")
+    ///     .unselected_symbol("• ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unselected_symbol<L: Into<Line<'a>>>(mut self, unselected_symbol: L) -> Self {
+        self.unselected_symbol = Some(unselected_symbol.into());
+        self
+    }
+
     /// Set the style of the selected item
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -330,6 +374,36 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the style of the items in the multi-selection
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style is applied to every item whose index is in [`ListState::selected_indices`]. It
+    /// is applied before the [highlight style](List::highlight_style), so the item under the
+    /// cursor can still be distinguished when it is also part of the multi-selection.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .highlight_style(Style::new().reversed())
+    ///     .multi_highlight_style(Style::new().green());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn multi_highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.multi_highlight_style = style.into();
+        self
+    }
+
     /// Set whether to repeat the highlight symbol and style over selected multi-line items
     ///
     /// This is `false` by default.
@@ -416,6 +490,92 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Sets whether the selected item is kept vertically centered in the list
+    ///
+    /// When enabled, the [offset](crate::list::ListState::offset) is calculated on each render so
+    /// that the selected item is displayed in the middle of the list, which scrolls the items
+    /// around a fixed selection row (like a wheel picker). Near the first and last items the
+    /// offset is clamped, so the list never scrolls past its ends and the selection moves away
+    /// from the center instead.
+    ///
+    /// When no item is selected, the offset of the [`ListState`] is used as is.
+    ///
+    /// This is `false` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2", "Item 3"];
+    /// let list = List::new(items).center_selection(true);
+    /// ```
+    ///
+    /// [`ListState`]: crate::list::ListState
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn center_selection(mut self, center_selection: bool) -> Self {
+        self.center_selection = center_selection;
+        self
+    }
+
+    /// Highlights the substrings of each item that match the given pattern
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// Every occurrence of `pattern` within a line of an item is rendered with `style` patched on
+    /// top of the style of the spans it covers. Matches do not span across lines. The
+    /// [highlight style](List::highlight_style) of the selected item is applied on top of the
+    /// match style, so both can be combined (e.g. a bold match in a reversed selected row).
+    ///
+    /// Matching is case-sensitive by default, see [`List::ignore_match_case`]. An empty pattern
+    /// does not highlight anything.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["apple", "banana", "pineapple"];
+    /// let list = List::new(items).highlight_matches("apple", Style::new().yellow().bold());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_matches<S: Into<Style>>(mut self, pattern: &str, style: S) -> Self {
+        self.match_pattern = Some(pattern.into());
+        self.match_style = style.into();
+        self
+    }
+
+    /// Set whether the pattern given to [`List::highlight_matches`] ignores case
+    ///
+    /// This is `false` by default.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Apple", "banana", "Pineapple"];
+    /// let list = List::new(items)
+    ///     .highlight_matches("APPLE", Style::new().yellow())
+    ///     .ignore_match_case(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ignore_match_case(mut self, ignore_case: bool) -> Self {
+        self.match_ignore_case = ignore_case;
+        self
+    }
+
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
         self.items.len()
@@ -1,5 +1,5 @@
 use ratatui_core::style::Style;
-use ratatui_core::text::Text;
+use ratatui_core::text::{Line, Text};
 
 /// A single item in a [`List`]
 ///
@@ -73,6 +73,7 @@ use ratatui_core::text::Text;
 pub struct ListItem<'a> {
     pub(crate) content: Text<'a>,
     pub(crate) style: Style,
+    pub(crate) marker: Option<Line<'a>>,
 }
 
 impl<'a> ListItem<'a> {
@@ -119,6 +120,7 @@ impl<'a> ListItem<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            marker: None,
         }
     }
 
@@ -160,6 +162,32 @@ impl<'a> ListItem<'a> {
         self
     }
 
+    /// Sets the marker displayed in front of the item
+    ///
+    /// The marker replaces the list's [unselected symbol](super::List::unselected_symbol) for this
+    /// item, which is useful to display e.g. a checkbox. It is displayed on the first line of the
+    /// item, and is replaced by the [highlight symbol](super::List::highlight_symbol) when the item
+    /// is selected.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem};
+    ///
+    /// let list = List::new([
+    ///     ListItem::new("Done").marker("[x] "),
+    ///     ListItem::new("Todo").marker("[ ] "),
+    /// ])
+    /// .highlight_symbol(">>> ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn marker<L: Into<Line<'a>>>(mut self, marker: L) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+
     /// Returns the item height
     ///
     /// # Examples
@@ -324,6 +352,16 @@ mod tests {
         assert_eq!(item.style, Style::default().bg(Color::Red));
     }
 
+    #[test]
+    fn marker() {
+        let item = ListItem::new("Test item");
+        assert_eq!(item.marker, None);
+
+        let item = item.marker("[x] ");
+        assert_eq!(item.content, Text::from("Test item"));
+        assert_eq!(item.marker, Some(Line::from("[x] ")));
+    }
+
     #[test]
     fn height() {
         let item = ListItem::new("Test item");
@@ -1,10 +1,17 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use ratatui_core::accessibility::{Annotation, Role};
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::Rect;
-use ratatui_core::text::{Line, ToLine};
+use ratatui_core::style::Style;
+use ratatui_core::text::{Line, Span, Text, ToLine};
 use ratatui_core::widgets::{StatefulWidget, Widget};
 
 use crate::block::BlockExt;
-use crate::list::{List, ListDirection, ListState};
+use crate::list::{List, ListDirection, ListItem, ListState};
 
 impl Widget for List<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
@@ -39,6 +46,10 @@ impl StatefulWidget for &List<'_> {
             return;
         }
 
+        if let Some(tree) = buf.accessibility_tree_mut() {
+            tree.push(Annotation::new(Role::List, String::new(), list_area));
+        }
+
         if self.items.is_empty() {
             state.select(None);
             return;
@@ -57,18 +68,14 @@ impl StatefulWidget for &List<'_> {
         // Important: this changes the state's offset to be the beginning of the now viewable items
         state.offset = first_visible_index;
 
-        // Get our set highlighted symbol (if one was set)
-        let default_highlight_symbol = Line::default();
-        let highlight_symbol = self
-            .highlight_symbol
-            .as_ref()
-            .unwrap_or(&default_highlight_symbol);
-        let highlight_symbol_width = highlight_symbol.width() as u16;
+        let highlight_symbol_width = self.symbols_width();
         let empty_symbol = " ".repeat(highlight_symbol_width as usize);
         let empty_symbol = empty_symbol.to_line();
 
         let mut current_height = 0;
-        let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
+        let selection_spacing = self
+            .highlight_spacing
+            .should_add(state.selected.is_some() || self.has_markers());
         for (i, item) in self
             .items
             .iter()
@@ -92,6 +99,13 @@ impl StatefulWidget for &List<'_> {
 
             let is_selected = state.selected == Some(i);
 
+            if let Some(tree) = buf.accessibility_tree_mut() {
+                let role = Role::ListItem {
+                    selected: is_selected,
+                };
+                tree.push(Annotation::new(role, item.content.to_string(), row_area));
+            }
+
             let item_area = if selection_spacing {
                 Rect {
                     x: row_area.x + highlight_symbol_width,
@@ -101,20 +115,36 @@ impl StatefulWidget for &List<'_> {
             } else {
                 row_area
             };
-            Widget::render(&item.content, item_area, buf);
+            match self.match_pattern.as_deref() {
+                Some(pattern) if !pattern.is_empty() => {
+                    let content = highlight_text_matches(
+                        &item.content,
+                        pattern,
+                        self.match_style,
+                        self.match_ignore_case,
+                    );
+                    Widget::render(&content, item_area, buf);
+                }
+                _ => Widget::render(&item.content, item_area, buf),
+            }
 
+            if state.selected_indices.contains(&i) {
+                buf.set_style(row_area, self.multi_highlight_style);
+            }
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
             }
             if selection_spacing {
+                let symbol = self.item_symbol(item, is_selected);
                 for j in 0..item.content.height() {
-                    // if the item is selected, we need to display the highlight symbol:
+                    // the symbol is displayed:
                     // - either for the first line of the item only,
-                    // - or for each line of the item if the appropriate option is set
-                    let line = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
-                        highlight_symbol
-                    } else {
-                        &empty_symbol
+                    // - or for each line of the selected item if the appropriate option is set
+                    let line = match symbol {
+                        Some(symbol) if j == 0 || (is_selected && self.repeat_highlight_symbol) => {
+                            symbol
+                        }
+                        _ => &empty_symbol,
                     };
                     let highlight_area = Rect::new(x, y + j as u16, highlight_symbol_width, 1);
                     line.render(highlight_area, buf);
@@ -125,6 +155,38 @@ impl StatefulWidget for &List<'_> {
 }
 
 impl List<'_> {
+    /// Returns whether a symbol is displayed in front of the items that are not selected
+    fn has_markers(&self) -> bool {
+        self.unselected_symbol.is_some() || self.items.iter().any(|item| item.marker.is_some())
+    }
+
+    /// Returns the width of the column in front of the items
+    ///
+    /// The column fits all of the symbols, so that the items stay aligned whichever symbol is
+    /// displayed in front of them.
+    fn symbols_width(&self) -> u16 {
+        let markers = self.items.iter().filter_map(|item| item.marker.as_ref());
+        self.highlight_symbol
+            .iter()
+            .chain(&self.unselected_symbol)
+            .chain(markers)
+            .map(Line::width)
+            .max()
+            .unwrap_or_default() as u16
+    }
+
+    /// Returns the symbol displayed in front of an item
+    ///
+    /// This is the highlight symbol if the item is selected, otherwise the item's marker or the
+    /// unselected symbol.
+    fn item_symbol<'s>(&'s self, item: &'s ListItem, is_selected: bool) -> Option<&'s Line<'s>> {
+        if is_selected {
+            self.highlight_symbol.as_ref()
+        } else {
+            item.marker.as_ref().or(self.unselected_symbol.as_ref())
+        }
+    }
+
     /// Given an offset, calculate which items can fit in a given area
     fn get_items_bounds(
         &self,
@@ -132,6 +194,10 @@ impl List<'_> {
         offset: usize,
         max_height: usize,
     ) -> (usize, usize) {
+        let offset = match selected {
+            Some(selected) if self.center_selection => self.centered_offset(selected, max_height),
+            _ => offset,
+        };
         let offset = offset.min(self.items.len().saturating_sub(1));
 
         // Note: visible here implies visible in the given area
@@ -206,6 +272,31 @@ impl List<'_> {
         (first_visible_index, last_visible_index)
     }
 
+    /// Calculates the offset which displays the selected item in the middle of the given height
+    ///
+    /// The offset is clamped so that the list does not scroll past its first or last item.
+    fn centered_offset(&self, selected: usize, max_height: usize) -> usize {
+        let Some(selected_item) = self.items.get(selected) else {
+            return selected;
+        };
+
+        // fill up to half of the remaining space with the items before the selected one
+        let mut height_above = max_height.saturating_sub(selected_item.height()) / 2;
+        let mut offset = selected;
+        while offset > 0 && self.items[offset - 1].height() <= height_above {
+            offset -= 1;
+            height_above -= self.items[offset].height();
+        }
+
+        // don't leave empty space after the last item if there are more items before the offset
+        let mut height_from_offset: usize = self.items[offset..].iter().map(ListItem::height).sum();
+        while offset > 0 && height_from_offset + self.items[offset - 1].height() <= max_height {
+            offset -= 1;
+            height_from_offset += self.items[offset].height();
+        }
+        offset
+    }
+
     /// Applies scroll padding to the selected index, reducing the padding value to keep the
     /// selected item on screen even with items of inconsistent sizes
     ///
@@ -253,11 +344,128 @@ impl List<'_> {
     }
 }
 
+/// Returns a copy of `text` where the substrings matching `pattern` are patched with `style`
+fn highlight_text_matches<'a>(
+    text: &Text<'a>,
+    pattern: &str,
+    style: Style,
+    ignore_case: bool,
+) -> Text<'a> {
+    let lines = text
+        .lines
+        .iter()
+        .map(|line| highlight_line_matches(line, pattern, style, ignore_case))
+        .collect();
+    Text {
+        lines,
+        ..text.clone()
+    }
+}
+
+/// Returns a copy of `line` where the substrings matching `pattern` are patched with `style`
+///
+/// Spans are split at the boundaries of each match, so a match may cover several spans.
+#[expect(clippy::string_slice)] // Is safe as the match ranges are on char boundaries
+fn highlight_line_matches<'a>(
+    line: &Line<'a>,
+    pattern: &str,
+    style: Style,
+    ignore_case: bool,
+) -> Line<'a> {
+    let content: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+    let matches = match_ranges(&content, pattern, ignore_case);
+    if matches.is_empty() {
+        return line.clone();
+    }
+    let mut spans = Vec::with_capacity(line.spans.len() + matches.len() * 2);
+    let mut span_start = 0;
+    for span in &line.spans {
+        let span_content = span.content.as_ref();
+        let span_end = span_start + span_content.len();
+        let mut overlapping = matches
+            .iter()
+            .filter(|range| range.start < span_end && range.end > span_start)
+            .peekable();
+        if overlapping.peek().is_none() {
+            spans.push(span.clone());
+            span_start = span_end;
+            continue;
+        }
+        let mut cursor = span_start;
+        for range in overlapping {
+            let start = range.start.max(span_start);
+            let end = range.end.min(span_end);
+            if cursor < start {
+                let unmatched = &span_content[cursor - span_start..start - span_start];
+                spans.push(Span::styled(unmatched.to_string(), span.style));
+            }
+            let highlighted = &span_content[start - span_start..end - span_start];
+            spans.push(Span::styled(
+                highlighted.to_string(),
+                span.style.patch(style),
+            ));
+            cursor = end;
+        }
+        if cursor < span_end {
+            let unmatched = &span_content[cursor - span_start..];
+            spans.push(Span::styled(unmatched.to_string(), span.style));
+        }
+        span_start = span_end;
+    }
+    Line {
+        spans,
+        ..line.clone()
+    }
+}
+
+/// Returns the non-overlapping byte ranges of `haystack` that match `pattern`
+fn match_ranges(haystack: &str, pattern: &str, ignore_case: bool) -> Vec<Range<usize>> {
+    // each (possibly lowercased) char is paired with the byte range of the char it came from, so
+    // that matches can be mapped back to the original string
+    let fold = |(index, c): (usize, char)| {
+        let range = index..index + c.len_utf8();
+        let lowercase = ignore_case.then(|| c.to_lowercase());
+        let chars: Vec<char> = lowercase.map_or_else(|| vec![c], Iterator::collect);
+        chars.into_iter().map(move |c| (c, range.clone()))
+    };
+    let haystack: Vec<(char, Range<usize>)> = haystack.char_indices().flat_map(fold).collect();
+    let needle: Vec<char> = pattern
+        .char_indices()
+        .flat_map(fold)
+        .map(|(c, _)| c)
+        .collect();
+
+    let mut ranges = Vec::new();
+    if needle.is_empty() {
+        return ranges;
+    }
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        let candidate = &haystack[start..start + needle.len()];
+        if candidate.iter().map(|(c, _)| c).eq(needle.iter()) {
+            let first = &candidate[0].1;
+            let last = &candidate[needle.len() - 1].1;
+            // a lowercased char may expand to several chars, don't start a match in the middle of
+            // the original char that a previous match ended in
+            if !ranges
+                .last()
+                .is_some_and(|prev: &Range<usize>| prev.end > first.start)
+            {
+                ranges.push(first.start..last.end);
+            }
+            start += needle.len();
+        } else {
+            start += 1;
+        }
+    }
+    ranges
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::borrow::ToOwned;
-    use alloc::vec;
     use alloc::vec::Vec;
+    use alloc::{format, vec};
 
     use pretty_assertions::assert_eq;
     use ratatui_core::layout::{Alignment, Rect};
@@ -818,6 +1026,56 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn unselected_symbol() {
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .highlight_symbol(">>")
+            .unselected_symbol("•");
+        let mut state = ListState::default();
+        let buffer = stateful_widget(list.clone(), &mut state, 10, 3);
+        let expected = Buffer::with_lines(["• Item 0  ", "• Item 1  ", "• Item 2  "]);
+        assert_eq!(buffer, expected);
+
+        state.select(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines(["• Item 0  ", ">>Item 1  ", "• Item 2  "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn item_markers() {
+        let list = List::new([
+            ListItem::new("Item 0").marker("[x] "),
+            ListItem::new("Item 1"),
+            ListItem::new("Item 2\nLine 2").marker("[ ] "),
+            ListItem::new("Item 3").marker("[x] "),
+        ])
+        .highlight_symbol(">")
+        .unselected_symbol("-");
+        let mut state = ListState::default();
+        state.select(Some(3));
+        let buffer = stateful_widget(list, &mut state, 12, 5);
+        let expected = Buffer::with_lines([
+            "[x] Item 0  ",
+            "-   Item 1  ",
+            "[ ] Item 2  ",
+            "    Line 2  ",
+            ">   Item 3  ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn item_markers_highlight_spacing_never() {
+        let list = List::new([ListItem::new("Item 0").marker("[x] "), "Item 1".into()])
+            .unselected_symbol("-")
+            .highlight_spacing(HighlightSpacing::Never);
+        let mut state = ListState::default();
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        let expected = Buffer::with_lines(["Item 0    ", "Item 1    "]);
+        assert_eq!(buffer, expected);
+    }
+
     #[rstest]
     #[case::top_to_bottom(ListDirection::TopToBottom, [
         "Item 0    ",
@@ -1249,6 +1507,88 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::first(0, 0)]
+    #[case::near_top(2, 0)]
+    #[case::first_centered(3, 1)]
+    #[case::middle(5, 3)]
+    #[case::last_centered(6, 4)]
+    #[case::near_bottom(8, 5)]
+    #[case::last(9, 5)]
+    fn center_selection_offset(#[case] selected: usize, #[case] expected_offset: usize) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let items = (0..10).map(|i| format!("Item {i}"));
+        let list = List::new(items).center_selection(true);
+        let mut state = ListState::default().with_selected(Some(selected));
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), expected_offset);
+    }
+
+    #[test]
+    fn center_selection_renders_selected_in_middle() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let items = (0..10).map(|i| format!("Item {i}"));
+        let list = List::new(items)
+            .center_selection(true)
+            .highlight_symbol(">> ");
+        // the offset is ignored when an item is selected
+        let mut state = ListState::default().with_offset(7).with_selected(Some(4));
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "   Item 2 ",
+                "   Item 3 ",
+                ">> Item 4 ",
+                "   Item 5 ",
+                "   Item 6 ",
+            ])
+        );
+    }
+
+    #[test]
+    fn center_selection_with_multiline_items() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let items = [
+            ListItem::new("Item 0"),
+            ListItem::new(
+                "Item 1
+Test",
+            ),
+            ListItem::new("Item 2"),
+            ListItem::new("Item 3"),
+            ListItem::new(
+                "Item 4
+Test",
+            ),
+            ListItem::new("Item 5"),
+        ];
+        let list = List::new(items).center_selection(true);
+        let mut state = ListState::default().with_selected(Some(2));
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 1);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "Item 1    ",
+                "Test      ",
+                "Item 2    ",
+                "Item 3    ",
+                "          "
+            ])
+        );
+    }
+
+    #[test]
+    fn center_selection_without_selection() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        let items = (0..10).map(|i| format!("Item {i}"));
+        let list = List::new(items).center_selection(true);
+        let mut state = ListState::default().with_offset(4);
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 4);
+    }
+
     /// Regression test for a bug where highlight symbol being greater than width caused a panic due
     /// to subtraction with underflow.
     ///
@@ -1269,4 +1609,157 @@ mod tests {
         StatefulWidget::render(list, single_line_buf.area, &mut single_line_buf, &mut state);
         assert_eq!(single_line_buf, Buffer::with_lines([expected]));
     }
+
+    #[test]
+    fn highlight_matches() {
+        let list = List::new(["apple", "banana", "pineapple"])
+            .highlight_matches("apple", Style::new().yellow());
+        let buffer = widget(list, 9, 3);
+        let mut expected = Buffer::with_lines(["apple", "banana", "pineapple"]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().yellow());
+        expected.set_style(Rect::new(4, 2, 5, 1), Style::new().yellow());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_matches_multiple_per_line() {
+        let list = List::new(["abcabab"]).highlight_matches("ab", Style::new().bold());
+        let buffer = widget(list, 7, 1);
+        let mut expected = Buffer::with_lines(["abcabab"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bold());
+        expected.set_style(Rect::new(3, 0, 4, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_matches_across_spans() {
+        let item = Line::from(vec!["fo".red(), "ob".blue(), "ar".into()]);
+        let list = List::new([item]).highlight_matches("oba", Style::new().bold());
+        let buffer = widget(list, 6, 1);
+        let expected = Buffer::with_lines([Line::from(vec![
+            "fo".red(),
+            "ob".blue().bold(),
+            "a".bold(),
+            "r".into(),
+        ])]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::case_sensitive(false, [false, false, true])]
+    #[case::ignore_case(true, [true, true, true])]
+    fn highlight_matches_case(#[case] ignore_case: bool, #[case] matched: [bool; 3]) {
+        let list = List::new(["FOO", "Foo", "foo"])
+            .highlight_matches("foo", Style::new().yellow())
+            .ignore_match_case(ignore_case);
+        let buffer = widget(list, 3, 3);
+        let mut expected = Buffer::with_lines(["FOO", "Foo", "foo"]);
+        for (y, _) in matched.iter().enumerate().filter(|(_, m)| **m) {
+            expected.set_style(Rect::new(0, y as u16, 3, 1), Style::new().yellow());
+        }
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_matches_with_selection() {
+        let list = List::new(["Item 0", "Item 1"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().on_blue())
+            .highlight_matches("tem", Style::new().bold());
+        let mut state = ListState::default().with_selected(Some(1));
+        let buffer = stateful_widget(list, &mut state, 10, 2);
+        let mut expected = Buffer::with_lines(["  Item 0  ".into(), ">>Item 1  ".on_blue()]);
+        expected.set_style(Rect::new(3, 0, 3, 1), Style::new().bold());
+        expected.set_style(Rect::new(3, 1, 3, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn highlight_matches_empty_pattern() {
+        let list = List::new(["Item 0"]).highlight_matches("", Style::new().bold());
+        let buffer = widget(list, 6, 1);
+        assert_eq!(buffer, Buffer::with_lines(["Item 0"]));
+    }
+
+    #[rstest]
+    #[case::none("hello", "x", false, vec![])]
+    #[case::single("hello", "ll", false, vec![2..4])]
+    #[case::non_overlapping("aaaa", "aa", false, vec![0..2, 2..4])]
+    #[case::ignore_case("HeLLo", "ll", true, vec![2..4])]
+    #[case::multi_byte("über Über", "über", true, vec![0..5, 6..11])]
+    fn match_ranges(
+        #[case] haystack: &str,
+        #[case] pattern: &str,
+        #[case] ignore_case: bool,
+        #[case] expected: Vec<Range<usize>>,
+    ) {
+        assert_eq!(
+            super::match_ranges(haystack, pattern, ignore_case),
+            expected
+        );
+    }
+
+    #[test]
+    fn multi_highlight_style() {
+        let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().reversed())
+            .multi_highlight_style(Style::new().green());
+        let mut state = ListState::default().with_selected(Some(2));
+        state.toggle_selected(0);
+        state.toggle_selected(2);
+        let buffer = stateful_widget(list, &mut state, 10, 4);
+        let expected = Buffer::with_lines([
+            "  Item 0  ".green(),
+            "  Item 1  ".into(),
+            ">>Item 2  ".green().reversed(),
+            "  Item 3  ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn multi_highlight_style_cleared() {
+        let list = List::new(["Item 0", "Item 1"]).multi_highlight_style(Style::new().green());
+        let mut state = ListState::default();
+        state.toggle_selected(0);
+        state.toggle_selected(1);
+        state.clear_selected_indices();
+        let buffer = stateful_widget(list, &mut state, 6, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 0", "Item 1"]));
+    }
+
+    #[test]
+    fn accessibility_tree_contains_list_items() {
+        let list = List::new(["Item 0", "Item 1", "Item 2"]).highlight_symbol(">>");
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        buffer.enable_accessibility_tree(true);
+        StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+        let tree = buffer.accessibility_tree().unwrap();
+        assert_eq!(
+            tree.annotations(),
+            [
+                Annotation::new(Role::List, "", Rect::new(0, 0, 10, 2)),
+                Annotation::new(
+                    Role::ListItem { selected: false },
+                    "Item 0",
+                    Rect::new(0, 0, 10, 1)
+                ),
+                Annotation::new(
+                    Role::ListItem { selected: true },
+                    "Item 1",
+                    Rect::new(0, 1, 10, 1)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn accessibility_tree_disabled_by_default() {
+        let list = List::new(["Item 0"]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        Widget::render(list, buffer.area, &mut buffer);
+        assert_eq!(buffer.accessibility_tree(), None);
+    }
 }
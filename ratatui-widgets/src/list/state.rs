@@ -1,3 +1,5 @@
+use alloc::collections::BTreeSet;
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -5,12 +7,19 @@
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of three fields:
 /// - [`offset`]: the index of the first item to be displayed
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`selected_indices`]: the indices of the items that are part of a multi-selection
+///
+/// The [`selected`] item acts as a cursor used for navigation, while the [`selected_indices`] can
+/// be toggled independently of it (e.g. with the space key in a file picker). Items in the
+/// multi-selection are rendered with [`List::multi_highlight_style`].
 ///
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
+/// [`selected_indices`]: ListState::selected_indices()
+/// [`List::multi_highlight_style`]: super::List::multi_highlight_style
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -45,6 +54,11 @@
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeSet::is_empty")
+    )]
+    pub(crate) selected_indices: BTreeSet<usize>,
 }
 
 impl ListState {
@@ -231,6 +245,87 @@ impl ListState {
         self.select(Some(usize::MAX));
     }
 
+    /// Indices of the items that are part of the multi-selection
+    ///
+    /// This is independent of the [`selected`](ListState::selected) item, which is used as a
+    /// cursor for navigation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle_selected(1);
+    /// state.toggle_selected(3);
+    /// assert_eq!(
+    ///     state.selected_indices().iter().collect::<Vec<_>>(),
+    ///     [&1, &3]
+    /// );
+    /// ```
+    pub const fn selected_indices(&self) -> &BTreeSet<usize> {
+        &self.selected_indices
+    }
+
+    /// Toggles whether the item at `index` is part of the multi-selection
+    ///
+    /// The item is added to the multi-selection if it was not already part of it, and removed
+    /// otherwise. This does not change the [`selected`](ListState::selected) item.
+    ///
+    /// Note: until the list is rendered, the number of items is not known, so indices out of
+    /// bounds are kept and simply never rendered as highlighted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle_selected(2);
+    /// assert!(state.is_index_selected(2));
+    /// state.toggle_selected(2);
+    /// assert!(!state.is_index_selected(2));
+    /// ```
+    pub fn toggle_selected(&mut self, index: usize) {
+        if !self.selected_indices.remove(&index) {
+            self.selected_indices.insert(index);
+        }
+    }
+
+    /// Returns whether the item at `index` is part of the multi-selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle_selected(0);
+    /// assert!(state.is_index_selected(0));
+    /// assert!(!state.is_index_selected(1));
+    /// ```
+    pub fn is_index_selected(&self, index: usize) -> bool {
+        self.selected_indices.contains(&index)
+    }
+
+    /// Removes all the items from the multi-selection
+    ///
+    /// This does not change the [`selected`](ListState::selected) item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default();
+    /// state.toggle_selected(0);
+    /// state.clear_selected_indices();
+    /// assert!(state.selected_indices().is_empty());
+    /// ```
+    pub fn clear_selected_indices(&mut self) {
+        self.selected_indices.clear();
+    }
+
     /// Scrolls down by a specified `amount` in the list.
     ///
     /// This method updates the selected index by moving it down by the given `amount`.
@@ -272,6 +367,8 @@ impl ListState {
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::BTreeSet;
+
     use pretty_assertions::assert_eq;
 
     use crate::list::ListState;
@@ -354,4 +451,33 @@ mod tests {
         state.scroll_up_by(4);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[test]
+    fn toggle_selected() {
+        let mut state = ListState::default();
+        assert!(state.selected_indices().is_empty());
+
+        state.toggle_selected(1);
+        state.toggle_selected(3);
+        assert_eq!(state.selected_indices(), &BTreeSet::from([1, 3]));
+        assert!(state.is_index_selected(1));
+        assert!(!state.is_index_selected(2));
+
+        state.toggle_selected(1);
+        assert_eq!(state.selected_indices(), &BTreeSet::from([3]));
+
+        // the cursor is independent of the multi-selection
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn clear_selected_indices() {
+        let mut state = ListState::default().with_selected(Some(2));
+        state.toggle_selected(0);
+        state.toggle_selected(2);
+
+        state.clear_selected_indices();
+        assert!(state.selected_indices().is_empty());
+        assert_eq!(state.selected(), Some(2));
+    }
 }
@@ -1,10 +1,15 @@
 //! The [`Paragraph`] widget and related types allows displaying a block of text with optional
 //! wrapping, alignment, and block styling.
+use alloc::string::ToString;
+use core::iter;
+
+use ratatui_core::accessibility::{Annotation, Role};
 use ratatui_core::buffer::Buffer;
 use ratatui_core::layout::{Alignment, Position, Rect};
-use ratatui_core::style::{Style, Styled};
+use ratatui_core::style::{Style, Styled, Theme, ThemeRole};
 use ratatui_core::text::{Line, StyledGrapheme, Text};
-use ratatui_core::widgets::Widget;
+use ratatui_core::widgets::{StatefulWidget, Widget};
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 use crate::block::{Block, BlockExt};
@@ -25,8 +30,16 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 /// The text can be aligned to the left, right, or center. The alignment can be configured with the
 /// [`alignment`] method or with the [`left_aligned`], [`right_aligned`], and [`centered`] methods.
 ///
+/// The text is laid out from left to right by default. It can be laid out from right to left (e.g.
+/// for Arabic or Hebrew content) with the [`direction`] method.
+///
+/// Tabs are expanded to spaces up to the next tab stop. Tab stops are placed every 4 columns by
+/// default, which can be configured with the [`tab_width`] method.
+///
 /// The text can be scrolled to show a specific part of the text. The scroll offset can be set with
-/// the [`scroll`] method.
+/// the [`scroll`] method. Alternatively, a reference to the paragraph can be rendered as a
+/// [`StatefulWidget`] with a [`ParagraphState`] which keeps the vertical scroll offset between
+/// renders.
 ///
 /// The text can be surrounded by a [`Block`] with a title and borders. The block can be configured
 /// with the [`block`] method.
@@ -44,6 +57,8 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 /// [`left_aligned`]: Self::left_aligned
 /// [`right_aligned`]: Self::right_aligned
 /// [`centered`]: Self::centered
+/// [`direction`]: Self::direction
+/// [`tab_width`]: Self::tab_width
 /// [`scroll`]: Self::scroll
 /// [`block`]: Self::block
 /// [`style`]: Self::style
@@ -73,7 +88,7 @@ use crate::reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine};
 /// ```
 ///
 /// [`Span`]: ratatui_core::text::Span
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Paragraph<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -81,12 +96,46 @@ pub struct Paragraph<'a> {
     style: Style,
     /// How to wrap the text
     wrap: Option<Wrap>,
+    /// How to break the words too long to fit on a line when wrapping
+    word_break: WordBreak,
     /// The text to display
     text: Text<'a>,
     /// Scroll
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// Base direction of the text
+    direction: TextDirection,
+    /// Number of columns between tab stops
+    tab_width: u16,
+    /// Style of the rows continuing a wrapped line
+    continuation_style: Option<Style>,
+    /// Whether line numbers are rendered in a gutter on the left of the text
+    line_numbers: bool,
+    /// Number of the first line
+    line_number_start: usize,
+    /// Style of the line numbers
+    line_number_style: Style,
+}
+
+impl Default for Paragraph<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            wrap: None,
+            word_break: WordBreak::Hard,
+            text: Text::default(),
+            scroll: Position::ORIGIN,
+            alignment: Alignment::Left,
+            direction: TextDirection::LeftToRight,
+            tab_width: 4,
+            continuation_style: None,
+            line_numbers: false,
+            line_number_start: 1,
+            line_number_style: Style::new(),
+        }
+    }
 }
 
 /// Describes how to wrap text across lines.
@@ -125,9 +174,150 @@ pub struct Wrap {
     pub trim: bool,
 }
 
+/// Describes how a [`Paragraph`] breaks words which are too long to fit on a line when wrapping.
+///
+/// See [`Paragraph::word_break`].
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Paragraph, WordBreak, Wrap};
+///
+/// let paragraph = Paragraph::new("Supercalifragilisticexpialidocious")
+///     .wrap(Wrap { trim: true })
+///     .word_break(WordBreak::Hyphenate);
+/// // With a width of 10 chars:
+/// // Supercali-
+/// // fragilist-
+/// // icexpiali-
+/// // docious
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WordBreak {
+    /// Words are broken at the edge of the line without any indication
+    #[default]
+    Hard,
+    /// A hyphen (`-`) is inserted where words are broken
+    ///
+    /// No hyphen is inserted when there is no room for at least one symbol of the word before it
+    /// (for example when the paragraph is a single column wide).
+    Hyphenate,
+}
+
+/// Describes the base direction in which the text of a [`Paragraph`] is laid out.
+///
+/// In [`TextDirection::RightToLeft`] mode, each line starts at the right edge of the paragraph and
+/// its graphemes are displayed from right to left. The alignment is mirrored accordingly: a left
+/// aligned line (the default) is anchored to the right edge and a right aligned line to the left
+/// edge.
+///
+/// Only the base direction of the lines is changed, the text is not reordered according to the
+/// Unicode Bidirectional Algorithm (e.g. numbers embedded in right to left text are reversed too).
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Paragraph, TextDirection};
+///
+/// let paragraph = Paragraph::new("שלום עולם").direction(TextDirection::RightToLeft);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TextDirection {
+    /// The text is laid out from left to right
+    #[default]
+    LeftToRight,
+    /// The text is laid out from right to left
+    RightToLeft,
+}
+
 type Horizontal = u16;
 type Vertical = u16;
 
+/// State of the [`Paragraph`] widget when it is rendered as a [`StatefulWidget`]
+///
+/// Only `&Paragraph` implements [`StatefulWidget`], so that calling `render` on an owned paragraph
+/// is not ambiguous when both widget traits are in scope.
+///
+/// The state holds the vertical scroll offset of the paragraph, i.e. the number of lines (after
+/// wrapping) hidden above the displayed area. It replaces the vertical offset given by
+/// [`Paragraph::scroll`], while the horizontal offset is still taken from the paragraph.
+///
+/// Scrolling past the end of the content is allowed, the offset is clamped when the paragraph is
+/// rendered so that the last line of the content is displayed at the bottom of the area.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Paragraph, ParagraphState};
+/// use ratatui::Frame;
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let paragraph = Paragraph::new("Line 1\nLine 2\nLine 3");
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ParagraphState::default();
+/// state.scroll_down();
+///
+/// frame.render_stateful_widget(&paragraph, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphState {
+    offset: u16,
+}
+
+impl ParagraphState {
+    /// Creates a new `ParagraphState` scrolled to the top of the content
+    pub const fn new() -> Self {
+        Self { offset: 0 }
+    }
+
+    /// Sets the vertical scroll offset
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: u16) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the number of lines hidden above the displayed area
+    pub const fn offset(self) -> u16 {
+        self.offset
+    }
+
+    /// Sets the number of lines hidden above the displayed area
+    pub const fn set_offset(&mut self, offset: u16) {
+        self.offset = offset;
+    }
+
+    /// Scrolls the content up by one line
+    pub const fn scroll_up(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    /// Scrolls the content down by one line
+    pub const fn scroll_down(&mut self) {
+        self.offset = self.offset.saturating_add(1);
+    }
+
+    /// Scrolls to the top of the content
+    pub const fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scrolls to the bottom of the content
+    ///
+    /// The offset is set to display the last line of the content when the paragraph is next
+    /// rendered.
+    pub const fn scroll_to_bottom(&mut self) {
+        self.offset = u16::MAX;
+    }
+}
+
 impl<'a> Paragraph<'a> {
     /// Creates a new [`Paragraph`] widget with the given text.
     ///
@@ -152,12 +342,8 @@ impl<'a> Paragraph<'a> {
         T: Into<Text<'a>>,
     {
         Self {
-            block: None,
-            style: Style::default(),
-            wrap: None,
             text: text.into(),
-            scroll: Position::ORIGIN,
-            alignment: Alignment::Left,
+            ..Self::default()
         }
     }
 
@@ -200,6 +386,30 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Patches the style of the entire widget with the style of the given role of the theme
+    ///
+    /// Does nothing if `theme` is `None`, so that the widget can be built the same way whether
+    /// the application uses a theme or not. See [`Theme`] for more information.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize, Theme, ThemeRole};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let theme = Theme::new().with_style(ThemeRole::Error, Style::new().red());
+    /// let paragraph = Paragraph::new("Disk full").themed(Some(&theme), ThemeRole::Error);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn themed(mut self, theme: Option<&Theme>, role: ThemeRole) -> Self {
+        if let Some(theme) = theme {
+            self.style = self.style.patch(theme.style(role));
+        }
+        self
+    }
+
     /// Sets the wrapping configuration for the widget.
     ///
     /// See [`Wrap`] for more information on the different options.
@@ -217,6 +427,26 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets how words too long to fit on a line are broken when wrapping.
+    ///
+    /// Defaults to [`WordBreak::Hard`]. This has no effect unless the paragraph is
+    /// [wrapped](Paragraph::wrap).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, WordBreak, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("Supercalifragilisticexpialidocious")
+    ///     .wrap(Wrap { trim: true })
+    ///     .word_break(WordBreak::Hyphenate);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn word_break(mut self, word_break: WordBreak) -> Self {
+        self.word_break = word_break;
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
@@ -256,6 +486,138 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the base direction of the text for the given paragraph
+    ///
+    /// See [`TextDirection`] for more information on how right to left text is rendered. If no
+    /// direction is specified, the text is laid out from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, TextDirection};
+    ///
+    /// let paragraph = Paragraph::new("مرحبا").direction(TextDirection::RightToLeft);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of columns between tab stops for the given paragraph
+    ///
+    /// Each tab is expanded to spaces up to the next multiple of `tab_width` columns, counted from
+    /// the start of the line (before wrapping), so that tabs align text into columns. A tab width
+    /// of 0 removes the tabs. Defaults to 4.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("Name\tValue").tab_width(8);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tab_width(mut self, tab_width: u16) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Sets the style of the rows continuing a wrapped line
+    ///
+    /// When a line is wrapped, the style is applied to every row after the first one (e.g. to dim
+    /// them), on top of the style of the text. It has no effect unless wrapping is enabled with
+    /// [`Paragraph::wrap`]. Set to `None` to style the continuation rows like the other rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("A long line which wraps")
+    ///     .wrap(Wrap { trim: true })
+    ///     .continuation_style(Style::new().dim());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn continuation_style<S: Into<Option<Style>>>(mut self, style: S) -> Self {
+        self.continuation_style = style.into();
+        self
+    }
+
+    /// Sets whether line numbers are rendered on the left of the text
+    ///
+    /// The numbers are right-aligned in a gutter which is as wide as the largest line number,
+    /// followed by a space. The rows continuing a wrapped line have an empty gutter. Use
+    /// [`Paragraph::line_number_start`] and [`Paragraph::line_number_style`] to customize the
+    /// numbers.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("fn main() {\n}").line_numbers(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Sets the number of the first line when line numbers are rendered
+    ///
+    /// Defaults to 1. It has no effect unless line numbers are enabled with
+    /// [`Paragraph::line_numbers`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// // an excerpt of a file starting at its 42nd line
+    /// let paragraph = Paragraph::new("let a = 1;\nlet b = 2;")
+    ///     .line_numbers(true)
+    ///     .line_number_start(42);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_number_start(mut self, start: usize) -> Self {
+        self.line_number_start = start;
+        self
+    }
+
+    /// Sets the style of the line numbers
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied on top of the style of the paragraph. It has no effect unless line
+    /// numbers are enabled with [`Paragraph::line_numbers`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Paragraph;
+    ///
+    /// let paragraph = Paragraph::new("fn main() {\n}")
+    ///     .line_numbers(true)
+    ///     .line_number_style(Style::new().dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_number_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.line_number_style = style.into();
+        self
+    }
+
     /// Left-aligns the text in the given paragraph.
     ///
     /// Convenience shortcut for `Paragraph::alignment(Alignment::Left)`.
@@ -339,16 +701,23 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
+        self.text_line_count(width)
+            .saturating_add(top as usize)
+            .saturating_add(bottom as usize)
+    }
+
+    /// Calculates the number of lines of the text once wrapped to the given width, without the
+    /// [`Block`]
+    fn text_line_count(&self, width: u16) -> usize {
+        if let Some(Wrap { trim }) = self.wrap {
+            let width = width.saturating_sub(self.gutter_width());
             let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
+                let graphemes = expand_tabs(line, self.style, self.tab_width);
                 let alignment = line.alignment.unwrap_or(self.alignment);
                 (graphemes, alignment)
             });
             let mut line_composer = WordWrapper::new(styled, width, trim);
+            line_composer.set_hyphenate(self.word_break == WordBreak::Hyphenate);
             let mut count = 0;
             while line_composer.next_line().is_some() {
                 count += 1;
@@ -356,11 +725,7 @@ impl<'a> Paragraph<'a> {
             count
         } else {
             self.text.height()
-        };
-
-        count
-            .saturating_add(top as usize)
-            .saturating_add(bottom as usize)
+        }
     }
 
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
@@ -385,7 +750,16 @@ impl<'a> Paragraph<'a> {
         issue = "https://github.com/ratatui/ratatui/issues/293"
     )]
     pub fn line_width(&self) -> usize {
-        let width = self.text.iter().map(Line::width).max().unwrap_or_default();
+        let width = self
+            .text
+            .iter()
+            .map(|line| {
+                expand_tabs(line, self.style, self.tab_width)
+                    .map(|grapheme| grapheme.symbol.width())
+                    .sum::<usize>()
+            })
+            .max()
+            .unwrap_or_default();
         let (left, right) = self
             .block
             .as_ref()
@@ -393,9 +767,25 @@ impl<'a> Paragraph<'a> {
             .unwrap_or_default();
 
         width
+            .saturating_add(usize::from(self.gutter_width()))
             .saturating_add(left as usize)
             .saturating_add(right as usize)
     }
+
+    /// Returns the width of the gutter in which the line numbers are rendered
+    ///
+    /// This is the number of digits of the largest line number, plus a column separating the
+    /// numbers from the text, or 0 if line numbers are disabled.
+    fn gutter_width(&self) -> u16 {
+        if !self.line_numbers {
+            return 0;
+        }
+        let last = self
+            .line_number_start
+            .saturating_add(self.text.height().saturating_sub(1));
+        let digits = last.checked_ilog10().unwrap_or_default() + 1;
+        u16::try_from(digits + 1).unwrap_or(u16::MAX)
+    }
 }
 
 impl Widget for Paragraph<'_> {
@@ -406,49 +796,157 @@ impl Widget for Paragraph<'_> {
 
 impl Widget for &Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_state(area, buf, None);
+    }
+}
+
+impl StatefulWidget for &Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_with_state(area, buf, Some(state));
+    }
+}
+
+impl Paragraph<'_> {
+    /// Renders the paragraph, scrolled vertically by the offset of the state if there is one or by
+    /// the [`scroll`](Self::scroll) offset otherwise
+    fn render_with_state(&self, area: Rect, buf: &mut Buffer, state: Option<&mut ParagraphState>) {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_paragraph(inner, buf);
+        let mut scroll = self.scroll;
+        if let Some(state) = state.filter(|_| !inner.is_empty()) {
+            let max_offset = self
+                .text_line_count(inner.width)
+                .saturating_sub(usize::from(inner.height));
+            let max_offset = u16::try_from(max_offset).unwrap_or(u16::MAX);
+            state.offset = state.offset.min(max_offset);
+            scroll.y = state.offset;
+        }
+        self.render_paragraph(inner, buf, scroll);
+        if let Some(tree) = buf.accessibility_tree_mut() {
+            if !inner.is_empty() {
+                tree.push(Annotation::new(
+                    Role::Paragraph,
+                    self.text.to_string(),
+                    inner,
+                ));
+            }
+        }
     }
-}
 
-impl Paragraph<'_> {
-    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
+    fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer, scroll: Position) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
+        let gutter_width = self.gutter_width().min(text_area.width);
+        let [gutter_area, text_area] = [
+            Rect {
+                width: gutter_width,
+                ..text_area
+            },
+            Rect {
+                x: text_area.x + gutter_width,
+                width: text_area.width - gutter_width,
+                ..text_area
+            },
+        ];
+        let mut gutter = self.line_numbers.then(|| Gutter {
+            area: gutter_area,
+            next_number: self.line_number_start,
+            style: self.style.patch(self.line_number_style),
+        });
+        if let Some(gutter) = &gutter {
+            buf.set_style(gutter.area, gutter.style);
+        }
+        if text_area.is_empty() {
+            if let Some(gutter) = &mut gutter {
+                gutter.next_number = gutter.next_number.saturating_add(usize::from(scroll.y));
+                for y in 0..gutter_area.height {
+                    gutter.render_number(buf, y);
+                }
+            }
+            return;
+        }
         let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
+            let graphemes = expand_tabs(line, self.text.style, self.tab_width);
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
 
         if let Some(Wrap { trim }) = self.wrap {
             let mut line_composer = WordWrapper::new(styled, text_area.width, trim);
+            line_composer.set_hyphenate(self.word_break == WordBreak::Hyphenate);
             // compute the lines iteratively until we reach the desired scroll offset.
-            for _ in 0..self.scroll.y {
-                if line_composer.next_line().is_none() {
+            for _ in 0..scroll.y {
+                let Some(wrapped) = line_composer.next_line() else {
                     return;
+                };
+                if let Some(gutter) = gutter.as_mut().filter(|_| !wrapped.continuation) {
+                    gutter.next_number = gutter.next_number.saturating_add(1);
                 }
             }
-            render_lines(line_composer, text_area, buf);
+            render_lines(
+                line_composer,
+                text_area,
+                buf,
+                self.direction,
+                self.continuation_style,
+                gutter,
+            );
         } else {
             // avoid unnecessary work by skipping directly to the relevant line before rendering
-            let lines = styled.skip(self.scroll.y as usize);
+            let lines = styled.skip(scroll.y as usize);
+            if let Some(gutter) = &mut gutter {
+                gutter.next_number = gutter.next_number.saturating_add(usize::from(scroll.y));
+            }
             let mut line_composer = LineTruncator::new(lines, text_area.width);
-            line_composer.set_horizontal_offset(self.scroll.x);
-            render_lines(line_composer, text_area, buf);
+            line_composer.set_horizontal_offset(scroll.x);
+            render_lines(line_composer, text_area, buf, self.direction, None, gutter);
         }
     }
 }
 
-fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut Buffer) {
+/// The gutter of a [`Paragraph`] in which the line numbers are rendered
+struct Gutter {
+    area: Rect,
+    /// Number of the next line which does not continue a wrapped line
+    next_number: usize,
+    style: Style,
+}
+
+impl Gutter {
+    /// Renders the next line number, right-aligned in the given row of the gutter
+    fn render_number(&mut self, buf: &mut Buffer, y: u16) {
+        let number = self.next_number.to_string();
+        self.next_number = self.next_number.saturating_add(1);
+        // the last column of the gutter separates the numbers from the text
+        let digits = usize::from(self.area.width.saturating_sub(1));
+        let x = self.area.x + u16::try_from(digits.saturating_sub(number.len())).unwrap_or(0);
+        buf.set_stringn(x, self.area.y + y, number, digits, self.style);
+    }
+}
+
+fn render_lines<'a, C: LineComposer<'a>>(
+    mut composer: C,
+    area: Rect,
+    buf: &mut Buffer,
+    direction: TextDirection,
+    continuation_style: Option<Style>,
+    mut gutter: Option<Gutter>,
+) {
     let mut y = 0;
     while let Some(ref wrapped) = composer.next_line() {
-        render_line(wrapped, area, buf, y);
+        if let Some(gutter) = gutter.as_mut().filter(|_| !wrapped.continuation) {
+            gutter.render_number(buf, y);
+        }
+        render_line(wrapped, area, buf, y, direction);
+        if let Some(style) = continuation_style.filter(|_| wrapped.continuation) {
+            buf.set_style(Rect::new(area.x, area.y + y, area.width, 1), style);
+        }
         y += 1;
         if y >= area.height {
             break;
@@ -456,21 +954,83 @@ fn render_lines<'a, C: LineComposer<'a>>(mut composer: C, area: Rect, buf: &mut
     }
 }
 
-fn render_line(wrapped: &WrappedLine<'_, '_>, area: Rect, buf: &mut Buffer, y: u16) {
-    let mut x = get_line_offset(wrapped.width, area.width, wrapped.alignment);
+fn render_line(
+    wrapped: &WrappedLine<'_, '_>,
+    area: Rect,
+    buf: &mut Buffer,
+    y: u16,
+    direction: TextDirection,
+) {
+    let alignment = match (direction, wrapped.alignment) {
+        (TextDirection::RightToLeft, Alignment::Left) => Alignment::Right,
+        (TextDirection::RightToLeft, Alignment::Right) => Alignment::Left,
+        (_, alignment) => alignment,
+    };
+    let mut x = get_line_offset(wrapped.width, area.width, alignment);
+    if direction == TextDirection::RightToLeft {
+        // graphemes are placed from the end of the line towards its start
+        x = x.saturating_add(wrapped.width).min(area.width);
+    }
     for StyledGrapheme { symbol, style } in wrapped.graphemes {
         let width = symbol.width();
         if width == 0 {
             continue;
         }
+        let width = u16::try_from(width).unwrap_or(u16::MAX);
+        if direction == TextDirection::RightToLeft {
+            if width > x {
+                break;
+            }
+            x -= width;
+        }
         // Make sure to overwrite any previous character with a space (rather than a zero-width)
         let symbol = if symbol.is_empty() { " " } else { symbol };
         let position = Position::new(area.left() + x, area.top() + y);
         buf[position].set_symbol(symbol).set_style(*style);
-        x += u16::try_from(width).unwrap_or(u16::MAX);
+        if direction == TextDirection::LeftToRight {
+            x += width;
+        }
     }
 }
 
+/// Returns the styled graphemes of the line, with tabs expanded to spaces up to the next tab stop
+///
+/// Tab stops are placed every `tab_width` columns from the start of the line. A tab width of 0
+/// removes the tabs. Other control characters are skipped, as in [`Line::styled_graphemes`].
+fn expand_tabs<'a>(
+    line: &'a Line<'a>,
+    base_style: Style,
+    tab_width: u16,
+) -> impl Iterator<Item = StyledGrapheme<'a>> {
+    let style = base_style.patch(line.style);
+    let tab_width = usize::from(tab_width);
+    let mut column = 0;
+    line.spans
+        .iter()
+        .flat_map(move |span| {
+            let style = style.patch(span.style);
+            span.content
+                .graphemes(true)
+                .filter(|symbol| *symbol == "\t" || !symbol.contains(char::is_control))
+                .map(move |symbol| StyledGrapheme::new(symbol, style))
+        })
+        .flat_map(move |grapheme| {
+            let (grapheme, count, width) = if grapheme.symbol == "\t" {
+                let spaces = if tab_width == 0 {
+                    0
+                } else {
+                    tab_width - column % tab_width
+                };
+                (StyledGrapheme::new(" ", grapheme.style), spaces, spaces)
+            } else {
+                let width = grapheme.symbol.width();
+                (grapheme, 1, width)
+            };
+            column += width;
+            iter::repeat(grapheme).take(count)
+        })
+}
+
 const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
@@ -500,6 +1060,7 @@ mod tests {
     use ratatui_core::style::{Color, Modifier, Style, Stylize};
     use ratatui_core::text::{Line, Span, Text};
     use ratatui_core::widgets::Widget;
+    use rstest::rstest;
 
     use super::*;
     use crate::block::Position;
@@ -512,7 +1073,7 @@ mod tests {
     #[track_caller]
     fn test_case(paragraph: &Paragraph, expected: &Buffer) {
         let mut buffer = Buffer::empty(Rect::new(0, 0, expected.area.width, expected.area.height));
-        paragraph.render(buffer.area, &mut buffer);
+        Widget::render(paragraph, buffer.area, &mut buffer);
         assert_eq!(buffer, *expected);
     }
 
@@ -1173,6 +1734,150 @@ mod tests {
         assert_eq!(p.alignment, Alignment::Right);
     }
 
+    #[test]
+    fn direction() {
+        let p = Paragraph::new("Hello, world!");
+        assert_eq!(p.direction, TextDirection::LeftToRight);
+        let p = p.direction(TextDirection::RightToLeft);
+        assert_eq!(p.direction, TextDirection::RightToLeft);
+    }
+
+    #[rstest]
+    #[case::left_aligned(Alignment::Left, ["   cba", "    ed"])]
+    #[case::centered(Alignment::Center, ["  cba ", "  ed  "])]
+    #[case::right_aligned(Alignment::Right, ["cba   ", "ed    "])]
+    fn render_right_to_left(#[case] alignment: Alignment, #[case] expected: [&str; 2]) {
+        let paragraph = Paragraph::new("abc\nde")
+            .alignment(alignment)
+            .direction(TextDirection::RightToLeft);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_right_to_left_wrapped() {
+        let paragraph = Paragraph::new("hello big world")
+            .direction(TextDirection::RightToLeft)
+            .wrap(Wrap { trim: true });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" gib olleh", "     dlrow"]));
+    }
+
+    #[test]
+    fn render_right_to_left_truncated() {
+        let paragraph = Paragraph::new("abcdefgh").direction(TextDirection::RightToLeft);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["edcba"]));
+    }
+
+    #[test]
+    fn render_right_to_left_with_line_alignment() {
+        let text = Text::from_iter([Line::from("ab"), Line::from("cd").right_aligned()]);
+        let paragraph = Paragraph::new(text).direction(TextDirection::RightToLeft);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  ba", "dc  "]));
+    }
+
+    #[test]
+    fn render_right_to_left_wide_characters() {
+        let paragraph = Paragraph::new("コンa").direction(TextDirection::RightToLeft);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" aンコ"]));
+    }
+
+    #[test]
+    fn render_continuation_style() {
+        let text = Text::from_iter(["aaaa bbbb cccc", "dddd"]);
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .continuation_style(Style::new().dim());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["aaaa ", "bbbb ", "cccc ", "dddd ", "     "]);
+        expected.set_style(Rect::new(0, 1, 5, 2), Modifier::DIM);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_continuation_style_when_scrolled() {
+        let paragraph = Paragraph::new("aaaa bbbb cccc")
+            .wrap(Wrap { trim: true })
+            .scroll((1, 0))
+            .continuation_style(Style::new().dim());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["bbbb", "cccc"]);
+        expected.set_style(buf.area, Modifier::DIM);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_continuation_style_without_wrap() {
+        let paragraph = Paragraph::new("aaaa bbbb").continuation_style(Style::new().dim());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["aaaa", "    "]));
+    }
+
+    #[test]
+    fn render_hyphenated_word_break() {
+        let paragraph = Paragraph::new("Supercalifragilistic")
+            .wrap(Wrap { trim: true })
+            .word_break(WordBreak::Hyphenate);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Superca-", "lifragi-", "listic  "])
+        );
+    }
+
+    #[test]
+    fn line_count_with_hyphenated_word_break() {
+        let paragraph = Paragraph::new("abcdefgh").wrap(Wrap { trim: true });
+        assert_eq!(paragraph.clone().line_count(4), 2);
+        assert_eq!(paragraph.word_break(WordBreak::Hyphenate).line_count(4), 3);
+    }
+
+    #[rstest]
+    #[case::at_line_start(4, "\tx", "    x     ")]
+    #[case::after_one_column(4, "a\tx", "a   x     ")]
+    #[case::before_tab_stop(4, "abc\tx", "abc x     ")]
+    #[case::on_tab_stop(4, "abcd\tx", "abcd    x ")]
+    #[case::consecutive_stops(4, "a\tb\tc", "a   b   c ")]
+    #[case::wide_character(4, "コ\tx", "コ  x     ")]
+    #[case::custom_width(8, "ab\tc", "ab      c ")]
+    #[case::zero_width(0, "a\tb", "ab        ")]
+    fn render_expands_tabs(#[case] tab_width: u16, #[case] text: &str, #[case] expected: &str) {
+        let paragraph = Paragraph::new(text).tab_width(tab_width);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_expands_tabs_across_spans() {
+        let line = Line::from(vec![Span::raw("ab"), Span::raw("\tc").red()]);
+        let paragraph = Paragraph::new(line);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["ab  c "]);
+        expected.set_style(Rect::new(2, 0, 3, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn line_width_with_tabs() {
+        let paragraph = Paragraph::new("a\tb\nabcd\t");
+        assert_eq!(paragraph.line_width(), 8);
+        assert_eq!(paragraph.tab_width(2).line_width(), 6);
+    }
+
     /// Regression test for <https://github.com/ratatui/ratatui/issues/990>
     ///
     /// This test ensures that paragraphs with a block and styled text are rendered correctly.
@@ -1193,4 +1898,174 @@ mod tests {
         expected.set_style(Rect::new(1, 1, 11, 1), Style::default().fg(Color::Green));
         assert_eq!(buf, expected);
     }
+
+    #[test]
+    fn accessibility_tree_contains_paragraph() {
+        let paragraph = Paragraph::new("Hello\nworld").block(Block::bordered().title("Title"));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 4));
+        buffer.enable_accessibility_tree(true);
+        paragraph.render(buffer.area, &mut buffer);
+        let tree = buffer.accessibility_tree().unwrap();
+        assert_eq!(
+            tree.annotations(),
+            [
+                Annotation::new(Role::Title, "Title", Rect::new(1, 0, 8, 1)),
+                Annotation::new(Role::Paragraph, "Hello\nworld", Rect::new(1, 1, 8, 2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_with_state_clamps_offset_to_content() {
+        let paragraph = Paragraph::new("a\nb\nc\nd\ne");
+        let mut state = ParagraphState::new().with_offset(10);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["d  ", "e  "]));
+        assert_eq!(state.offset(), 3);
+
+        state.scroll_down();
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 3);
+    }
+
+    #[test]
+    fn render_with_state_scroll_up_stops_at_top() {
+        let paragraph = Paragraph::new("a\nb\nc");
+        let mut state = ParagraphState::new().with_offset(1);
+        state.scroll_up();
+        state.scroll_up();
+        assert_eq!(state.offset(), 0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["a  ", "b  "]));
+    }
+
+    #[test]
+    fn render_with_state_content_shorter_than_area() {
+        let paragraph = Paragraph::new("a\nb");
+        let mut state = ParagraphState::new();
+        state.scroll_to_bottom();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["a  ", "b  ", "   "]));
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_with_state_scroll_to_bottom_of_wrapped_text() {
+        let paragraph = Paragraph::new("aaa bbb ccc")
+            .block(Block::bordered())
+            .wrap(Wrap::default());
+        let mut state = ParagraphState::new();
+        state.scroll_to_bottom();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 4));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┐",
+            "│bbb│",
+            "│ccc│",
+            "└───┘",
+        ]);
+        assert_eq!(buf, expected);
+        assert_eq!(state.offset(), 1);
+
+        state.scroll_to_top();
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_with_state_keeps_horizontal_scroll() {
+        let paragraph = Paragraph::new("abcd\nefgh\nijkl").scroll((0, 2));
+        let mut state = ParagraphState::new().with_offset(1);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["gh"]));
+    }
+
+    #[rstest]
+    #[case::single_digit(1, 9, 2)]
+    #[case::two_digits(1, 10, 3)]
+    #[case::start(95, 5, 3)]
+    #[case::start_crossing_digits(95, 6, 4)]
+    #[case::start_zero(0, 10, 2)]
+    fn gutter_width(#[case] start: usize, #[case] lines: usize, #[case] expected: u16) {
+        let text: Text = iter::repeat("a").take(lines).collect();
+        let paragraph = Paragraph::new(text)
+            .line_numbers(true)
+            .line_number_start(start);
+        assert_eq!(paragraph.gutter_width(), expected);
+        assert_eq!(paragraph.line_width(), usize::from(expected) + 1);
+    }
+
+    #[test]
+    fn gutter_width_without_line_numbers() {
+        let paragraph = Paragraph::new("a\nb").line_number_start(100);
+        assert_eq!(paragraph.gutter_width(), 0);
+    }
+
+    #[test]
+    fn render_line_numbers() {
+        let text: Text = iter::repeat("line").take(10).collect();
+        let paragraph = Paragraph::new(text)
+            .line_numbers(true)
+            .line_number_style(Style::new().dark_gray());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 10));
+        paragraph.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            " 1 line", " 2 line", " 3 line", " 4 line", " 5 line", " 6 line", " 7 line", " 8 line",
+            " 9 line", "10 line",
+        ]);
+        expected.set_style(Rect::new(0, 0, 3, 10), Style::new().dark_gray());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_line_numbers_with_wrapped_continuation_rows() {
+        let text = Text::from_iter(["aaaa bbbb", "cccc"]);
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .line_numbers(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        Widget::render(&paragraph, buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["1 aaaa", "  bbbb", "2 cccc", "      "])
+        );
+        assert_eq!(paragraph.line_count(6), 3);
+    }
+
+    #[test]
+    fn render_line_numbers_when_scrolled() {
+        let text = Text::from_iter(["aaaa bbbb", "cccc", "dddd"]);
+        let wrap = Wrap { trim: true };
+        let paragraph = Paragraph::new(text.clone())
+            .wrap(wrap)
+            .line_numbers(true)
+            .scroll((1, 0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  bbbb", "2 cccc"]));
+
+        let paragraph = Paragraph::new(text)
+            .line_numbers(true)
+            .line_number_start(8)
+            .scroll((1, 0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([" 9 cccc", "10 dddd"]));
+    }
+
+    #[test]
+    fn render_themed() {
+        let theme = Theme::new().with_style(ThemeRole::Accent, Style::new().yellow());
+        let paragraph = Paragraph::new("ab").themed(Some(&theme), ThemeRole::Accent);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        paragraph.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["ab ".yellow()]));
+
+        let paragraph = Paragraph::new("ab").themed(None, ThemeRole::Accent);
+        assert_eq!(paragraph.style, Style::new());
+    }
 }
@@ -24,6 +24,8 @@ pub struct WrappedLine<'lend, 'text> {
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
+    /// Whether the line is the continuation of a line that was wrapped
+    pub continuation: bool,
 }
 
 /// A state machine that wraps lines on word boundaries.
@@ -42,8 +44,12 @@ where
     wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
     current_alignment: Alignment,
     current_line: Vec<StyledGrapheme<'a>>,
+    /// Whether the next emitted line is the first wrapped line of an input line
+    first_wrapped_line: bool,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Inserts a hyphen where a word is broken because it does not fit on a line
+    hyphenate: bool,
 
     // These are cached allocations that hold no state across next_line invocations
     pending_word: Vec<StyledGrapheme<'a>>,
@@ -64,7 +70,9 @@ where
             wrapped_lines: VecDeque::new(),
             current_alignment: Alignment::Left,
             current_line: vec![],
+            first_wrapped_line: true,
             trim,
+            hyphenate: false,
 
             pending_word: Vec::new(),
             pending_line_pool: Vec::new(),
@@ -72,6 +80,11 @@ where
         }
     }
 
+    /// Set whether a hyphen is inserted where a word is broken because it does not fit on a line.
+    pub const fn set_hyphenate(&mut self, hyphenate: bool) {
+        self.hyphenate = hyphenate;
+    }
+
     /// Split an input line (`line_symbols`) into wrapped lines
     /// and cache them to be emitted later
     fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
@@ -108,6 +121,10 @@ where
                 && !self.trim
                 && word_width + whitespace_width + symbol_width > self.max_line_width;
 
+            // the current word is broken as it does not fit on a line on its own
+            let mut hyphenated = false;
+            let mut broken_word_end = Vec::new();
+
             // append finished segment to current line
             if word_found || trimmed_overflow || whitespace_overflow || untrimmed_overflow {
                 if !pending_line.is_empty() || !self.trim {
@@ -115,16 +132,30 @@ where
                     line_width += whitespace_width;
                 }
 
+                let word_broken = !word_found && (trimmed_overflow || untrimmed_overflow);
+                if self.hyphenate && word_broken {
+                    if let Some(end) = self.hyphenate_pending_word(line_width, &mut word_width) {
+                        hyphenated = true;
+                        broken_word_end = end;
+                    }
+                }
+
                 pending_line.append(&mut self.pending_word);
                 line_width += word_width;
 
                 self.pending_whitespace.clear();
                 whitespace_width = 0;
                 word_width = 0;
+
+                // the end of the broken word starts the next line
+                for grapheme in broken_word_end {
+                    word_width += grapheme.symbol.width() as u16;
+                    self.pending_word.push(grapheme);
+                }
             }
 
             // pending line fills up limit
-            let line_full = line_width >= self.max_line_width;
+            let line_full = hyphenated || line_width >= self.max_line_width;
             // pending word would overflow line limit
             let pending_word_overflow = symbol_width > 0
                 && line_width + whitespace_width + word_width >= self.max_line_width;
@@ -191,6 +222,31 @@ where
         }
     }
 
+    /// Ends the pending word with a hyphen, moving as many graphemes as needed for the hyphen to
+    /// fit on the line out of the word. The moved graphemes are returned in order.
+    ///
+    /// Returns `None` without changing anything if no part of the word fits before the hyphen.
+    fn hyphenate_pending_word(
+        &mut self,
+        line_width: u16,
+        word_width: &mut u16,
+    ) -> Option<Vec<StyledGrapheme<'a>>> {
+        let mut split = self.pending_word.len();
+        let mut width = line_width + *word_width;
+        while split > 0 && width + 1 > self.max_line_width {
+            split -= 1;
+            width -= self.pending_word[split].symbol.width() as u16;
+        }
+        if split == 0 {
+            return None;
+        }
+        let end = self.pending_word.split_off(split);
+        let style = self.pending_word[split - 1].style;
+        self.pending_word.push(StyledGrapheme::new("-", style));
+        *word_width = width - line_width + 1;
+        Some(end)
+    }
+
     fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
         let cache = mem::replace(&mut self.current_line, line);
         if cache.capacity() > 0 {
@@ -218,16 +274,19 @@ where
                     .sum();
 
                 self.replace_current_line(line);
+                let continuation = !mem::replace(&mut self.first_wrapped_line, false);
                 return Some(WrappedLine {
                     graphemes: &self.current_line,
                     width: line_width,
                     alignment: self.current_alignment,
+                    continuation,
                 });
             }
 
             // otherwise, process pending wrapped lines from input
             let (line_symbols, line_alignment) = self.input_lines.next()?;
             self.current_alignment = line_alignment;
+            self.first_wrapped_line = true;
             self.process_input(line_symbols);
         }
     }
@@ -328,6 +387,7 @@ where
                 graphemes: &self.current_line,
                 width: current_line_width,
                 alignment: current_alignment,
+                continuation: false,
             })
         }
     }
@@ -357,12 +417,14 @@ mod tests {
 
     use ratatui_core::style::Style;
     use ratatui_core::text::{Line, Text};
+    use rstest::rstest;
 
     use super::*;
 
     #[derive(Clone, Copy)]
     enum Composer {
         WordWrapper { trim: bool },
+        HyphenatingWordWrapper { trim: bool },
         LineTruncator,
     }
 
@@ -384,6 +446,11 @@ mod tests {
             Composer::WordWrapper { trim } => {
                 Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
             }
+            Composer::HyphenatingWordWrapper { trim } => {
+                let mut composer = WordWrapper::new(styled_lines, text_area_width, trim);
+                composer.set_hyphenate(true);
+                Box::new(composer)
+            }
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
         };
         let mut lines = vec![];
@@ -393,6 +460,7 @@ mod tests {
             graphemes,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = graphemes
@@ -728,4 +796,29 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    #[rstest]
+    #[case::long_word(5, "abcdefghijk", &["abcd-", "efgh-", "ijk"])]
+    #[case::exact_fit(4, "abcd", &["abcd"])]
+    #[case::after_words(5, "ab abcdefghij", &["ab", "abcd-", "efgh-", "ij"])]
+    #[case::before_words(5, "abcdefg hi", &["abcd-", "efg", "hi"])]
+    #[case::two_columns(2, "abcde", &["a-", "b-", "c-", "de"])]
+    #[case::one_column(1, "abc", &["a", "b", "c"])]
+    #[case::wide_characters(5, "コンピュータ", &["コン-", "ピュ-", "ータ"])]
+    fn line_composer_hyphenate(#[case] width: u16, #[case] text: &str, #[case] expected: &[&str]) {
+        let (lines, widths, _) =
+            run_composer(Composer::HyphenatingWordWrapper { trim: true }, text, width);
+        assert_eq!(lines, expected);
+        assert!(widths.iter().all(|&line_width| line_width <= width));
+    }
+
+    #[test]
+    fn line_composer_hyphenate_without_trim() {
+        let (lines, _, _) = run_composer(
+            Composer::HyphenatingWordWrapper { trim: false },
+            "  abcdefgh",
+            5,
+        );
+        assert_eq!(lines, ["  ab-", "cdef-", "gh"]);
+    }
 }
@@ -0,0 +1,417 @@
+//! The [`ScrollView`] widget displays a scrollable view over content larger than its area.
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::{Position, Rect, Size};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+use crate::scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+
+/// A widget to display a scrollable view over content of a known size
+///
+/// The content is rendered into the `ScrollView` with [`ScrollView::render_widget`] and
+/// [`ScrollView::render_stateful_widget`], using the coordinates of the content (i.e. the top left
+/// corner of the content is at `(0, 0)`). When the `ScrollView` is rendered, only the part of the
+/// content starting at the [`ScrollViewState::offset`] is displayed.
+///
+/// A vertical scrollbar is rendered on the right side of the area when the content is taller than
+/// the area, and a horizontal scrollbar is rendered at the bottom of the area when the content is
+/// wider than the area. The scrollbars take up space from the area where the content is displayed.
+///
+/// The offset is clamped when rendering so that the view never scrolls past the end of the
+/// content.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::{Rect, Size};
+/// use ratatui::widgets::{Paragraph, ScrollView, ScrollViewState};
+/// use ratatui::Frame;
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let mut scroll_view = ScrollView::new(Size::new(20, 100));
+/// for i in 0..100 {
+///     let line = Paragraph::new(format!("Line {i}"));
+///     scroll_view.render_widget(line, Rect::new(0, i, 20, 1));
+/// }
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ScrollViewState::default();
+/// state.scroll_down();
+///
+/// frame.render_stateful_widget(&scroll_view, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollView {
+    buf: Buffer,
+}
+
+/// State of the [`ScrollView`] widget
+///
+/// The state holds the offset of the content displayed in the top left corner of the view. The
+/// scroll methods move this offset. Scrolling past the end of the content is allowed, the offset
+/// is clamped to the content when the view is rendered.
+///
+/// The size of the area where the content was last displayed is kept to scroll by pages with
+/// [`ScrollViewState::scroll_page_down`] and [`ScrollViewState::scroll_page_up`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScrollViewState {
+    offset: Position,
+    page_size: Size,
+}
+
+impl ScrollView {
+    /// Creates a new `ScrollView` with content of the given size
+    ///
+    /// The content is initially empty.
+    pub fn new(size: Size) -> Self {
+        Self {
+            buf: Buffer::empty(Rect::from((Position::ORIGIN, size))),
+        }
+    }
+
+    /// Returns the size of the content
+    pub const fn size(&self) -> Size {
+        self.buf.area.as_size()
+    }
+
+    /// Returns the area of the content
+    ///
+    /// This is the area to render the content widgets in, its top left corner is at `(0, 0)`.
+    pub const fn area(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// Returns a reference to the buffer holding the content
+    pub const fn buf(&self) -> &Buffer {
+        &self.buf
+    }
+
+    /// Returns a mutable reference to the buffer holding the content
+    pub const fn buf_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Renders a widget into the content of the scroll view
+    ///
+    /// The `area` is given in the coordinates of the content.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area, &mut self.buf);
+    }
+
+    /// Renders a stateful widget into the content of the scroll view
+    ///
+    /// The `area` is given in the coordinates of the content.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area, &mut self.buf, state);
+    }
+
+    /// Returns the area displaying the content and whether the vertical and horizontal scrollbars
+    /// are shown
+    fn layout(&self, area: Rect) -> (Rect, bool, bool) {
+        let size = self.size();
+        let mut vertical = size.height > area.height;
+        let horizontal = size.width > area.width.saturating_sub(u16::from(vertical));
+        // the horizontal scrollbar can make the content taller than the remaining area
+        vertical = size.height > area.height.saturating_sub(u16::from(horizontal));
+        let content_area = Rect {
+            width: area.width.saturating_sub(u16::from(vertical)),
+            height: area.height.saturating_sub(u16::from(horizontal)),
+            ..area
+        };
+        (content_area, vertical, horizontal)
+    }
+}
+
+impl StatefulWidget for ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let size = self.size();
+        let (content_area, vertical, horizontal) = self.layout(area);
+        let max_offset = Position::new(
+            size.width.saturating_sub(content_area.width),
+            size.height.saturating_sub(content_area.height),
+        );
+        state.offset.x = state.offset.x.min(max_offset.x);
+        state.offset.y = state.offset.y.min(max_offset.y);
+        state.page_size = content_area.as_size();
+
+        let visible_width = content_area.width.min(size.width);
+        let visible_height = content_area.height.min(size.height);
+        for y in 0..visible_height {
+            for x in 0..visible_width {
+                let content_position = Position::new(state.offset.x + x, state.offset.y + y);
+                let position = Position::new(content_area.x + x, content_area.y + y);
+                if let (Some(cell), Some(target)) =
+                    (self.buf.cell(content_position), buf.cell_mut(position))
+                {
+                    target.clone_from(cell);
+                }
+            }
+        }
+
+        if vertical {
+            let scrollbar_area = Rect {
+                height: content_area.height,
+                ..area
+            };
+            let mut scrollbar_state = ScrollbarState::new(usize::from(max_offset.y) + 1)
+                .position(usize::from(state.offset.y))
+                .viewport_content_length(usize::from(content_area.height));
+            Scrollbar::new(ScrollbarOrientation::VerticalRight).render(
+                scrollbar_area,
+                buf,
+                &mut scrollbar_state,
+            );
+        }
+        if horizontal {
+            let scrollbar_area = Rect {
+                width: content_area.width,
+                ..area
+            };
+            let mut scrollbar_state = ScrollbarState::new(usize::from(max_offset.x) + 1)
+                .position(usize::from(state.offset.x))
+                .viewport_content_length(usize::from(content_area.width));
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom).render(
+                scrollbar_area,
+                buf,
+                &mut scrollbar_state,
+            );
+        }
+    }
+}
+
+impl ScrollViewState {
+    /// Creates a new `ScrollViewState` with the offset at the top left corner of the content
+    pub const fn new() -> Self {
+        Self {
+            offset: Position::ORIGIN,
+            page_size: Size::ZERO,
+        }
+    }
+
+    /// Sets the offset of the content
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: Position) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Returns the offset of the content displayed in the top left corner of the view
+    pub const fn offset(self) -> Position {
+        self.offset
+    }
+
+    /// Sets the offset of the content displayed in the top left corner of the view
+    pub const fn set_offset(&mut self, offset: Position) {
+        self.offset = offset;
+    }
+
+    /// Scrolls the content up by one row
+    pub const fn scroll_up(&mut self) {
+        self.offset.y = self.offset.y.saturating_sub(1);
+    }
+
+    /// Scrolls the content down by one row
+    pub const fn scroll_down(&mut self) {
+        self.offset.y = self.offset.y.saturating_add(1);
+    }
+
+    /// Scrolls the content left by one column
+    pub const fn scroll_left(&mut self) {
+        self.offset.x = self.offset.x.saturating_sub(1);
+    }
+
+    /// Scrolls the content right by one column
+    pub const fn scroll_right(&mut self) {
+        self.offset.x = self.offset.x.saturating_add(1);
+    }
+
+    /// Scrolls the content up by the height of the view
+    ///
+    /// The height is the one of the last render, or one row if the view was never rendered.
+    pub fn scroll_page_up(&mut self) {
+        let page = self.page_size.height.max(1);
+        self.offset.y = self.offset.y.saturating_sub(page);
+    }
+
+    /// Scrolls the content down by the height of the view
+    ///
+    /// The height is the one of the last render, or one row if the view was never rendered.
+    pub fn scroll_page_down(&mut self) {
+        let page = self.page_size.height.max(1);
+        self.offset.y = self.offset.y.saturating_add(page);
+    }
+
+    /// Scrolls to the top of the content
+    pub const fn scroll_to_top(&mut self) {
+        self.offset = Position::ORIGIN;
+    }
+
+    /// Scrolls to the bottom of the content
+    ///
+    /// The offset is set to the last row of the content when the view is next rendered.
+    pub const fn scroll_to_bottom(&mut self) {
+        self.offset.y = u16::MAX;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::buffer::Cell;
+    use ratatui_core::text::Line;
+
+    use super::*;
+
+    /// A scroll view with 10 rows of content, each row containing its index
+    fn scroll_view(width: u16) -> ScrollView {
+        let mut scroll_view = ScrollView::new(Size::new(width, 10));
+        for i in 0..10 {
+            scroll_view.render_widget(Line::from(format!("Line {i}")), Rect::new(0, i, width, 1));
+        }
+        scroll_view
+    }
+
+    fn render(
+        scroll_view: &ScrollView,
+        state: &mut ScrollViewState,
+        width: u16,
+        height: u16,
+    ) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(scroll_view, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn new() {
+        let scroll_view = ScrollView::new(Size::new(5, 3));
+        assert_eq!(scroll_view.size(), Size::new(5, 3));
+        assert_eq!(scroll_view.area(), Rect::new(0, 0, 5, 3));
+        assert_eq!(scroll_view.buf(), &Buffer::empty(Rect::new(0, 0, 5, 3)));
+    }
+
+    #[test]
+    fn render_top() {
+        let mut state = ScrollViewState::new();
+        let buf = render(&scroll_view(6), &mut state, 7, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Line 0▲", "Line 1█", "Line 2█", "Line 3║", "Line 4▼"])
+        );
+        assert_eq!(state.page_size, Size::new(6, 5));
+    }
+
+    #[test]
+    fn scrolling_updates_visible_rows() {
+        let scroll_view = scroll_view(6);
+        let mut state = ScrollViewState::new();
+        state.scroll_down();
+        state.scroll_down();
+        let buf = render(&scroll_view, &mut state, 7, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Line 2▲", "Line 3║", "Line 4█", "Line 5║", "Line 6▼"])
+        );
+
+        state.scroll_up();
+        let buf = render(&scroll_view, &mut state, 7, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Line 1▲", "Line 2█", "Line 3█", "Line 4║", "Line 5▼"])
+        );
+    }
+
+    #[test]
+    fn scroll_to_bottom_clamps_offset() {
+        let mut state = ScrollViewState::new();
+        state.scroll_to_bottom();
+        let buf = render(&scroll_view(6), &mut state, 7, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Line 5▲", "Line 6║", "Line 7║", "Line 8█", "Line 9▼"])
+        );
+        assert_eq!(state.offset(), Position::new(0, 5));
+
+        state.scroll_to_top();
+        assert_eq!(state.offset(), Position::ORIGIN);
+    }
+
+    #[test]
+    fn scroll_page() {
+        let scroll_view = scroll_view(6);
+        let mut state = ScrollViewState::new();
+        state.scroll_page_down();
+        assert_eq!(state.offset(), Position::new(0, 1));
+
+        render(&scroll_view, &mut state, 7, 4);
+        state.scroll_page_down();
+        assert_eq!(state.offset(), Position::new(0, 5));
+        state.scroll_page_up();
+        state.scroll_page_up();
+        assert_eq!(state.offset(), Position::ORIGIN);
+    }
+
+    #[test]
+    fn render_without_scrollbar() {
+        let mut state = ScrollViewState::new().with_offset(Position::new(2, 3));
+        let buf = render(&scroll_view(6), &mut state, 7, 10);
+        assert_eq!(state.offset(), Position::ORIGIN);
+        assert_eq!(buf.cell((6, 0)).map(Cell::symbol), Some(" "));
+        assert_eq!(buf.cell((0, 9)).map(Cell::symbol), Some("L"));
+    }
+
+    #[test]
+    fn render_horizontal_scrollbar() {
+        let mut state = ScrollViewState::new();
+        state.scroll_right();
+        state.scroll_right();
+        let mut scroll_view = ScrollView::new(Size::new(8, 2));
+        scroll_view.render_widget(Line::from("abcdefgh"), Rect::new(0, 0, 8, 1));
+        scroll_view.render_widget(Line::from("ABCDEFGH"), Rect::new(0, 1, 8, 1));
+        let buf = render(&scroll_view, &mut state, 6, 3);
+        assert_eq!(buf, Buffer::with_lines(["cdefgh", "CDEFGH", "◄═███►"]));
+    }
+
+    #[test]
+    fn render_both_scrollbars() {
+        let mut state = ScrollViewState::new();
+        let buf = render(&scroll_view(8), &mut state, 7, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Line 0▲", "Line 1█", "Line 2║", "Line 3▼", "◄███═► "])
+        );
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let mut state = ScrollViewState::new().with_offset(Position::new(0, 3));
+        let buf = render(&scroll_view(6), &mut state, 0, 0);
+        assert_eq!(buf, Buffer::empty(Rect::ZERO));
+        assert_eq!(state.offset(), Position::new(0, 3));
+    }
+}
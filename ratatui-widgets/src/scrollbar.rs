@@ -88,8 +88,10 @@ pub struct Scrollbar<'a> {
     track_style: Style,
     track_symbol: Option<&'a str>,
     begin_symbol: Option<&'a str>,
+    disabled_begin_symbol: Option<&'a str>,
     begin_style: Style,
     end_symbol: Option<&'a str>,
+    disabled_end_symbol: Option<&'a str>,
     end_style: Style,
 }
 
@@ -198,8 +200,10 @@ impl<'a> Scrollbar<'a> {
             track_symbol: Some(symbols.track),
             track_style: Style::new(),
             begin_symbol: Some(symbols.begin),
+            disabled_begin_symbol: None,
             begin_style: Style::new(),
             end_symbol: Some(symbols.end),
+            disabled_end_symbol: None,
             end_style: Style::new(),
         }
     }
@@ -306,6 +310,33 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the symbol that represents the beginning of the scrollbar when it can't scroll any
+    /// further backward, i.e. when the position is at the start of the content.
+    ///
+    /// This replaces the [`Scrollbar::begin_symbol`] to show that scrolling backward is not
+    /// possible. It has no effect if the begin symbol is `None`. Defaults to `None`, which always
+    /// renders the begin symbol.
+    ///
+    /// The symbol should have the same width as the begin symbol, as only the latter is taken into
+    /// account when sizing the track.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Scrollbar, ScrollbarOrientation};
+    ///
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+    ///     .begin_symbol(Some("▲"))
+    ///     .disabled_begin_symbol(Some("△"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled_begin_symbol(mut self, disabled_begin_symbol: Option<&'a str>) -> Self {
+        self.disabled_begin_symbol = disabled_begin_symbol;
+        self
+    }
+
     /// Sets the style that is used for the beginning of the scrollbar.
     ///
     /// See [`Scrollbar`] for a visual example of what this represents.
@@ -333,6 +364,23 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the symbol that represents the end of the scrollbar when it can't scroll any further
+    /// forward, i.e. when the position is at the end of the content.
+    ///
+    /// This replaces the [`Scrollbar::end_symbol`] to show that scrolling forward is not possible.
+    /// It has no effect if the end symbol is `None`. Defaults to `None`, which always renders the
+    /// end symbol.
+    ///
+    /// The symbol should have the same width as the end symbol, as only the latter is taken into
+    /// account when sizing the track.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled_end_symbol(mut self, disabled_end_symbol: Option<&'a str>) -> Self {
+        self.disabled_end_symbol = disabled_end_symbol;
+        self
+    }
+
     /// Sets the style that is used for the end of the scrollbar.
     ///
     /// See [`Scrollbar`] for a visual example of what this represents.
@@ -526,10 +574,23 @@ impl Scrollbar<'_> {
     ) -> impl Iterator<Item = Option<(&str, Style)>> {
         let (track_start_len, thumb_len, track_end_len) = self.part_lengths(area, state);
 
-        let begin = self.begin_symbol.map(|s| Some((s, self.begin_style)));
+        // Scrolling is not possible past the first and last positions, see `ScrollbarState::prev`
+        // and `ScrollbarState::next`.
+        let at_start = state.position == 0;
+        let at_end = state.position >= state.content_length.saturating_sub(1);
+        let begin_symbol = match self.disabled_begin_symbol {
+            Some(disabled) if at_start => self.begin_symbol.map(|_| disabled),
+            _ => self.begin_symbol,
+        };
+        let end_symbol = match self.disabled_end_symbol {
+            Some(disabled) if at_end => self.end_symbol.map(|_| disabled),
+            _ => self.end_symbol,
+        };
+
+        let begin = begin_symbol.map(|s| Some((s, self.begin_style)));
         let track = Some(self.track_symbol.map(|s| (s, self.track_style)));
         let thumb = Some(Some((self.thumb_symbol, self.thumb_style)));
-        let end = self.end_symbol.map(|s| Some((s, self.end_style)));
+        let end = end_symbol.map(|s| Some((s, self.end_style)));
 
         // `<`
         iter::once(begin)
@@ -780,6 +841,45 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
 
+    #[rstest]
+    #[case::position_0("^####---->", 0, 10)]
+    #[case::position_5("<--####-->", 5, 10)]
+    #[case::position_9("<----####_", 9, 10)]
+    #[case::position_out_of_bounds("<----####_", 15, 10)]
+    #[case::single_item("^########_", 0, 1)]
+    fn render_scrollbar_with_disabled_symbols(
+        #[case] expected: &str,
+        #[case] position: usize,
+        #[case] content_length: usize,
+    ) {
+        let size = expected.width() as u16;
+        let mut buffer = Buffer::empty(Rect::new(0, 0, size, 1));
+        let mut state = ScrollbarState::new(content_length).position(position);
+        Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(Some("<"))
+            .disabled_begin_symbol(Some("^"))
+            .end_symbol(Some(">"))
+            .disabled_end_symbol(Some("_"))
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_scrollbar_disabled_symbols_without_arrows() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = ScrollbarState::new(4);
+        Scrollbar::new(ScrollbarOrientation::HorizontalTop)
+            .begin_symbol(None)
+            .disabled_begin_symbol(Some("^"))
+            .end_symbol(None)
+            .track_symbol(Some("-"))
+            .thumb_symbol("#")
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["##--"]));
+    }
+
     #[rstest]
     #[case::position_0("█████═════", 0, 10)]
     #[case::position_1("═█████════", 1, 10)]
@@ -218,6 +218,17 @@ impl<'a> Sparkline<'a> {
     ///
     /// Every bar will be scaled accordingly. If no max is given, this will be the max in the
     /// dataset.
+    ///
+    /// Setting a max keeps the scale stable when the data changes between frames (e.g. for
+    /// streaming data). Values above the max are drawn at full height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Sparkline;
+    ///
+    /// let sparkline = Sparkline::default().data([1, 5, 10, 20]).max(10);
+    /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn max(mut self, max: u64) -> Self {
         self.max = Some(max);
@@ -388,7 +399,7 @@ impl Sparkline<'_> {
                     let height = if max_height == 0 {
                         0
                     } else {
-                        *value * u64::from(spark_area.height) * 8 / max_height
+                        (*value).min(max_height) * u64::from(spark_area.height) * 8 / max_height
                     };
                     (height, None, *style)
                 }
@@ -570,6 +581,24 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█xxx"]));
     }
 
+    #[test]
+    fn it_draws_with_fixed_max() {
+        let data = [0, 2, 4];
+        let auto_scaled = render(Sparkline::default().data(data), 3);
+        assert_eq!(auto_scaled, Buffer::with_lines([" ▄█"]));
+        let fixed_max = render(Sparkline::default().data(data).max(8), 3);
+        assert_eq!(fixed_max, Buffer::with_lines([" ▂▄"]));
+    }
+
+    #[test]
+    fn it_clamps_values_above_max() {
+        let widget = Sparkline::default().data([4, 8, 16, u64::MAX]).max(8);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" ███", "████"]));
+    }
+
     #[test]
     fn it_draws_double_height() {
         let widget = Sparkline::default().data([0, 1, 2, 3, 4, 5, 6, 7, 8]);
@@ -579,6 +608,23 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["     ▂▄▆█xxx", " ▂▄▆█████xxx"]));
     }
 
+    #[test]
+    fn it_renders_absent_values_as_gaps() {
+        let widget = Sparkline::default().data([Some(2), None, Some(4), None, Some(0)]);
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        // the gaps are blank and the max is taken from the present values only
+        assert_eq!(buffer, Buffer::with_lines(["  █  x", "█ █  x"]));
+    }
+
+    #[test]
+    fn it_renders_only_absent_values() {
+        let widget = Sparkline::default().data([None, None]);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["  x"]));
+    }
+
     #[test]
     fn it_renders_left_to_right() {
         let widget = Sparkline::default()
@@ -0,0 +1,338 @@
+//! The [`Spinner`] widget displays an animated indicator for ongoing work.
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Line;
+use ratatui_core::widgets::Widget;
+
+use crate::block::{Block, BlockExt};
+
+/// Spinner frames made of braille characters (`⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏`)
+///
+/// This is the default set of frames of a [`Spinner`].
+pub const BRAILLE: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Spinner frames made of ASCII line characters (`-\|/`)
+pub const LINE: &[&str] = &["-", "\\", "|", "/"];
+
+/// Spinner frames made of quadrant block characters (`▖▘▝▗`)
+pub const QUADRANT: &[&str] = &["▖", "▘", "▝", "▗"];
+
+/// Spinner frames made of growing and shrinking vertical bars (`▁▃▅▇█▇▅▃`)
+pub const BAR: &[&str] = &["▁", "▃", "▅", "▇", "█", "▇", "▅", "▃"];
+
+/// A widget to display an animated spinner, optionally followed by a label.
+///
+/// A `Spinner` renders a single frame out of a set of frames. The frame to render is chosen with
+/// [`Spinner::frame_index`], which wraps around the number of frames. The spinner does not keep
+/// track of time, the application controls the animation by incrementing the frame index (e.g. on
+/// each tick of its event loop).
+///
+/// The frames default to [`BRAILLE`] and can be changed with [`Spinner::frames`]. Other frame sets
+/// are available in the [`spinner`](self) module.
+///
+/// The spinner is rendered on the first line of the area it is rendered in, with the label (if
+/// any) displayed after a space.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{spinner, Spinner};
+///
+/// # let tick = 0;
+/// Spinner::new()
+///     .frames(spinner::LINE)
+///     .frame_index(tick)
+///     .label("Loading...")
+///     .frame_style(Style::new().cyan());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Spinner<'a> {
+    block: Option<Block<'a>>,
+    frames: &'a [&'a str],
+    frame_index: usize,
+    label: Option<Line<'a>>,
+    style: Style,
+    frame_style: Style,
+}
+
+impl Default for Spinner<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Spinner<'a> {
+    /// Creates a new `Spinner` using the [`BRAILLE`] frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Spinner;
+    ///
+    /// let spinner = Spinner::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            frames: BRAILLE,
+            frame_index: 0,
+            label: None,
+            style: Style::new(),
+            frame_style: Style::new(),
+        }
+    }
+
+    /// Surrounds the `Spinner` with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the frames of the spinner.
+    ///
+    /// Each frame is expected to have the same width. If no frames are given, only the label is
+    /// rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Spinner;
+    ///
+    /// let spinner = Spinner::new().frames(&["◐", "◓", "◑", "◒"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frames(mut self, frames: &'a [&'a str]) -> Self {
+        self.frames = frames;
+        self
+    }
+
+    /// Sets the index of the frame to render.
+    ///
+    /// The index wraps around the number of frames, so it can be incremented indefinitely.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frame_index(mut self, frame_index: usize) -> Self {
+        self.frame_index = frame_index;
+        self
+    }
+
+    /// Sets the label displayed after the spinner.
+    ///
+    /// `label` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
+    ///
+    /// [`String`]: alloc::string::String
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the widget style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This will style the entire area, including the spinner and the label. It can be overridden
+    /// for the spinner frame with [`Spinner::frame_style`].
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the spinner frame.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn frame_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.frame_style = style.into();
+        self
+    }
+
+    /// Returns the frame that is rendered for the current frame index.
+    ///
+    /// Returns `None` if the spinner has no frames.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::{spinner, Spinner};
+    ///
+    /// let spinner = Spinner::new().frames(spinner::LINE).frame_index(5);
+    /// assert_eq!(spinner.current_frame(), Some("\\"));
+    /// ```
+    pub fn current_frame(&self) -> Option<&'a str> {
+        if self.frames.is_empty() {
+            return None;
+        }
+        Some(self.frames[self.frame_index % self.frames.len()])
+    }
+}
+
+impl Widget for Spinner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Spinner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let mut label_area = Rect { height: 1, ..inner };
+        if let Some(frame) = self.current_frame() {
+            let (x, _) = buf.set_stringn(
+                inner.x,
+                inner.y,
+                frame,
+                inner.width as usize,
+                self.frame_style,
+            );
+            // keep a space between the spinner and the label
+            let offset = (x - inner.x).saturating_add(1).min(inner.width);
+            label_area.x += offset;
+            label_area.width -= offset;
+        }
+        if let Some(label) = &self.label {
+            label.render(label_area, buf);
+        }
+    }
+}
+
+impl Styled for Spinner<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Modifier, Stylize};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let spinner = Spinner::new();
+        assert_eq!(spinner.frames, BRAILLE);
+        assert_eq!(spinner.frame_index, 0);
+        assert_eq!(spinner.label, None);
+        assert_eq!(spinner, Spinner::default());
+    }
+
+    #[rstest]
+    #[case::first(0, "⠋")]
+    #[case::second(1, "⠙")]
+    #[case::last(9, "⠏")]
+    #[case::wraparound(10, "⠋")]
+    #[case::wraparound_twice(23, "⠸")]
+    fn current_frame(#[case] frame_index: usize, #[case] expected: &str) {
+        let spinner = Spinner::new().frame_index(frame_index);
+        assert_eq!(spinner.current_frame(), Some(expected));
+    }
+
+    #[test]
+    fn current_frame_without_frames() {
+        let spinner = Spinner::new().frames(&[]);
+        assert_eq!(spinner.current_frame(), None);
+    }
+
+    #[rstest]
+    #[case::first(0, "-    ")]
+    #[case::third(2, "|    ")]
+    #[case::wraparound(4, "-    ")]
+    #[case::wraparound_last(7, "/    ")]
+    fn render_frame(#[case] frame_index: usize, #[case] expected: &str) {
+        let spinner = Spinner::new().frames(LINE).frame_index(frame_index);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        spinner.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_with_label() {
+        let spinner = Spinner::new().frame_index(3).label("Loading");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 2));
+        spinner.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["⠸ Loading   ", "            "]));
+    }
+
+    #[test]
+    fn render_label_without_frames() {
+        let spinner = Spinner::new().frames(&[]).label("Done");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        spinner.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["Done  "]));
+    }
+
+    #[test]
+    fn render_truncated() {
+        let spinner = Spinner::new().frames(&["<=>"]).label("Loading");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        (&spinner).render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["<=> Lo"]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        spinner.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["<="]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let spinner = Spinner::new()
+            .frames(LINE)
+            .label("Wait")
+            .style(Style::new().on_blue())
+            .frame_style(Style::new().yellow());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        spinner.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["- Wait ".on_blue()]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().yellow());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_with_block() {
+        let spinner = Spinner::new().frames(LINE).block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        spinner.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["┌──┐", "│- │", "└──┘"]));
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let spinner = Spinner::new().label("Loading");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        spinner.render(Rect::new(0, 0, 0, 0), &mut buf);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 5, 1)));
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Spinner::new().black().on_white().bold().style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        );
+    }
+}
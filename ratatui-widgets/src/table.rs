@@ -6,7 +6,7 @@ use alloc::vec::Vec;
 
 use itertools::Itertools;
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::{Constraint, Flex, Layout, Rect};
+use ratatui_core::layout::{Alignment, Constraint, Flex, Layout, Rect};
 use ratatui_core::style::{Style, Styled};
 use ratatui_core::text::Text;
 use ratatui_core::widgets::{StatefulWidget, Widget};
@@ -66,11 +66,14 @@ mod state;
 /// - [`Table::column_spacing`] sets the spacing between each column.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
 /// - [`Table::style`] sets the base style of the widget.
+/// - [`Table::row_striping`] sets alternating styles for even and odd rows.
 /// - [`Table::row_highlight_style`] sets the style of the selected row.
 /// - [`Table::column_highlight_style`] sets the style of the selected column.
 /// - [`Table::cell_highlight_style`] sets the style of the selected cell.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::freeze_header`] sets whether the header stays visible when scrolling.
+/// - [`Table::freeze_first_column`] sets whether the first column stays visible when scrolling.
 ///
 /// # Example
 ///
@@ -246,12 +249,21 @@ pub struct Table<'a> {
     /// Space between each column
     column_spacing: u16,
 
+    /// Default alignment of the content of the cells in each column
+    column_alignments: Vec<Alignment>,
+
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
 
     /// Base style for the widget
     style: Style,
 
+    /// Style used to render the rows with an even index
+    even_row_style: Style,
+
+    /// Style used to render the rows with an odd index
+    odd_row_style: Style,
+
     /// Style used to render the selected row
     row_highlight_style: Style,
 
@@ -269,6 +281,12 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Whether the header stays visible when the rows are scrolled
+    freeze_header: bool,
+
+    /// Whether the first column stays visible when the columns are scrolled
+    freeze_first_column: bool,
 }
 
 impl Default for Table<'_> {
@@ -279,14 +297,19 @@ impl Default for Table<'_> {
             footer: None,
             widths: Vec::new(),
             column_spacing: 1,
+            column_alignments: Vec::new(),
             block: None,
             style: Style::new(),
+            even_row_style: Style::new(),
+            odd_row_style: Style::new(),
             row_highlight_style: Style::new(),
             column_highlight_style: Style::new(),
             cell_highlight_style: Style::new(),
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            freeze_header: true,
+            freeze_first_column: false,
         }
     }
 }
@@ -467,6 +490,37 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the alignment of the content of the cells in each column
+    ///
+    /// The alignments are applied to the columns in order, including the cells of the header and
+    /// footer. Columns without an alignment keep the default left alignment. An alignment set on
+    /// the [`Text`] or the [`Line`]s of a cell takes precedence over the alignment of its column.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::{Alignment, Constraint};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["Apples", "3"]), Row::new(["Oranges", "12"])];
+    /// let widths = [Constraint::Length(10), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .header(Row::new(["Fruit", "Count"]))
+    ///     .column_alignments([Alignment::Left, Alignment::Right]);
+    /// ```
+    ///
+    /// [`Line`]: ratatui_core::text::Line
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_alignments<I>(mut self, alignments: I) -> Self
+    where
+        I: IntoIterator<Item = Alignment>,
+    {
+        self.column_alignments = alignments.into_iter().collect();
+        self
+    }
+
     /// Wraps the table with a custom [`Block`] widget.
     ///
     /// The `block` parameter is of type [`Block`]. This holds the specified block to be
@@ -534,6 +588,38 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set alternating styles for the rows of the table (zebra striping)
+    ///
+    /// `even` and `odd` accept any type that is convertible to [`Style`] (e.g. [`Style`],
+    /// [`Color`], or your own type that implements [`Into<Style>`]).
+    ///
+    /// Rows are striped by their index in the table rows, starting with an even row at index `0`.
+    /// The header and footer are not striped and do not affect whether a row is even or odd, and
+    /// neither does scrolling. The stripe style is applied beneath the row's own
+    /// [`Row::style`], and the selection highlight styles are applied on top of both.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).row_striping(Style::new(), Style::new().on_dark_gray());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_striping<E: Into<Style>, O: Into<Style>>(mut self, even: E, odd: O) -> Self {
+        self.even_row_style = even.into();
+        self.odd_row_style = odd.into();
+        self
+    }
+
     /// Set the style of the selected row
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -720,6 +806,56 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Set whether the header stays visible when the rows are scrolled
+    ///
+    /// This is `true` by default. When set to `false`, the header scrolls along with the rows and
+    /// is only displayed while the first row is visible (i.e. when the rows are not scrolled).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .header(Row::new(vec!["Head1", "Head2"]))
+    ///     .freeze_header(false);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn freeze_header(mut self, freeze: bool) -> Self {
+        self.freeze_header = freeze;
+        self
+    }
+
+    /// Set whether the first column stays visible when the columns are scrolled
+    ///
+    /// This is `false` by default. The columns are scrolled horizontally using
+    /// [`TableState::column_offset_mut`]. When set to `true`, the first column is pinned to the
+    /// left of the table and the scrolled columns are displayed after it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::layout::Constraint;
+    /// use ratatui::widgets::{Row, Table, TableState};
+    ///
+    /// # let rows = [Row::new(vec!["Name", "Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5); 3];
+    /// let table = Table::new(rows, widths).freeze_first_column(true);
+    /// let mut state = TableState::new().with_column_offset(2); // displays columns 0 and 2
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn freeze_first_column(mut self, freeze: bool) -> Self {
+        self.freeze_first_column = freeze;
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -769,10 +905,25 @@ impl StatefulWidget for &Table<'_> {
         if column_count == 0 {
             state.select_column(None);
         }
+        state.column_offset = state.column_offset.min(column_count.saturating_sub(1));
 
         let selection_width = self.selection_width(state);
-        let column_widths = self.get_column_widths(table_area.width, selection_width, column_count);
-        let (header_area, rows_area, footer_area) = self.layout(table_area);
+        let column_widths = self.get_visible_column_widths(
+            table_area.width,
+            selection_width,
+            column_count,
+            state.column_offset,
+        );
+        let (mut header_area, mut rows_area, footer_area) = self.layout(table_area, true);
+        if !self.freeze_header {
+            let (offset, _) = self.visible_rows(state, rows_area);
+            if offset > 0 {
+                // the header scrolls out of view along with the first row. The rows never scroll
+                // back before this offset, so the first row stays hidden in the taller rows area.
+                state.offset = offset;
+                (header_area, rows_area, _) = self.layout(table_area, false);
+            }
+        }
 
         self.render_header(header_area, buf, &column_widths);
 
@@ -785,10 +936,13 @@ impl StatefulWidget for &Table<'_> {
 // private methods for rendering
 impl Table<'_> {
     /// Splits the table area into a header, rows area and a footer
-    fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
-        let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
-        let header_height = self.header.as_ref().map_or(0, |h| h.height);
-        let header_bottom_margin = self.header.as_ref().map_or(0, |h| h.bottom_margin);
+    ///
+    /// When `show_header` is false, no space is allocated for the header.
+    fn layout(&self, area: Rect, show_header: bool) -> (Rect, Rect, Rect) {
+        let header = self.header.as_ref().filter(|_| show_header);
+        let header_top_margin = header.map_or(0, |h| h.top_margin);
+        let header_height = header.map_or(0, |h| h.height);
+        let header_bottom_margin = header.map_or(0, |h| h.bottom_margin);
         let footer_top_margin = self.footer.as_ref().map_or(0, |h| h.top_margin);
         let footer_height = self.footer.as_ref().map_or(0, |f| f.height);
         let footer_bottom_margin = self.footer.as_ref().map_or(0, |h| h.bottom_margin);
@@ -806,21 +960,17 @@ impl Table<'_> {
         (header_area, rows_area, footer_area)
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(usize, u16, u16)]) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
-            }
+            self.render_cells(&header.cells, area, buf, column_widths);
         }
     }
 
-    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(usize, u16, u16)]) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
-            }
+            self.render_cells(&footer.cells, area, buf, column_widths);
         }
     }
 
@@ -830,7 +980,7 @@ impl Table<'_> {
         buf: &mut Buffer,
         state: &mut TableState,
         selection_width: u16,
-        columns_widths: &[(u16, u16)],
+        columns_widths: &[(usize, u16, u16)],
     ) {
         if self.rows.is_empty() {
             return;
@@ -852,6 +1002,12 @@ impl Table<'_> {
             let y = area.y + y_offset + row.top_margin;
             let height = (y + row.height).min(area.bottom()).saturating_sub(y);
             let row_area = Rect { y, height, ..area };
+            let stripe_style = if i % 2 == 0 {
+                self.even_row_style
+            } else {
+                self.odd_row_style
+            };
+            buf.set_style(row_area, stripe_style);
             buf.set_style(row_area, row.style);
 
             let is_selected = state.selected.is_some_and(|index| index == i);
@@ -863,12 +1019,7 @@ impl Table<'_> {
                 buf.set_style(selection_area, row.style);
                 (&self.highlight_symbol).render(selection_area, buf);
             }
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
-            }
+            self.render_cells(&row.cells, row_area, buf, columns_widths);
             if is_selected {
                 selected_row_area = Some(row_area);
             }
@@ -878,11 +1029,14 @@ impl Table<'_> {
         let selected_column_area = state.selected_column.and_then(|s| {
             // The selection is clamped by the column count. Since a user can manually specify an
             // incorrect number of widths, we should use panic free methods.
-            columns_widths.get(s).map(|(x, width)| Rect {
-                x: x + area.x,
-                width: *width,
-                ..area
-            })
+            columns_widths
+                .iter()
+                .find(|(column, _, _)| *column == s)
+                .map(|(_, x, width)| Rect {
+                    x: x + area.x,
+                    width: *width,
+                    ..area
+                })
         });
 
         match (selected_row_area, selected_column_area) {
@@ -922,7 +1076,7 @@ impl Table<'_> {
         let mut height = 0;
 
         for item in self.rows.iter().skip(start) {
-            if height + item.height > area.height {
+            if height + item.height_with_top_margin() > area.height {
                 break;
             }
             height += item.height_with_margin();
@@ -936,7 +1090,9 @@ impl Table<'_> {
             while selected >= end {
                 height = height.saturating_add(self.rows[end].height_with_margin());
                 end += 1;
-                while height > area.height {
+                // the bottom margin of the selected row does not need to be visible
+                let bottom_margin = self.rows[selected].bottom_margin;
+                while height.saturating_sub(bottom_margin) > area.height {
                     height = height.saturating_sub(self.rows[start].height_with_margin());
                     start += 1;
                 }
@@ -951,31 +1107,58 @@ impl Table<'_> {
         (start, end)
     }
 
-    /// Get all offsets and widths of all user specified columns.
+    /// Get the index, offset and width of the columns that are visible with the given column
+    /// offset.
     ///
-    /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
-    /// and a default of equal widths is returned.
-    fn get_column_widths(
+    /// Returns (column, x, width). Columns before `column_offset` are skipped, except for the first
+    /// column when `freeze_first_column` is set.
+    fn get_visible_column_widths(
         &self,
         max_width: u16,
         selection_width: u16,
         col_count: usize,
-    ) -> Vec<(u16, u16)> {
+        column_offset: usize,
+    ) -> Vec<(usize, u16, u16)> {
         let widths = if self.widths.is_empty() {
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
         };
+        let frozen = usize::from(self.freeze_first_column && column_offset > 0);
+        let columns = (0..frozen)
+            .chain(column_offset.max(frozen)..widths.len())
+            .collect_vec();
         // this will always allocate a selection area
         let [_selection_area, columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
                 .areas(Rect::new(0, 0, max_width, 1));
-        let rects = Layout::horizontal(widths)
+        let rects = Layout::horizontal(columns.iter().map(|&column| widths[column]))
             .flex(self.flex)
             .spacing(self.column_spacing)
             .split(columns_area);
-        rects.iter().map(|c| (c.x, c.width)).collect()
+        columns
+            .into_iter()
+            .zip(rects.iter())
+            .map(|(column, c)| (column, c.x, c.width))
+            .collect()
+    }
+
+    /// Renders the cells of a row, header or footer in the columns given by `column_widths`
+    fn render_cells(
+        &self,
+        cells: &[Cell],
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(usize, u16, u16)],
+    ) {
+        for &(column, x, width) in column_widths {
+            if let Some(cell) = cells.get(column) {
+                let cell_area = Rect::new(area.x + x, area.y, width, area.height);
+                let alignment = self.column_alignments.get(column).copied();
+                cell.render(cell_area, buf, alignment);
+            }
+        }
     }
 
     fn column_count(&self) -> usize {
@@ -1189,6 +1372,15 @@ mod tests {
         assert_eq!(table.cell_highlight_style, style);
     }
 
+    #[test]
+    fn row_striping() {
+        let even = Style::default().on_black();
+        let odd = Style::default().on_dark_gray();
+        let table = Table::default().row_striping(even, odd);
+        assert_eq!(table.even_row_style, even);
+        assert_eq!(table.odd_row_style, odd);
+    }
+
     #[test]
     fn highlight_symbol() {
         let table = Table::default().highlight_symbol(">>");
@@ -1201,6 +1393,20 @@ mod tests {
         assert_eq!(table.highlight_spacing, HighlightSpacing::Always);
     }
 
+    #[test]
+    fn freeze_header() {
+        assert!(Table::default().freeze_header);
+        let table = Table::default().freeze_header(false);
+        assert!(!table.freeze_header);
+    }
+
+    #[test]
+    fn freeze_first_column() {
+        assert!(!Table::default().freeze_first_column);
+        let table = Table::default().freeze_first_column(true);
+        assert!(table.freeze_first_column);
+    }
+
     #[test]
     #[should_panic = "Percentages should be between 0 and 100 inclusively"]
     fn table_invalid_percentages() {
@@ -1371,6 +1577,61 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_column_alignments() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
+            let header = Row::new(["Name", "Qty", "Price"]);
+            let footer = Row::new(["Total", "13", "4.5"]);
+            let rows = [
+                Row::new(["Apple", "1", "0.5"]),
+                Row::new(["Pear", "12", "4.0"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(6); 3])
+                .header(header)
+                .footer(footer)
+                .column_alignments([Alignment::Left, Alignment::Right, Alignment::Center]);
+            Widget::render(table, Rect::new(0, 0, 20, 4), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name      Qty Price ",
+                "Apple       1  0.5  ",
+                "Pear       12  4.0  ",
+                "Total      13  4.5  ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_alignments_overridden_by_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 2));
+            let rows = [
+                Row::new([Text::from("1"), Text::from("2")]),
+                Row::new([
+                    Text::from("3").left_aligned(),
+                    Line::from("4").centered().into(),
+                ]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(6); 2])
+                .column_alignments([Alignment::Right, Alignment::Right]);
+            Widget::render(table, Rect::new(0, 0, 13, 2), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "     1      2",
+                "3        4   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_fewer_column_alignments_than_columns() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let rows = [Row::new(["1", "2"])];
+            let table =
+                Table::new(rows, [Constraint::Length(5); 2]).column_alignments([Alignment::Right]);
+            Widget::render(table, Rect::new(0, 0, 11, 1), &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["    1 2    "]));
+        }
+
         #[test]
         fn render_with_footer() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1462,6 +1723,282 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_row_top_margin() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 4));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]).top_margin(1),
+                Row::new(vec!["Cell3", "Cell4"]).top_margin(1),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]);
+            Widget::render(table, Rect::new(0, 0, 15, 4), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "               ",
+                "Cell1 Cell2    ",
+                "               ",
+                "Cell3 Cell4    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_selected_row_margins_not_highlighted() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 5));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"])
+                    .top_margin(1)
+                    .bottom_margin(1),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .row_highlight_style(Style::new().red())
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(Some(1));
+            StatefulWidget::render(table, Rect::new(0, 0, 13, 5), &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                "  Cell1 Cell2".into(),
+                "             ".into(),
+                ">>Cell3 Cell4".red(),
+                "             ".into(),
+                "  Cell5 Cell6".into(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        /// The selected row must be scrolled into view including its top margin, while its bottom
+        /// margin may be cut off.
+        #[rstest]
+        #[case::top_margin_fits(Row::new(["Cell"]).top_margin(1), 4, 1, 0)]
+        #[case::top_margin_scrolls(Row::new(["Cell"]).top_margin(1), 3, 1, 1)]
+        #[case::top_margin_last_row(Row::new(["Cell"]).top_margin(1), 4, 3, 2)]
+        #[case::bottom_margin_fits(Row::new(["Cell"]).bottom_margin(1), 3, 1, 0)]
+        #[case::bottom_margin_scrolls(Row::new(["Cell"]).bottom_margin(1), 3, 2, 1)]
+        #[case::bottom_margin_last_row(Row::new(["Cell"]).bottom_margin(1), 3, 3, 2)]
+        fn scroll_to_selected_row_with_margin(
+            #[case] row: Row<'static>,
+            #[case] height: u16,
+            #[case] selected: usize,
+            #[case] expected_offset: usize,
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, height));
+            let table = Table::new(vec![row; 4], [Constraint::Length(4)]);
+            let mut state = TableState::new().with_selected(Some(selected));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.offset(), expected_offset);
+        }
+
+        #[test]
+        fn render_with_row_striping() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .header(Row::new(vec!["Head1", "Head2"]))
+                .row_striping(Style::new().on_black(), Style::new().on_dark_gray());
+            Widget::render(table, Rect::new(0, 0, 11, 4), &mut buf);
+            let expected = Buffer::with_lines([
+                "Head1 Head2".into(),
+                "Cell1 Cell2".on_black(),
+                "Cell3 Cell4".on_dark_gray(),
+                "Cell5 Cell6".on_black(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_striping_and_offset() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1"]),
+                Row::new(vec!["Cell2"]),
+                Row::new(vec!["Cell3"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .row_striping(Style::new().on_black(), Style::new().on_dark_gray());
+            let mut state = TableState::new().with_offset(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 5, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(["Cell2".on_dark_gray(), "Cell3".on_black()]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_striping_beneath_row_style_and_selection() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1"]).red(),
+                Row::new(vec!["Cell2"]),
+                Row::new(vec!["Cell3"]).on_blue(),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .row_striping(Style::new().on_black(), Style::new().on_dark_gray())
+                .row_highlight_style(Style::new().bold());
+            let mut state = TableState::new().with_selected(Some(1));
+            StatefulWidget::render(table, Rect::new(0, 0, 5, 3), &mut buf, &mut state);
+            let expected = Buffer::with_lines([
+                "Cell1".red().on_black(),
+                "Cell2".bold().on_dark_gray(),
+                "Cell3".on_blue(),
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        fn scrollable_table() -> Table<'static> {
+            let rows = vec![
+                Row::new(vec!["Row1", "A1", "B1", "C1"]),
+                Row::new(vec!["Row2", "A2", "B2", "C2"]),
+            ];
+            Table::new(
+                rows,
+                [
+                    Constraint::Length(4),
+                    Constraint::Length(2),
+                    Constraint::Length(2),
+                    Constraint::Length(2),
+                ],
+            )
+            .header(Row::new(vec!["Name", "A", "B", "C"]))
+        }
+
+        #[test]
+        fn render_with_column_offset() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            let mut state = TableState::new().with_column_offset(2);
+            StatefulWidget::render(scrollable_table(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "B  C      ",
+                "B1 C1     ",
+                "B2 C2     ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_frozen_first_column() {
+            let table = scrollable_table().freeze_first_column(true);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 13, 3));
+            let mut state = TableState::new();
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name A  B  C ",
+                "Row1 A1 B1 C1",
+                "Row2 A2 B2 C2",
+            ]);
+            assert_eq!(buf, expected);
+
+            *state.column_offset_mut() = 2;
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name B  C ",
+                "Row1 B1 C1",
+                "Row2 B2 C2",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_frozen_first_column_and_selected_column() {
+            let table = scrollable_table()
+                .freeze_first_column(true)
+                .column_highlight_style(Style::new().red());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            let mut state = TableState::new()
+                .with_column_offset(3)
+                .with_selected_column(3);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["Name C    ", "Row1 C1   ", "Row2 C2   "]);
+            expected.set_style(Rect::new(5, 1, 2, 2), Style::new().red());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_offset_out_of_bounds() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+            let mut state = TableState::new().with_column_offset(10);
+            StatefulWidget::render(scrollable_table(), buf.area, &mut buf, &mut state);
+            assert_eq!(state.column_offset, 3);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "C         ",
+                "C1        ",
+                "C2        ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[rstest]
+        #[case::frozen(true, ["Name A  B  ", "Row2 A2 B2 "])]
+        #[case::not_frozen(false, ["Row2 A2 B2 ", "Row3 A3 B3 "])]
+        fn render_with_freeze_header(#[case] freeze: bool, #[case] expected: [&str; 2]) {
+            let rows = vec![
+                Row::new(vec!["Row1", "A1", "B1"]),
+                Row::new(vec!["Row2", "A2", "B2"]),
+                Row::new(vec!["Row3", "A3", "B3"]),
+            ];
+            let table = Table::new(
+                rows,
+                [
+                    Constraint::Length(4),
+                    Constraint::Length(2),
+                    Constraint::Length(2),
+                ],
+            )
+            .header(Row::new(vec!["Name", "A", "B"]))
+            .freeze_header(freeze);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let mut state = TableState::new().with_offset(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(expected));
+        }
+
+        #[test]
+        fn render_with_unfrozen_header_selected_at_boundary() {
+            // the selected row fits in the table area with the first row, but not with the header
+            let rows = (1..=5).map(|i| Row::new(vec![format!("Row{i}")]));
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .header(Row::new(vec!["Name"]))
+                .freeze_header(false);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+            let mut state = TableState::new().with_selected(Some(3));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Row2", "Row3", "Row4", "Row5"]));
+            assert_eq!(state.offset(), 1);
+        }
+
+        #[test]
+        fn render_with_unfrozen_header_not_scrolled() {
+            let rows = vec![Row::new(vec!["Row1"]), Row::new(vec!["Row2"])];
+            let table = Table::new(rows, [Constraint::Length(4)])
+                .header(Row::new(vec!["Name"]))
+                .freeze_header(false);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+            Widget::render(table, buf.area, &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Name", "Row1"]));
+        }
+
+        #[test]
+        fn render_with_multi_span_cell_truncated() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let status = Line::from(vec!["[".into(), "FAILED".red().bold(), "]".into()]);
+            let rows = vec![Row::new(vec![Cell::from(status), Cell::from("next")])];
+            let table = Table::new(rows, [Constraint::Length(6), Constraint::Length(4)]);
+            Widget::render(table, buf.area, &mut buf);
+            let expected = Buffer::with_lines([Line::from(vec![
+                "[".into(),
+                "FAILE".red().bold(),
+                " next".into(),
+            ])]);
+            assert_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_tall_row() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 23, 3));
@@ -1676,15 +2213,24 @@ mod tests {
         fn length_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_column_widths(20, 0, 0), [(0, 4), (5, 4)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 0, 0, 0),
+                [(0, 0, 4), (1, 5, 4)]
+            );
 
             // with selection, more than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_column_widths(20, 3, 0), [(3, 4), (8, 4)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 3, 0, 0),
+                [(0, 3, 4), (1, 8, 4)]
+            );
 
             // without selection, less than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_column_widths(7, 0, 0), [(0, 3), (4, 3)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 0, 0, 0),
+                [(0, 0, 3), (1, 4, 3)]
+            );
 
             // with selection, less than needed width
             // <--------7px-------->
@@ -1693,26 +2239,41 @@ mod tests {
             // └────────┘x└────────┘
             // column spacing (i.e. `x`) is always prioritized
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_column_widths(7, 3, 0), [(3, 2), (6, 1)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 3, 0, 0),
+                [(0, 3, 2), (1, 6, 1)]
+            );
         }
 
         #[test]
         fn max_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_column_widths(20, 0, 0), [(0, 4), (5, 4)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 0, 0, 0),
+                [(0, 0, 4), (1, 5, 4)]
+            );
 
             // with selection, more than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_column_widths(20, 3, 0), [(3, 4), (8, 4)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 3, 0, 0),
+                [(0, 3, 4), (1, 8, 4)]
+            );
 
             // without selection, less than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_column_widths(7, 0, 0), [(0, 3), (4, 3)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 0, 0, 0),
+                [(0, 0, 3), (1, 4, 3)]
+            );
 
             // with selection, less than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_column_widths(7, 3, 0), [(3, 2), (6, 1)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 3, 0, 0),
+                [(0, 3, 2), (1, 6, 1)]
+            );
         }
 
         #[test]
@@ -1723,42 +2284,66 @@ mod tests {
 
             // without selection, more than needed width
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_column_widths(20, 0, 0), [(0, 10), (11, 9)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 0, 0, 0),
+                [(0, 0, 10), (1, 11, 9)]
+            );
 
             // with selection, more than needed width
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_column_widths(20, 3, 0), [(3, 8), (12, 8)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 3, 0, 0),
+                [(0, 3, 8), (1, 12, 8)]
+            );
 
             // without selection, less than needed width
             // allocates spacer
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_column_widths(7, 0, 0), [(0, 3), (4, 3)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 0, 0, 0),
+                [(0, 0, 3), (1, 4, 3)]
+            );
 
             // with selection, less than needed width
             // always allocates selection and spacer
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_column_widths(7, 3, 0), [(3, 2), (6, 1)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 3, 0, 0),
+                [(0, 3, 2), (1, 6, 1)]
+            );
         }
 
         #[test]
         fn percentage_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_column_widths(20, 0, 0), [(0, 6), (7, 6)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 0, 0, 0),
+                [(0, 0, 6), (1, 7, 6)]
+            );
 
             // with selection, more than needed width
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_column_widths(20, 3, 0), [(3, 5), (9, 5)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 3, 0, 0),
+                [(0, 3, 5), (1, 9, 5)]
+            );
 
             // without selection, less than needed width
             // rounds from positions: [0.0, 0.0, 2.1, 3.1, 5.2, 7.0]
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_column_widths(7, 0, 0), [(0, 2), (3, 2)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 0, 0, 0),
+                [(0, 0, 2), (1, 3, 2)]
+            );
 
             // with selection, less than needed width
             // rounds from positions: [0.0, 3.0, 5.1, 6.1, 7.0, 7.0]
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_column_widths(7, 3, 0), [(3, 1), (5, 1)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 3, 0, 0),
+                [(0, 3, 1), (1, 5, 1)]
+            );
         }
 
         #[test]
@@ -1766,22 +2351,34 @@ mod tests {
             // without selection, more than needed width
             // rounds from positions: [0.00, 0.00, 6.67, 7.67, 14.33]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_column_widths(20, 0, 0), [(0, 7), (8, 6)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 0, 0, 0),
+                [(0, 0, 7), (1, 8, 6)]
+            );
 
             // with selection, more than needed width
             // rounds from positions: [0.00, 3.00, 10.67, 17.33, 20.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_column_widths(20, 3, 0), [(3, 6), (10, 5)]);
+            assert_eq!(
+                table.get_visible_column_widths(20, 3, 0, 0),
+                [(0, 3, 6), (1, 10, 5)]
+            );
 
             // without selection, less than needed width
             // rounds from positions: [0.00, 2.33, 3.33, 5.66, 7.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_column_widths(7, 0, 0), [(0, 2), (3, 3)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 0, 0, 0),
+                [(0, 0, 2), (1, 3, 3)]
+            );
 
             // with selection, less than needed width
             // rounds from positions: [0.00, 3.00, 5.33, 6.33, 7.00, 7.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_column_widths(7, 3, 0), [(3, 1), (5, 2)]);
+            assert_eq!(
+                table.get_visible_column_widths(7, 3, 0, 0),
+                [(0, 3, 1), (1, 5, 2)]
+            );
         }
 
         /// When more width is available than requested, the behavior is controlled by flex
@@ -1789,24 +2386,24 @@ mod tests {
         fn underconstrained_flex() {
             let table = Table::default().widths([Min(10), Min(10), Min(1)]);
             assert_eq!(
-                table.get_column_widths(62, 0, 0),
-                &[(0, 20), (21, 20), (42, 20)]
+                table.get_visible_column_widths(62, 0, 0, 0),
+                [(0, 0, 20), (1, 21, 20), (2, 42, 20)]
             );
 
             let table = Table::default()
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::Legacy);
             assert_eq!(
-                table.get_column_widths(62, 0, 0),
-                &[(0, 10), (11, 10), (22, 40)]
+                table.get_visible_column_widths(62, 0, 0, 0),
+                [(0, 0, 10), (1, 11, 10), (2, 22, 40)]
             );
 
             let table = Table::default()
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::SpaceBetween);
             assert_eq!(
-                table.get_column_widths(62, 0, 0),
-                &[(0, 20), (21, 20), (42, 20)]
+                table.get_visible_column_widths(62, 0, 0, 0),
+                [(0, 0, 20), (1, 21, 20), (2, 42, 20)]
             );
         }
 
@@ -1814,16 +2411,16 @@ mod tests {
         fn underconstrained_segment_size() {
             let table = Table::default().widths([Min(10), Min(10), Min(1)]);
             assert_eq!(
-                table.get_column_widths(62, 0, 0),
-                &[(0, 20), (21, 20), (42, 20)]
+                table.get_visible_column_widths(62, 0, 0, 0),
+                [(0, 0, 20), (1, 21, 20), (2, 42, 20)]
             );
 
             let table = Table::default()
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::Legacy);
             assert_eq!(
-                table.get_column_widths(62, 0, 0),
-                &[(0, 10), (11, 10), (22, 40)]
+                table.get_visible_column_widths(62, 0, 0, 0),
+                [(0, 0, 10), (1, 11, 10), (2, 22, 40)]
             );
         }
 
@@ -1839,8 +2436,8 @@ mod tests {
                 .footer(Row::new(vec!["h", "i"]))
                 .column_spacing(0);
             assert_eq!(
-                table.get_column_widths(30, 0, 3),
-                &[(0, 10), (10, 10), (20, 10)]
+                table.get_visible_column_widths(30, 0, 3, 0),
+                [(0, 0, 10), (1, 10, 10), (2, 20, 10)]
             );
         }
 
@@ -1850,7 +2447,10 @@ mod tests {
                 .rows(vec![])
                 .header(Row::new(vec!["f", "g"]))
                 .column_spacing(0);
-            assert_eq!(table.get_column_widths(10, 0, 2), [(0, 5), (5, 5)]);
+            assert_eq!(
+                table.get_visible_column_widths(10, 0, 2, 0),
+                [(0, 0, 5), (1, 5, 5)]
+            );
         }
 
         #[test]
@@ -1859,7 +2459,10 @@ mod tests {
                 .rows(vec![])
                 .footer(Row::new(vec!["h", "i"]))
                 .column_spacing(0);
-            assert_eq!(table.get_column_widths(10, 0, 2), [(0, 5), (5, 5)]);
+            assert_eq!(
+                table.get_visible_column_widths(10, 0, 2, 0),
+                [(0, 0, 5), (1, 5, 5)]
+            );
         }
 
         #[track_caller]
@@ -1,5 +1,5 @@
 use ratatui_core::buffer::Buffer;
-use ratatui_core::layout::Rect;
+use ratatui_core::layout::{Alignment, Rect};
 use ratatui_core::style::{Style, Styled};
 use ratatui_core::text::Text;
 use ratatui_core::widgets::Widget;
@@ -13,6 +13,12 @@ use ratatui_core::widgets::Widget;
 ///
 /// You can use [`Text::alignment`] when creating a cell to align its content.
 ///
+/// The content may contain several [`Span`]s with their own styles (e.g. a colored status word in
+/// the middle of some text). Each span is rendered with its own style, and content wider than the
+/// column is truncated at the column boundary based on its display width.
+///
+/// [`Span`]: ratatui_core::text::Span
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -153,9 +159,15 @@ impl<'a> Cell<'a> {
 }
 
 impl Cell<'_> {
-    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer) {
+    /// Renders the cell, using `alignment` if the content does not define its own alignment
+    pub(crate) fn render(&self, area: Rect, buf: &mut Buffer, alignment: Option<Alignment>) {
         buf.set_style(area, self.style);
-        Widget::render(&self.content, area, buf);
+        match alignment {
+            Some(alignment) if self.content.alignment.is_none() => {
+                Widget::render(self.content.clone().alignment(alignment), area, buf);
+            }
+            _ => Widget::render(&self.content, area, buf),
+        }
     }
 }
 
@@ -185,7 +197,10 @@ impl Styled for Cell<'_> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+
     use ratatui_core::style::{Color, Modifier, Stylize};
+    use ratatui_core::text::{Line, Span};
 
     use super::*;
 
@@ -201,6 +216,43 @@ mod tests {
         assert_eq!(cell.content, Text::from(""));
     }
 
+    #[test]
+    fn from_line() {
+        let line = Line::from(vec![Span::raw("status: "), Span::from("ok").green()]);
+        let cell = Cell::from(line.clone());
+        assert_eq!(cell.content, Text::from(line));
+    }
+
+    #[test]
+    fn from_text() {
+        let text = Text::from(vec![Line::from("one"), Line::from("two".red())]);
+        let cell = Cell::from(text.clone());
+        assert_eq!(cell.content, text);
+    }
+
+    #[test]
+    fn render_multi_span_truncated() {
+        let cell = Cell::from(Line::from(vec![
+            Span::raw("a "),
+            Span::from("red").red(),
+            Span::raw(" b"),
+        ]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        cell.render(buf.area, &mut buf, None);
+        let expected =
+            Buffer::with_lines([Line::from(vec![Span::raw("a "), Span::from("re").red()])]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_wide_char_truncated() {
+        // the wide char does not fit in the remaining column and is not rendered
+        let cell = Cell::from(Line::from(vec![Span::raw("ab"), Span::from("コ").red()]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        cell.render(buf.area, &mut buf, None);
+        assert_eq!(buf, Buffer::with_lines(["ab "]));
+    }
+
     #[test]
     fn style() {
         let style = Style::default().red().italic();
@@ -249,6 +249,13 @@ impl Row<'_> {
             .saturating_add(self.top_margin)
             .saturating_add(self.bottom_margin)
     }
+
+    /// Returns the height of the row including its top margin but not its bottom margin.
+    ///
+    /// This is the height needed for the content of the row to be fully visible.
+    pub(crate) const fn height_with_top_margin(&self) -> u16 {
+        self.height.saturating_add(self.top_margin)
+    }
 }
 
 impl Styled for Row<'_> {
@@ -5,13 +5,15 @@
 /// table will be shifted to ensure that the selected row is visible. This will modify the
 /// [`TableState`] object passed to the `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of the following fields:
 /// - [`offset`]: the index of the first row to be displayed
+/// - [`column_offset`]: the index of the first column to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 /// - [`selected_column`]: the index of the selected column, which can be `None` if no column is
 ///   selected
 ///
 /// [`offset`]: TableState::offset()
+/// [`column_offset`]: TableState::column_offset()
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
 ///
@@ -54,10 +56,20 @@
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub(crate) column_offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
 }
 
+/// Skips serializing the column offset of states that don't scroll horizontally, so that they
+/// serialize the same as before the column offset was added.
+#[cfg(feature = "serde")]
+#[expect(clippy::trivially_copy_pass_by_ref)] // required by serde
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
 impl TableState {
     /// Creates a new [`TableState`]
     ///
@@ -71,6 +83,7 @@ impl TableState {
     pub const fn new() -> Self {
         Self {
             offset: 0,
+            column_offset: 0,
             selected: None,
             selected_column: None,
         }
@@ -93,6 +106,23 @@ impl TableState {
         self
     }
 
+    /// Sets the index of the first column to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_column_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -186,6 +216,39 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Index of the first column to be displayed
+    ///
+    /// Columns before this index are scrolled out of view, except for the first column when
+    /// [`Table::freeze_first_column`] is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_offset(), 0);
+    /// ```
+    ///
+    /// [`Table::freeze_first_column`]: crate::table::Table::freeze_first_column
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the index of the first column to be displayed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// *state.column_offset_mut() = 1;
+    /// ```
+    pub const fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -541,6 +604,7 @@ mod tests {
     fn new() {
         let state = TableState::new();
         assert_eq!(state.offset, 0);
+        assert_eq!(state.column_offset, 0);
         assert_eq!(state.selected, None);
         assert_eq!(state.selected_column, None);
     }
@@ -551,6 +615,12 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn with_column_offset() {
+        let state = TableState::new().with_column_offset(1);
+        assert_eq!(state.column_offset, 1);
+    }
+
     #[test]
     fn with_selected() {
         let state = TableState::new().with_selected(Some(1));
@@ -583,6 +653,19 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn column_offset() {
+        let state = TableState::new().with_column_offset(2);
+        assert_eq!(state.column_offset(), 2);
+    }
+
+    #[test]
+    fn column_offset_mut() {
+        let mut state = TableState::new();
+        *state.column_offset_mut() = 1;
+        assert_eq!(state.column_offset, 1);
+    }
+
     #[test]
     fn selected() {
         let state = TableState::new();
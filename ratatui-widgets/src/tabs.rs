@@ -1,4 +1,5 @@
 //! The [`Tabs`] widget displays a horizontal set of tabs with a single tab selected.
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
 use itertools::Itertools;
@@ -9,16 +10,21 @@ use ratatui_core::symbols;
 use ratatui_core::text::{Line, Span};
 use ratatui_core::widgets::Widget;
 
+pub use self::state::TabsState;
 use crate::block::{Block, BlockExt};
 
+mod state;
+
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
 
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
 /// Each tab title is stored as a [`Line`] which can be individually styled. The selected tab is set
-/// using [`Tabs::select`] and styled using [`Tabs::highlight_style`]. The divider can be customized
-/// with [`Tabs::divider`]. Padding can be set with [`Tabs::padding`] or [`Tabs::padding_left`] and
-/// [`Tabs::padding_right`].
+/// using [`Tabs::select`] and styled using [`Tabs::highlight_style`]. Individual tabs can be styled
+/// with [`Tabs::tab_style_for`]. The divider can be customized with [`Tabs::divider`]. Padding can
+/// be set with [`Tabs::padding`] or [`Tabs::padding_left`] and [`Tabs::padding_right`].
+///
+/// [`TabsState`] can be used to keep track of the selected tab when navigating with the keyboard.
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
@@ -58,6 +64,8 @@ pub struct Tabs<'a> {
     style: Style,
     /// Style to apply to the selected item
     highlight_style: Style,
+    /// Styles to apply to individual tabs, by index
+    tab_styles: BTreeMap<usize, Style>,
     /// Tab divider
     divider: Span<'a>,
     /// Tab Left Padding
@@ -138,6 +146,7 @@ impl<'a> Tabs<'a> {
             selected,
             style: Style::default(),
             highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            tab_styles: BTreeMap::new(),
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
@@ -252,9 +261,37 @@ impl<'a> Tabs<'a> {
         self
     }
 
+    /// Sets the style of the tab at the given index.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is useful to make a single tab stand out, e.g. a tab with unsaved changes. The style is
+    /// applied to the title of the tab on top of the style of the title itself. The
+    /// [highlight style](Tabs::highlight_style) is applied on top of it when the tab is selected.
+    /// Calling this again for the same index replaces the previous style.
+    ///
+    /// # Examples
+    ///
+    /// Display the second tab in yellow.
+    /// ```
+    /// use ratatui::style::{Style, Stylize};
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).tab_style_for(1, Style::new().yellow());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn tab_style_for<S: Into<Style>>(mut self, index: usize, style: S) -> Self {
+        self.tab_styles.insert(index, style.into());
+        self
+    }
+
     /// Sets the string to use as tab divider.
     ///
-    /// By default, the divider is a pipe (`|`).
+    /// By default, the divider is a pipe (`|`). The divider is a [`Span`], so it can be styled
+    /// independently of the tabs.
     ///
     /// # Examples
     ///
@@ -271,6 +308,14 @@ impl<'a> Tabs<'a> {
     ///
     /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).divider("-");
     /// ```
+    ///
+    /// Use a styled divider.
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::widgets::Tabs;
+    ///
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).divider(" / ".dark_gray());
+    /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
     pub fn divider<T>(mut self, divider: T) -> Self
     where
@@ -405,16 +450,17 @@ impl Tabs<'_> {
 
             // Title
             let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
+            let title_area = Rect {
+                x,
+                y: tabs_area.top(),
+                width: pos.0.saturating_sub(x),
+                height: 1,
+            };
+            if let Some(style) = self.tab_styles.get(&i) {
+                buf.set_style(title_area, *style);
+            }
             if Some(i) == self.selected {
-                buf.set_style(
-                    Rect {
-                        x,
-                        y: tabs_area.top(),
-                        width: pos.0.saturating_sub(x),
-                        height: 1,
-                    },
-                    self.highlight_style,
-                );
+                buf.set_style(title_area, self.highlight_style);
             }
             x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
@@ -470,6 +516,7 @@ mod tests {
                 selected: Some(0),
                 style: Style::default(),
                 highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+                tab_styles: BTreeMap::new(),
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
@@ -487,6 +534,7 @@ mod tests {
                 selected: None,
                 style: Style::default(),
                 highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+                tab_styles: BTreeMap::new(),
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
@@ -640,6 +688,23 @@ mod tests {
         test_case(tabs.clone().select(None), Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_select_from_state() {
+        let titles = vec!["Tab1", "Tab2", "Tab3", "Tab4"];
+        let mut state = TabsState::default();
+        state.select_previous(titles.len(), true);
+        let expected = Buffer::with_lines([Line::from(vec![
+            " Tab1 │ Tab2 │ Tab3 │ ".into(),
+            "Tab4".reversed(),
+            "    ".into(),
+        ])]);
+        test_case(
+            Tabs::new(titles).select(state.selected()),
+            Rect::new(0, 0, 30, 1),
+            &expected,
+        );
+    }
+
     #[test]
     fn render_style_and_selected() {
         let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3", "Tab4"])
@@ -663,6 +728,60 @@ mod tests {
         test_case(tabs, Rect::new(0, 0, 30, 1), &expected);
     }
 
+    #[test]
+    fn render_styled_divider() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .divider("/".blue())
+            .select(None);
+        let expected = Buffer::with_lines([Line::from(vec![
+            " Tab1 ".into(),
+            "/".blue(),
+            " Tab2 ".into(),
+        ])]);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+    }
+
+    #[test]
+    fn tab_style_for() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .tab_style_for(1, Style::new().red())
+            .tab_style_for(1, Style::new().yellow());
+        assert_eq!(
+            tabs.tab_styles,
+            BTreeMap::from([(1, Style::new().yellow())])
+        );
+    }
+
+    #[test]
+    fn render_tab_style_for() {
+        let tabs = Tabs::new(vec!["Tab1".bold(), "Tab2".into(), "Tab3".into()])
+            .tab_style_for(0, Style::new().yellow())
+            .tab_style_for(2, Style::new().green())
+            .select(None);
+        let expected = Buffer::with_lines([Line::from(vec![
+            " ".into(),
+            "Tab1".yellow().bold(),
+            " │ Tab2 │ ".into(),
+            "Tab3".green(),
+            " ".into(),
+        ])]);
+        test_case(tabs, Rect::new(0, 0, 20, 1), &expected);
+    }
+
+    #[test]
+    fn render_tab_style_for_selected() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2"])
+            .tab_style_for(1, Style::new().yellow())
+            .highlight_style(Style::new().on_blue())
+            .select(1);
+        let expected = Buffer::with_lines([Line::from(vec![
+            " Tab1 │ ".into(),
+            "Tab2".yellow().on_blue(),
+            " ".into(),
+        ])]);
+        test_case(tabs, Rect::new(0, 0, 13, 1), &expected);
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
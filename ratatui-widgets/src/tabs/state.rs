@@ -0,0 +1,185 @@
+/// Selection state of the [`Tabs`] widget
+///
+/// This state keeps track of the selected tab to help with keyboard navigation. The selected index
+/// is passed to the widget with [`Tabs::select`].
+///
+/// As the state does not know the number of tabs, the navigation methods such as
+/// [`TabsState::select_next`] take it as a parameter. They can either stop at the first and last
+/// tabs or wrap around.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Tabs, TabsState};
+/// use ratatui::Frame;
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let titles = ["Tab 1", "Tab 2", "Tab 3"];
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TabsState::default();
+///
+/// state.select_next(titles.len(), true); // select the first tab
+/// state.select_previous(titles.len(), true); // wrap around to the last tab
+///
+/// frame.render_widget(Tabs::new(titles).select(state.selected()), area);
+/// # }
+/// ```
+///
+/// [`Tabs`]: super::Tabs
+/// [`Tabs::select`]: super::Tabs::select
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TabsState {
+    pub(crate) selected: Option<usize>,
+}
+
+impl TabsState {
+    /// Sets the index of the selected tab
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let state = TabsState::default().with_selected(Some(1));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the selected tab
+    ///
+    /// Returns `None` if no tab is selected
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Sets the index of the selected tab
+    ///
+    /// Set to `None` if no tab is selected.
+    pub const fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Selects the next tab, or the first one if no tab is selected
+    ///
+    /// `count` is the number of tabs. When the last tab is selected, the selection wraps around to
+    /// the first tab if `wrap` is `true` and stays on the last tab otherwise. The selection is
+    /// cleared if there are no tabs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default().with_selected(Some(2));
+    /// state.select_next(3, true);
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub const fn select_next(&mut self, count: usize, wrap: bool) {
+        let Some(last) = count.checked_sub(1) else {
+            self.selected = None;
+            return;
+        };
+        self.selected = Some(match self.selected {
+            None => 0,
+            Some(index) if index >= last && wrap => 0,
+            Some(index) if index >= last => last,
+            Some(index) => index + 1,
+        });
+    }
+
+    /// Selects the previous tab, or the last one if no tab is selected
+    ///
+    /// `count` is the number of tabs. When the first tab is selected, the selection wraps around
+    /// to the last tab if `wrap` is `true` and stays on the first tab otherwise. The selection is
+    /// cleared if there are no tabs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TabsState;
+    ///
+    /// let mut state = TabsState::default().with_selected(Some(0));
+    /// state.select_previous(3, true);
+    /// assert_eq!(state.selected(), Some(2));
+    /// ```
+    pub const fn select_previous(&mut self, count: usize, wrap: bool) {
+        let Some(last) = count.checked_sub(1) else {
+            self.selected = None;
+            return;
+        };
+        self.selected = Some(match self.selected {
+            None => last,
+            Some(0) if wrap => last,
+            Some(0) => 0,
+            Some(index) if index > last => last,
+            Some(index) => index - 1,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn select() {
+        let mut state = TabsState::default();
+        assert_eq!(state.selected(), None);
+        state.select(Some(1));
+        assert_eq!(state.selected(), Some(1));
+        assert_eq!(state, TabsState::default().with_selected(Some(1)));
+    }
+
+    #[rstest]
+    #[case::from_none(None, 3, false, Some(0))]
+    #[case::middle(Some(0), 3, false, Some(1))]
+    #[case::last_without_wrap(Some(2), 3, false, Some(2))]
+    #[case::last_with_wrap(Some(2), 3, true, Some(0))]
+    #[case::out_of_range_without_wrap(Some(5), 3, false, Some(2))]
+    #[case::out_of_range_with_wrap(Some(5), 3, true, Some(0))]
+    #[case::single_tab(Some(0), 1, true, Some(0))]
+    #[case::no_tabs(Some(1), 0, true, None)]
+    #[case::no_tabs_from_none(None, 0, false, None)]
+    fn select_next(
+        #[case] selected: Option<usize>,
+        #[case] count: usize,
+        #[case] wrap: bool,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = TabsState::default().with_selected(selected);
+        state.select_next(count, wrap);
+        assert_eq!(state.selected(), expected);
+    }
+
+    #[rstest]
+    #[case::from_none(None, 3, false, Some(2))]
+    #[case::middle(Some(2), 3, false, Some(1))]
+    #[case::first_without_wrap(Some(0), 3, false, Some(0))]
+    #[case::first_with_wrap(Some(0), 3, true, Some(2))]
+    #[case::out_of_range(Some(5), 3, false, Some(2))]
+    #[case::single_tab(Some(0), 1, true, Some(0))]
+    #[case::no_tabs(Some(1), 0, true, None)]
+    #[case::no_tabs_from_none(None, 0, false, None)]
+    fn select_previous(
+        #[case] selected: Option<usize>,
+        #[case] count: usize,
+        #[case] wrap: bool,
+        #[case] expected: Option<usize>,
+    ) {
+        let mut state = TabsState::default().with_selected(selected);
+        state.select_previous(count, wrap);
+        assert_eq!(state.selected(), expected);
+    }
+}
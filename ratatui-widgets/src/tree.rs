@@ -0,0 +1,499 @@
+//! The [`Tree`] widget is used to display a hierarchy of collapsible items, such as a file tree.
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ratatui_core::buffer::Buffer;
+use ratatui_core::layout::Rect;
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::widgets::{StatefulWidget, Widget};
+
+pub use self::item::TreeItem;
+use self::item::{visible_items, VisibleItem};
+pub use self::state::TreeState;
+use crate::block::{Block, BlockExt};
+
+mod item;
+mod state;
+
+const OPEN_MARKER: &str = "▾ ";
+const CLOSED_MARKER: &str = "▸ ";
+const ROOT_LEAF_MARKER: &str = "  ";
+const LEAF_MARKER: &str = "─ ";
+const BRANCH: &str = "├─";
+const LAST_BRANCH: &str = "└─";
+const VERTICAL_GUIDE: &str = "│ ";
+const EMPTY_GUIDE: &str = "  ";
+
+/// A widget to display a tree of items among which one can be selected (optional)
+///
+/// A tree is a collection of nested [`TreeItem`]s. Items with children are drawn with a `▸` marker
+/// when they are closed and a `▾` marker when they are open. The children of open items are
+/// indented below their parent and connected to it with guide lines:
+///
+/// ```text
+/// ▾ src
+/// ├─▾ widgets
+/// │ ├── list.rs
+/// │ └── table.rs
+/// └── lib.rs
+/// ▸ tests
+/// ```
+///
+/// Which items are open and which one is selected is stored in a [`TreeState`], see its
+/// documentation for how to navigate through the tree.
+///
+/// # Fluent setters
+///
+/// - [`Tree::highlight_style`] sets the style of the selected item.
+/// - [`Tree::guide_style`] sets the style of the markers and guide lines.
+/// - [`Tree::style`] sets the base style of the widget.
+/// - [`Tree::block`] wraps the tree in a [`Block`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::{Block, Tree, TreeItem, TreeState};
+/// use ratatui::Frame;
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [
+///     TreeItem::new(
+///         "src",
+///         "src",
+///         [
+///             TreeItem::new_leaf("src/lib.rs", "lib.rs"),
+///             TreeItem::new_leaf("src/main.rs", "main.rs"),
+///         ],
+///     ),
+///     TreeItem::new_leaf("Cargo.toml", "Cargo.toml"),
+/// ];
+/// let tree = Tree::new(items)
+///     .block(Block::bordered().title("Files"))
+///     .highlight_style(Style::new().reversed())
+///     .guide_style(Style::new().dark_gray());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+/// state.open("src");
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Tree<'a> {
+    items: Vec<TreeItem<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    guide_style: Style,
+}
+
+impl<'a> Tree<'a> {
+    /// Creates a new tree from [`TreeItem`]s
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Tree, TreeItem};
+    ///
+    /// let tree = Tree::new([TreeItem::new_leaf("a", "Item A")]);
+    /// ```
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator<Item = TreeItem<'a>>,
+    {
+        Self {
+            items: items.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the root items of the tree
+    pub fn items(&self) -> &[TreeItem<'a>] {
+        &self.items
+    }
+
+    /// Wraps the tree with a custom [`Block`] widget.
+    ///
+    /// The `block` parameter holds the specified [`Block`] to be created around the [`Tree`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// All text rendered by the widget will use this style, unless overridden by the style of an
+    /// item or of its content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected item
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to the whole row of the selected item and is patched on top of
+    /// the existing styles.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the open/closed markers and of the guide lines
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn guide_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.guide_style = style.into();
+        self
+    }
+}
+
+impl Widget for Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let tree_area = self.block.inner_if_some(area);
+        if tree_area.is_empty() {
+            return;
+        }
+
+        let visible = visible_items(&self.items, &state.opened);
+        let selected = state.selected.as_deref().and_then(|selected| {
+            visible
+                .iter()
+                .position(|visible| visible.item.id == selected)
+        });
+        let height = tree_area.height as usize;
+        let mut offset = state.offset.min(visible.len().saturating_sub(1));
+        if let Some(selected) = selected {
+            offset = offset
+                .min(selected)
+                .max((selected + 1).saturating_sub(height));
+        }
+        state.offset = offset;
+
+        for (i, visible) in visible.iter().enumerate().skip(offset).take(height) {
+            let row_area = Rect {
+                y: tree_area.y + (i - offset) as u16,
+                height: 1,
+                ..tree_area
+            };
+            buf.set_style(row_area, visible.item.style);
+            let prefix = guides(visible, state.is_open(&visible.item.id));
+            let (x, _) = buf.set_stringn(
+                row_area.x,
+                row_area.y,
+                prefix,
+                row_area.width as usize,
+                self.guide_style,
+            );
+            let content_area = Rect {
+                x,
+                width: row_area.right().saturating_sub(x),
+                ..row_area
+            };
+            (&visible.item.content).render(content_area, buf);
+            if selected == Some(i) {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+/// Returns the guide lines and marker displayed before the content of an item
+fn guides(visible: &VisibleItem, is_open: bool) -> String {
+    let mut prefix: String = visible
+        .guides
+        .iter()
+        .map(|&has_sibling| {
+            if has_sibling {
+                VERTICAL_GUIDE
+            } else {
+                EMPTY_GUIDE
+            }
+        })
+        .collect();
+    if visible.depth > 0 {
+        prefix.push_str(if visible.is_last { LAST_BRANCH } else { BRANCH });
+    }
+    let marker = if !visible.item.is_leaf() {
+        if is_open {
+            OPEN_MARKER
+        } else {
+            CLOSED_MARKER
+        }
+    } else if visible.depth > 0 {
+        LEAF_MARKER
+    } else {
+        ROOT_LEAF_MARKER
+    };
+    prefix.push_str(marker);
+    prefix
+}
+
+impl Styled for Tree<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::{Color, Modifier, Stylize};
+
+    use super::*;
+
+    fn items() -> [TreeItem<'static>; 2] {
+        [
+            TreeItem::new(
+                "src",
+                "src",
+                [
+                    TreeItem::new(
+                        "widgets",
+                        "widgets",
+                        [
+                            TreeItem::new_leaf("list", "list.rs"),
+                            TreeItem::new_leaf("table", "table.rs"),
+                        ],
+                    ),
+                    TreeItem::new_leaf("lib", "lib.rs"),
+                ],
+            ),
+            TreeItem::new("tests", "tests", [TreeItem::new_leaf("test", "test.rs")]),
+        ]
+    }
+
+    fn render(tree: &Tree, state: &mut TreeState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(tree, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn new() {
+        let tree = Tree::new(items());
+        assert_eq!(tree.items(), items());
+        assert_eq!(tree.style, Style::default());
+        assert_eq!(tree.highlight_style, Style::default());
+    }
+
+    #[test]
+    fn render_closed() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default();
+        let buf = render(&tree, &mut state, 12, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▸ src       ", "▸ tests     ", "            "])
+        );
+    }
+
+    #[test]
+    fn render_indentation() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default();
+        state.open("src");
+        state.open("widgets");
+        state.open("tests");
+        let buf = render(&tree, &mut state, 14, 7);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "▾ src         ",
+                "├─▾ widgets   ",
+                "│ ├── list.rs ",
+                "│ └── table.rs",
+                "└── lib.rs    ",
+                "▾ tests       ",
+                "└── test.rs   ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_indentation_of_last_child() {
+        let items = [TreeItem::new(
+            "a",
+            "a",
+            [
+                TreeItem::new_leaf("b", "b"),
+                TreeItem::new("c", "c", [TreeItem::new_leaf("d", "d")]),
+            ],
+        )];
+        let tree = Tree::new(items);
+        let mut state = TreeState::default();
+        state.open("a");
+        state.open("c");
+        let buf = render(&tree, &mut state, 8, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▾ a     ", "├── b   ", "└─▾ c   ", "  └── d "])
+        );
+    }
+
+    #[test]
+    fn render_collapsed_subtree() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default();
+        state.open("src");
+        let buf = render(&tree, &mut state, 12, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "▾ src       ",
+                "├─▸ widgets ",
+                "└── lib.rs  ",
+                "▸ tests     ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_root_leaf() {
+        let tree = Tree::new([TreeItem::new_leaf("a", "a"), TreeItem::new_leaf("b", "b")]);
+        let mut state = TreeState::default();
+        let buf = render(&tree, &mut state, 4, 2);
+        assert_eq!(buf, Buffer::with_lines(["  a ", "  b "]));
+    }
+
+    #[test]
+    fn render_highlight() {
+        let tree = Tree::new(items()).highlight_style(Style::new().reversed());
+        let mut state = TreeState::default().with_selected("widgets");
+        state.open("src");
+        let buf = render(&tree, &mut state, 12, 4);
+        let mut expected = Buffer::with_lines([
+            "▾ src       ",
+            "├─▸ widgets ",
+            "└── lib.rs  ",
+            "▸ tests     ",
+        ]);
+        expected.set_style(Rect::new(0, 1, 12, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_hidden_selection() {
+        let tree = Tree::new(items()).highlight_style(Style::new().reversed());
+        let mut state = TreeState::default().with_selected("lib");
+        let buf = render(&tree, &mut state, 8, 2);
+        assert_eq!(buf, Buffer::with_lines(["▸ src   ", "▸ tests "]));
+    }
+
+    #[test]
+    fn render_guide_style() {
+        let tree = Tree::new(items()).guide_style(Style::new().dark_gray());
+        let mut state = TreeState::default();
+        state.open("tests");
+        let buf = render(&tree, &mut state, 11, 3);
+        let mut expected = Buffer::with_lines(["▸ src      ", "▾ tests    ", "└── test.rs"]);
+        expected.set_style(Rect::new(0, 0, 2, 2), Style::new().dark_gray());
+        expected.set_style(Rect::new(0, 2, 4, 1), Style::new().dark_gray());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_scrolls_to_selection() {
+        let tree = Tree::new(items());
+        let mut state = TreeState::default().with_selected("lib");
+        state.open("src");
+        state.open("widgets");
+        let buf = render(&tree, &mut state, 12, 2);
+        assert_eq!(buf, Buffer::with_lines(["│ └── table.", "└── lib.rs  "]));
+        assert_eq!(state.offset(), 3);
+
+        state.select(Some("src".into()));
+        let buf = render(&tree, &mut state, 12, 2);
+        assert_eq!(buf, Buffer::with_lines(["▾ src       ", "├─▾ widgets "]));
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_with_block() {
+        let tree = Tree::new(items()).block(Block::bordered());
+        let mut state = TreeState::default();
+        let buf = render(&tree, &mut state, 9, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌───────┐", "│▸ src  │", "│▸ tests│", "└───────┘"])
+        );
+    }
+
+    #[test]
+    fn render_in_empty_area() {
+        let tree = Tree::new(items());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Widget::render(&tree, Rect::ZERO, &mut buf);
+        assert_eq!(buf, Buffer::empty(Rect::new(0, 0, 5, 1)));
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            Tree::new(items()).black().on_white().bold().style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ratatui_core::style::{Style, Styled};
+use ratatui_core::text::Line;
+
+/// A single node of a [`Tree`]
+///
+/// Each item has an identifier, some content and an optional list of children. The identifier is
+/// used by the [`TreeState`] to keep track of which items are open and which one is selected. It
+/// must therefore be unique across the whole tree.
+///
+/// Items without children are leaves and are rendered without an open/closed marker.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::style::Stylize;
+/// use ratatui::widgets::TreeItem;
+///
+/// let item = TreeItem::new(
+///     "src",
+///     "src",
+///     [
+///         TreeItem::new_leaf("src/lib.rs", "lib.rs"),
+///         TreeItem::new_leaf("src/main.rs", "main.rs").bold(),
+///     ],
+/// );
+/// ```
+///
+/// [`Tree`]: super::Tree
+/// [`TreeState`]: super::TreeState
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TreeItem<'a> {
+    pub(crate) id: String,
+    pub(crate) content: Line<'a>,
+    pub(crate) children: Vec<Self>,
+    pub(crate) style: Style,
+}
+
+impl<'a> TreeItem<'a> {
+    /// Creates a new `TreeItem` with the given children
+    ///
+    /// `content` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeItem;
+    ///
+    /// let item = TreeItem::new("fruits", "Fruits", [TreeItem::new_leaf("apple", "Apple")]);
+    /// ```
+    pub fn new<I, T, C>(id: I, content: T, children: C) -> Self
+    where
+        I: Into<String>,
+        T: Into<Line<'a>>,
+        C: IntoIterator<Item = Self>,
+    {
+        Self {
+            id: id.into(),
+            content: content.into(),
+            children: children.into_iter().collect(),
+            style: Style::default(),
+        }
+    }
+
+    /// Creates a new `TreeItem` without children
+    ///
+    /// `content` can be a [`&str`], [`String`] or anything that can be converted into [`Line`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeItem;
+    ///
+    /// let item = TreeItem::new_leaf("apple", "Apple");
+    /// ```
+    pub fn new_leaf<I, T>(id: I, content: T) -> Self
+    where
+        I: Into<String>,
+        T: Into<Line<'a>>,
+    {
+        Self::new(id, content, [])
+    }
+
+    /// Sets the item style
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied to the whole row of the item, including the indentation guides.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the identifier of the item
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the children of the item
+    pub fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    /// Returns whether the item has children
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl Styled for TreeItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// An item of a [`Tree`] as it is displayed, with the information needed to draw its guides
+///
+/// [`Tree`]: super::Tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct VisibleItem<'b, 'a> {
+    pub(crate) item: &'b TreeItem<'a>,
+    pub(crate) depth: usize,
+    /// For each ancestor below the root level, whether it is followed by a sibling
+    pub(crate) guides: Vec<bool>,
+    pub(crate) is_last: bool,
+}
+
+/// Flattens the items into the list of visible items, skipping the children of closed items
+pub(crate) fn visible_items<'b, 'a>(
+    items: &'b [TreeItem<'a>],
+    opened: &BTreeSet<String>,
+) -> Vec<VisibleItem<'b, 'a>> {
+    let mut visible = Vec::new();
+    push_visible_items(items, opened, 0, &mut Vec::new(), &mut visible);
+    visible
+}
+
+fn push_visible_items<'b, 'a>(
+    items: &'b [TreeItem<'a>],
+    opened: &BTreeSet<String>,
+    depth: usize,
+    guides: &mut Vec<bool>,
+    visible: &mut Vec<VisibleItem<'b, 'a>>,
+) {
+    for (index, item) in items.iter().enumerate() {
+        let is_last = index + 1 == items.len();
+        visible.push(VisibleItem {
+            item,
+            depth,
+            guides: guides.clone(),
+            is_last,
+        });
+        if item.is_leaf() || !opened.contains(&item.id) {
+            continue;
+        }
+        if depth > 0 {
+            guides.push(!is_last);
+        }
+        push_visible_items(&item.children, opened, depth + 1, guides, visible);
+        if depth > 0 {
+            guides.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use ratatui_core::style::{Color, Modifier, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = TreeItem::new("a", "A", [TreeItem::new_leaf("b", "B")]);
+        assert_eq!(item.id(), "a");
+        assert_eq!(item.content, Line::from("A"));
+        assert_eq!(item.children(), [TreeItem::new_leaf("b", "B")]);
+        assert!(!item.is_leaf());
+    }
+
+    #[test]
+    fn new_leaf() {
+        let item = TreeItem::new_leaf("a", "A");
+        assert_eq!(item.children(), []);
+        assert!(item.is_leaf());
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            TreeItem::new_leaf("a", "A").black().on_white().bold().style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn visible_items_skips_closed_children() {
+        let items = [
+            TreeItem::new(
+                "a",
+                "A",
+                [
+                    TreeItem::new("b", "B", [TreeItem::new_leaf("c", "C")]),
+                    TreeItem::new_leaf("d", "D"),
+                ],
+            ),
+            TreeItem::new_leaf("e", "E"),
+        ];
+        let ids = |opened: &[&str]| {
+            let opened = opened.iter().map(|&id| id.into()).collect();
+            visible_items(&items, &opened)
+                .iter()
+                .map(|visible| visible.item.id())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&[]), ["a", "e"]);
+        assert_eq!(ids(&["b"]), ["a", "e"]);
+        assert_eq!(ids(&["a"]), ["a", "b", "d", "e"]);
+        assert_eq!(ids(&["a", "b"]), ["a", "b", "c", "d", "e"]);
+    }
+
+    #[test]
+    fn visible_items_guides() {
+        let items = [TreeItem::new(
+            "a",
+            "A",
+            [
+                TreeItem::new("b", "B", [TreeItem::new_leaf("c", "C")]),
+                TreeItem::new("d", "D", [TreeItem::new_leaf("e", "E")]),
+            ],
+        )];
+        let opened = ["a", "b", "d"].into_iter().map(String::from).collect();
+        let visible = visible_items(&items, &opened)
+            .into_iter()
+            .map(|visible| (visible.depth, visible.guides, visible.is_last))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            visible,
+            [
+                (0, vec![], true),
+                (1, vec![], false),
+                (2, vec![true], true),
+                (1, vec![], true),
+                (2, vec![false], true),
+            ]
+        );
+    }
+}
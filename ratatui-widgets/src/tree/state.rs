@@ -0,0 +1,340 @@
+use alloc::collections::BTreeSet;
+use alloc::string::String;
+
+use crate::tree::item::{visible_items, TreeItem};
+
+/// State of the [`Tree`] widget
+///
+/// This state keeps track of which items are open, which item is selected and the scroll offset.
+/// Items are referred to by their [`TreeItem::id`]. When the tree is rendered as a stateful widget,
+/// the selected item will be highlighted and the tree will be scrolled to ensure that the selected
+/// item is visible.
+///
+/// The children of an item are only displayed when the item is open. Navigation methods such as
+/// [`TreeState::select_next`] move between the displayed items and therefore skip the children of
+/// closed items. As the state does not own the items, these methods take the items of the tree as
+/// a parameter.
+///
+/// If the selected item is hidden because one of its ancestors was closed, it is not highlighted
+/// and navigation behaves as if no item was selected.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Tree, TreeItem, TreeState};
+/// use ratatui::Frame;
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [TreeItem::new(
+///     "fruits",
+///     "Fruits",
+///     [TreeItem::new_leaf("apple", "Apple")],
+/// )];
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+///
+/// state.toggle("fruits"); // open the "Fruits" item
+/// state.select_next(&items); // select the first item
+///
+/// frame.render_stateful_widget(Tree::new(items), area, &mut state);
+/// # }
+/// ```
+///
+/// [`Tree`]: super::Tree
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<String>,
+    pub(crate) opened: BTreeSet<String>,
+}
+
+impl TreeState {
+    /// Sets the index of the first displayed item to be rendered
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the identifier of the selected item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected<S: Into<String>>(mut self, id: S) -> Self {
+        self.selected = Some(id.into());
+        self
+    }
+
+    /// Index of the first displayed item to be rendered
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first displayed item to be rendered
+    pub const fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Identifier of the selected item
+    ///
+    /// Returns `None` if no item is selected
+    pub fn selected(&self) -> Option<&str> {
+        self.selected.as_deref()
+    }
+
+    /// Sets the identifier of the selected item
+    ///
+    /// Set to `None` if no item is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeState;
+    ///
+    /// let mut state = TreeState::default();
+    /// state.select(Some("apple".into()));
+    /// assert_eq!(state.selected(), Some("apple"));
+    /// ```
+    pub fn select(&mut self, id: Option<String>) {
+        self.selected = id;
+    }
+
+    /// Identifiers of the open items
+    pub const fn opened(&self) -> &BTreeSet<String> {
+        &self.opened
+    }
+
+    /// Returns whether the item with the given identifier is open
+    pub fn is_open(&self, id: &str) -> bool {
+        self.opened.contains(id)
+    }
+
+    /// Opens the item with the given identifier, displaying its children
+    pub fn open(&mut self, id: &str) {
+        self.opened.insert(id.into());
+    }
+
+    /// Closes the item with the given identifier, hiding its children
+    pub fn close(&mut self, id: &str) {
+        self.opened.remove(id);
+    }
+
+    /// Opens the item with the given identifier if it is closed, or closes it otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TreeState;
+    ///
+    /// let mut state = TreeState::default();
+    /// state.toggle("fruits");
+    /// assert!(state.is_open("fruits"));
+    /// state.toggle("fruits");
+    /// assert!(!state.is_open("fruits"));
+    /// ```
+    pub fn toggle(&mut self, id: &str) {
+        if !self.opened.remove(id) {
+            self.opened.insert(id.into());
+        }
+    }
+
+    /// Opens or closes the selected item
+    ///
+    /// Does nothing if no item is selected.
+    pub fn toggle_selected(&mut self) {
+        if let Some(id) = self.selected.take() {
+            self.toggle(&id);
+            self.selected = Some(id);
+        }
+    }
+
+    /// Selects the next displayed item, or the first one if no item is selected
+    ///
+    /// The children of closed items are skipped. The selection stays on the last item when it is
+    /// already selected.
+    pub fn select_next(&mut self, items: &[TreeItem]) {
+        let visible = visible_items(items, &self.opened);
+        let index = self
+            .selected_position(items)
+            .map_or(0, |index| index.saturating_add(1))
+            .min(visible.len().saturating_sub(1));
+        self.selected = visible.get(index).map(|visible| visible.item.id.clone());
+    }
+
+    /// Selects the previous displayed item, or the last one if no item is selected
+    ///
+    /// The children of closed items are skipped. The selection stays on the first item when it is
+    /// already selected.
+    pub fn select_previous(&mut self, items: &[TreeItem]) {
+        let visible = visible_items(items, &self.opened);
+        let index = self.selected_position(items).map_or_else(
+            || visible.len().saturating_sub(1),
+            |index| index.saturating_sub(1),
+        );
+        self.selected = visible.get(index).map(|visible| visible.item.id.clone());
+    }
+
+    /// Selects the first item
+    pub fn select_first(&mut self, items: &[TreeItem]) {
+        self.selected = items.first().map(|item| item.id.clone());
+    }
+
+    /// Selects the last displayed item
+    pub fn select_last(&mut self, items: &[TreeItem]) {
+        self.selected = visible_items(items, &self.opened)
+            .last()
+            .map(|visible| visible.item.id.clone());
+    }
+
+    /// Returns the position of the selected item among the displayed items
+    fn selected_position(&self, items: &[TreeItem]) -> Option<usize> {
+        let selected = self.selected.as_deref()?;
+        visible_items(items, &self.opened)
+            .iter()
+            .position(|visible| visible.item.id == selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// ```text
+    /// a
+    /// ├─b
+    /// │ └─c
+    /// └─d
+    /// e
+    /// ```
+    fn items() -> [TreeItem<'static>; 2] {
+        [
+            TreeItem::new(
+                "a",
+                "A",
+                [
+                    TreeItem::new("b", "B", [TreeItem::new_leaf("c", "C")]),
+                    TreeItem::new_leaf("d", "D"),
+                ],
+            ),
+            TreeItem::new_leaf("e", "E"),
+        ]
+    }
+
+    #[test]
+    fn toggle() {
+        let mut state = TreeState::default();
+        state.toggle("a");
+        assert!(state.is_open("a"));
+        state.toggle("a");
+        assert!(!state.is_open("a"));
+    }
+
+    #[test]
+    fn open_close() {
+        let mut state = TreeState::default();
+        state.open("a");
+        state.open("a");
+        assert!(state.is_open("a"));
+        state.close("a");
+        assert!(!state.is_open("a"));
+        assert!(state.opened().is_empty());
+    }
+
+    #[test]
+    fn toggle_selected() {
+        let mut state = TreeState::default();
+        state.toggle_selected();
+        assert!(state.opened().is_empty());
+
+        state.select(Some("a".into()));
+        state.toggle_selected();
+        assert!(state.is_open("a"));
+        assert_eq!(state.selected(), Some("a"));
+    }
+
+    #[test]
+    fn select_next_skips_collapsed_subtree() {
+        let items = items();
+        let mut state = TreeState::default();
+        state.open("a");
+
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("a"));
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("b"));
+        // "b" is closed, so "c" is skipped
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("d"));
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("e"));
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("e"));
+    }
+
+    #[test]
+    fn select_previous_skips_collapsed_subtree() {
+        let items = items();
+        let mut state = TreeState::default();
+
+        state.select_previous(&items);
+        assert_eq!(state.selected(), Some("e"));
+        // "a" is closed, so all its children are skipped
+        state.select_previous(&items);
+        assert_eq!(state.selected(), Some("a"));
+        state.select_previous(&items);
+        assert_eq!(state.selected(), Some("a"));
+    }
+
+    #[test]
+    fn select_into_open_subtree() {
+        let items = items();
+        let mut state = TreeState::default().with_selected("b");
+        state.open("a");
+        state.open("b");
+
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("c"));
+        state.select_previous(&items);
+        assert_eq!(state.selected(), Some("b"));
+    }
+
+    #[test]
+    fn select_from_hidden_item() {
+        let items = items();
+        let mut state = TreeState::default().with_selected("c");
+
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some("a"));
+    }
+
+    #[test]
+    fn select_first_and_last() {
+        let items = items();
+        let mut state = TreeState::default();
+
+        state.select_last(&items);
+        assert_eq!(state.selected(), Some("e"));
+        state.select_first(&items);
+        assert_eq!(state.selected(), Some("a"));
+
+        state.select_last(&[]);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn select_without_items() {
+        let mut state = TreeState::default();
+        state.select_next(&[]);
+        assert_eq!(state.selected(), None);
+        state.select_previous(&[]);
+        assert_eq!(state.selected(), None);
+    }
+}
@@ -0,0 +1,52 @@
+use criterion::{criterion_group, Criterion};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::symbols::Marker;
+use ratatui::widgets::canvas::{Canvas, CanvasState, Circle};
+use ratatui::widgets::{StatefulWidget, Widget};
+
+/// Benchmark for rendering a canvas with a fresh grid and with a grid reused from the state.
+fn canvas(c: &mut Criterion) {
+    let mut group = c.benchmark_group("canvas");
+
+    for marker in [Marker::Braille, Marker::HalfBlock] {
+        for (width, height) in [
+            (100, 50),  // vertically split screen
+            (200, 50),  // 1080p fullscreen with medium font
+            (256, 256), // Max sized area
+        ] {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+            let canvas = Canvas::default()
+                .marker(marker)
+                .x_bounds([-10.0, 10.0])
+                .y_bounds([-10.0, 10.0])
+                .paint(|ctx| {
+                    ctx.draw(&Circle {
+                        x: 0.0,
+                        y: 0.0,
+                        radius: 5.0,
+                        color: Color::Red,
+                    });
+                });
+
+            group.bench_function(format!("render/{marker:?}/{width}x{height}"), |b| {
+                b.iter(|| Widget::render(&canvas, buffer.area, &mut buffer));
+            });
+
+            let mut state = CanvasState::default();
+            group.bench_function(
+                format!("render_with_state/{marker:?}/{width}x{height}"),
+                |b| {
+                    b.iter(|| {
+                        StatefulWidget::render(&canvas, buffer.area, &mut buffer, &mut state);
+                    });
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, canvas);
@@ -336,7 +336,7 @@ extern crate std;
 #[cfg(feature = "palette")]
 pub use palette;
 pub use ratatui_core::terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
-pub use ratatui_core::{buffer, layout};
+pub use ratatui_core::{accessibility, buffer, layout};
 /// re-export the `crossterm` crate so that users don't have to add it as a dependency
 #[cfg(feature = "crossterm")]
 pub use ratatui_crossterm::crossterm;
@@ -44,5 +44,5 @@ pub use crate::layout::{
 pub use crate::style::{self, Color, Modifier, Style, Stylize};
 pub use crate::text::{self, Line, Masked, Span, Text};
 pub use crate::widgets::block::BlockExt;
-pub use crate::widgets::{StatefulWidget, Widget};
+pub use crate::widgets::{FramePaddingExt, StatefulWidget, Widget};
 pub use crate::{symbols, Frame, Terminal};
@@ -17,14 +17,18 @@
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`Grid`]: draws a grid of intersecting lines.
 //! - [`Gauge`]: displays progress percentage using block characters.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`ScrollView`]: displays a scrollable view over content larger than its area.
 //! - [`Sparkline`]: display a single data set as a sparkline.
+//! - [`Spinner`]: displays an animated spinner with an optional label.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`Tree`]: displays a tree of collapsible items and allows selection.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`RatatuiMascot`]: displays the Ratatui mascot.
 //!
@@ -34,24 +38,28 @@ pub use ratatui_core::widgets::{StatefulWidget, Widget};
 pub use ratatui_widgets::barchart::{Bar, BarChart, BarGroup};
 // TODO remove this module once title etc. are gone
 pub use ratatui_widgets::block;
-pub use ratatui_widgets::block::{Block, Padding};
+pub use ratatui_widgets::block::{Block, Blocked, Padding, WidgetExt};
 pub use ratatui_widgets::borders::{BorderType, Borders};
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
-pub use ratatui_widgets::canvas;
-pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition};
-pub use ratatui_widgets::clear::Clear;
+pub use ratatui_widgets::chart::{Axis, Chart, Dataset, GraphType, LegendPosition, YAxisPosition};
+pub use ratatui_widgets::clear::{Clear, ClearSymbols};
 pub use ratatui_widgets::gauge::{Gauge, LineGauge};
+pub use ratatui_widgets::grid::Grid;
 pub use ratatui_widgets::list::{List, ListDirection, ListItem, ListState};
 pub use ratatui_widgets::logo::{RatatuiLogo, Size as RatatuiLogoSize};
 pub use ratatui_widgets::mascot::{MascotEyeColor, RatatuiMascot};
-pub use ratatui_widgets::paragraph::{Paragraph, Wrap};
+pub use ratatui_widgets::paragraph::{Paragraph, ParagraphState, TextDirection, WordBreak, Wrap};
+pub use ratatui_widgets::scroll_view::{ScrollView, ScrollViewState};
 pub use ratatui_widgets::scrollbar::{
     ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState,
 };
 pub use ratatui_widgets::sparkline::{RenderDirection, Sparkline, SparklineBar};
+pub use ratatui_widgets::spinner::Spinner;
 pub use ratatui_widgets::table::{Cell, HighlightSpacing, Row, Table, TableState};
-pub use ratatui_widgets::tabs::Tabs;
+pub use ratatui_widgets::tabs::{Tabs, TabsState};
+pub use ratatui_widgets::tree::{Tree, TreeItem, TreeState};
+pub use ratatui_widgets::{canvas, spinner};
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};
 
@@ -60,6 +68,39 @@ mod widget_ref;
 
 use ratatui_core::layout::Rect;
 
+/// Extension trait for [`Frame`] that provides methods to render widgets inside a [`Padding`].
+///
+/// This trait is included in the [`prelude`](crate::prelude).
+///
+/// [`Frame`]: crate::Frame
+pub trait FramePaddingExt {
+    /// Render a [`Widget`] inside the given area shrunk by `padding`.
+    ///
+    /// The cells of the padding are left untouched. This is a shorthand for rendering the widget
+    /// in [`Padding::inner`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, Terminal};
+    /// # let backend = TestBackend::new(10, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// use ratatui::layout::Rect;
+    /// use ratatui::widgets::{FramePaddingExt, Padding, Paragraph};
+    ///
+    /// let area = Rect::new(0, 0, 10, 5);
+    /// frame.render_widget_padded(Paragraph::new("Hello"), area, Padding::uniform(1));
+    /// ```
+    fn render_widget_padded<W: Widget>(&mut self, widget: W, area: Rect, padding: Padding);
+}
+
+impl FramePaddingExt for ratatui_core::terminal::Frame<'_> {
+    fn render_widget_padded<W: Widget>(&mut self, widget: W, area: Rect, padding: Padding) {
+        self.render_widget(widget, padding.inner(area));
+    }
+}
+
 /// Extension trait for [`Frame`] that provides methods to render [`WidgetRef`] and
 /// [`StatefulWidgetRef`] to the current buffer.
 #[instability::unstable(feature = "widget-ref")]
@@ -134,3 +175,42 @@ impl FrameExt for ratatui_core::terminal::Frame<'_> {
         widget.render_ref(area, self.buffer_mut(), state);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::buffer::Buffer;
+    use ratatui_core::style::{Style, Stylize};
+
+    use super::*;
+    use crate::backend::TestBackend;
+    use crate::Terminal;
+
+    #[test]
+    fn render_widget_padded() {
+        let mut terminal = Terminal::new(TestBackend::new(8, 4)).unwrap();
+        terminal
+            .draw(|frame| {
+                frame.render_widget(Block::new().style(Style::new().on_blue()), frame.area());
+                let padding = Padding::new(2, 1, 1, 1);
+                frame.render_widget_padded(Paragraph::new("Hello world"), frame.area(), padding);
+            })
+            .unwrap();
+        let mut expected = Buffer::with_lines(["        ", "  Hello ", "        ", "        "]);
+        expected.set_style(expected.area, Style::new().on_blue());
+        terminal.backend().assert_buffer(&expected);
+    }
+
+    #[test]
+    fn render_widget_padded_larger_than_area() {
+        let mut terminal = Terminal::new(TestBackend::new(4, 2)).unwrap();
+        terminal
+            .draw(|frame| {
+                let padding = Padding::uniform(3);
+                frame.render_widget_padded(Paragraph::new("Hello"), frame.area(), padding);
+            })
+            .unwrap();
+        terminal
+            .backend()
+            .assert_buffer(&Buffer::with_lines(["    ", "    "]));
+    }
+}